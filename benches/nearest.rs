@@ -0,0 +1,58 @@
+// benches/nearest.rs
+//
+// Indexed nearest-color lookup vs. a linear scan over the full catalog.
+// Run with `cargo bench --bench nearest`.
+use colorum::{DistanceMetric, Origin, Rgb, nearest_colors, nearest_in, origin_slice};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+fn targets() -> Vec<Rgb> {
+    // deterministic spread of query colors
+    let mut state = 0x2545_F491_u32;
+    (0..500)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let [r, g, b, _] = state.to_le_bytes();
+            Rgb { r, g, b }
+        })
+        .collect()
+}
+
+fn time(label: &str, queries: &[Rgb], f: impl Fn(Rgb)) -> Duration {
+    let start = Instant::now();
+    for &q in queries {
+        f(q);
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{label:<28} {:>10.2?} total  {:>8.2?}/query",
+        elapsed,
+        elapsed / queries.len() as u32
+    );
+    elapsed
+}
+
+fn main() {
+    let queries = targets();
+    let colors = origin_slice(Origin::All);
+    println!("{} catalog entries, {} queries, k = 5\n", colors.len(), queries.len());
+
+    for metric in DistanceMetric::ALL {
+        // build the index outside the timed region
+        black_box(nearest_colors(queries[0], 1, Origin::All, metric));
+
+        let indexed = time(&format!("{metric} indexed"), &queries, |q| {
+            black_box(nearest_colors(q, 5, Origin::All, metric));
+        });
+        let linear = time(&format!("{metric} linear"), &queries, |q| {
+            black_box(nearest_in(colors, q, 5, metric));
+        });
+        println!(
+            "{:<28} {:.1}x\n",
+            format!("{metric} speedup"),
+            linear.as_secs_f64() / indexed.as_secs_f64()
+        );
+    }
+}
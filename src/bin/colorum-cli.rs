@@ -0,0 +1,466 @@
+//! `colorum-cli` — the color catalog from the shell, without the GUI.
+//!
+//! ```text
+//! colorum-cli name '#FF6347'
+//! colorum-cli hex "traffic red"
+//! colorum-cli search sky blue --origin css --mode all
+//! colorum-cli search 'entity:brand hue:200..240 -"dark"'
+//! colorum-cli nearest 'oklch(70% 0.1 200)' -k 3 --metric oklab
+//! colorum-cli convert tomato --to lab
+//! colorum-cli contrast '#333' white --json
+//! ```
+//!
+//! Every subcommand prints plain text by default and JSON with `--json`.
+//! Lookups that find nothing exit with status 1, bad arguments with 2.
+
+use clap::{Parser, Subcommand, ValueEnum};
+use colorum::contrast::{self, TextSize, WcagLevel};
+use colorum::color_types::HexCode;
+use colorum::colors_helper::{PaletteId, lookup_in_origin_ci, lookup_ral, matched_alias, parse_query, search_query};
+use colorum::rgb::{CopyFormat, format_rgba};
+use colorum::{
+    Cmyk, DistanceMetric, Hsv, Hwb, Lab, Lch, Oklab, Oklch, Rgb, Rgba, TokenMode, Xyz,
+    find_closest_color_name, name_for_hex, nearest_colors, parse_color, rgb_to_hex,
+};
+use serde_json::{Value, json};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "colorum-cli", version, about = "Look up, search and convert colors from the colorum catalog")]
+struct Cli {
+    /// Print JSON instead of plain text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Name of a color; the nearest name when no entry has exactly that hex
+    Name {
+        /// Hex or any CSS color
+        color: String,
+    },
+    /// Hex of a color name or RAL code
+    Hex {
+        /// Color name (case-insensitive); several words are joined with spaces
+        #[arg(required = true)]
+        name: Vec<String>,
+        /// Only look in this palette (a name shared by several palettes resolves to the first, CSS first, otherwise)
+        #[arg(long, default_value = "all")]
+        origin: String,
+    },
+    /// Search color names; clauses like origin:, entity:, hue:200..240, light:>60,
+    /// near:#3366ff~10, quoted phrases and -negation work as in the GUI search box
+    Search {
+        #[arg(required = true)]
+        query: Vec<String>,
+        /// Palette to search, e.g. css, xkcd, "ral classic"
+        #[arg(long, default_value = "all")]
+        origin: String,
+        #[arg(long, value_enum, default_value_t = Mode::Any)]
+        mode: Mode,
+        /// At most this many results
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Closest named colors
+    Nearest {
+        /// Hex or any CSS color
+        color: String,
+        /// How many matches
+        #[arg(short, default_value_t = 5, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        k: usize,
+        #[arg(long, default_value = "all")]
+        origin: String,
+        #[arg(long, value_enum, default_value_t = Metric::Ciede2000)]
+        metric: Metric,
+    },
+    /// Express a color in another notation
+    Convert {
+        /// Hex or any CSS color
+        color: String,
+        #[arg(long, value_enum)]
+        to: Target,
+    },
+    /// WCAG 2.1 contrast ratio and APCA Lc of text on a background
+    Contrast {
+        /// Text color
+        fg: String,
+        /// Background color
+        bg: String,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Mode {
+    Any,
+    All,
+    Substring,
+    /// Typo-tolerant, best match first
+    Fuzzy,
+}
+
+impl From<Mode> for TokenMode {
+    fn from(m: Mode) -> Self {
+        match m {
+            Mode::Any => TokenMode::Any,
+            Mode::All => TokenMode::All,
+            Mode::Substring => TokenMode::Substring,
+            Mode::Fuzzy => TokenMode::Fuzzy,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Metric {
+    Rgb,
+    Cie76,
+    Cie94,
+    Ciede2000,
+    Oklab,
+}
+
+impl From<Metric> for DistanceMetric {
+    fn from(m: Metric) -> Self {
+        match m {
+            Metric::Rgb => DistanceMetric::RgbEuclid,
+            Metric::Cie76 => DistanceMetric::Cie76,
+            Metric::Cie94 => DistanceMetric::Cie94,
+            Metric::Ciede2000 => DistanceMetric::Ciede2000,
+            Metric::Oklab => DistanceMetric::Oklab,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Target {
+    Hex,
+    Rgb,
+    Rgba,
+    Hsl,
+    Hsla,
+    Hsv,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+    Xyz,
+    Cmyk,
+}
+
+/// A failed command: message for stderr and the exit status
+struct Failure {
+    message: String,
+    code: u8,
+}
+
+impl Failure {
+    fn not_found(message: impl Into<String>) -> Self {
+        Self { message: message.into(), code: 1 }
+    }
+
+    fn usage(message: impl Into<String>) -> Self {
+        Self { message: message.into(), code: 2 }
+    }
+}
+
+/// What a command prints: its plain-text lines and its JSON form
+struct Output {
+    text: Vec<String>,
+    json: Value,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.command) {
+        Ok(out) => {
+            if cli.json {
+                println!("{}", serde_json::to_string_pretty(&out.json).expect("JSON values serialize"));
+            } else {
+                for line in out.text {
+                    println!("{line}");
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Err(f) => {
+            eprintln!("colorum-cli: {}", f.message);
+            ExitCode::from(f.code)
+        }
+    }
+}
+
+fn run(command: Command) -> Result<Output, Failure> {
+    match command {
+        Command::Name { color } => name(&color),
+        Command::Hex { name, origin } => hex(&name.join(" "), &origin),
+        Command::Search { query, origin, mode, limit } => search(&query.join(" "), &origin, mode, limit),
+        Command::Nearest { color, k, origin, metric } => nearest(&color, k, &origin, metric),
+        Command::Convert { color, to } => convert(&color, to),
+        Command::Contrast { fg, bg } => contrast(&fg, &bg),
+    }
+}
+
+fn color_arg(s: &str) -> Result<Rgba, Failure> {
+    parse_color(s).map_err(|e| Failure::usage(format!("`{s}`: {e}")))
+}
+
+fn origin_arg(s: &str) -> Result<PaletteId, Failure> {
+    s.parse().map_err(|e| Failure::usage(format!("{e}")))
+}
+
+// catalog hex as printed: some palettes store lowercase digits
+fn hex_out(hex: HexCode) -> String {
+    hex.as_str().to_ascii_uppercase()
+}
+
+fn name(color: &str) -> Result<Output, Failure> {
+    let rgb = color_arg(color)?.rgb();
+    let hex = rgb_to_hex(rgb);
+    let (name, exact) = match name_for_hex(hex.clone()) {
+        Some(n) => (n, true),
+        None => (
+            find_closest_color_name(rgb).ok_or_else(|| Failure::not_found("the catalog is empty"))?,
+            false,
+        ),
+    };
+    Ok(Output {
+        text: vec![name.to_string()],
+        json: json!({ "hex": hex, "name": name, "exact": exact }),
+    })
+}
+
+fn hex(name: &str, origin: &str) -> Result<Output, Failure> {
+    let origin = origin_arg(origin)?;
+    let missing = || Failure::not_found(format!("no color named `{name}` in {origin}"));
+    // the catalog lookup resolves a name shared by several palettes as the GUI does
+    let (hex, canonical) = (origin == PaletteId::All)
+        .then(|| lookup_ral(name))
+        .flatten()
+        .or_else(|| lookup_in_origin_ci(origin, name))
+        .ok_or_else(missing)?;
+    let (hex, canonical) = (hex_out(hex), canonical.as_str());
+    Ok(Output {
+        text: vec![hex.clone()],
+        json: json!({ "name": canonical, "hex": hex }),
+    })
+}
+
+fn search(query: &str, origin: &str, mode: Mode, limit: usize) -> Result<Output, Failure> {
+    let origin = origin_arg(origin)?;
+    let parsed = parse_query(query).map_err(|e| Failure::usage(e.to_string()))?;
+    let mut hits = search_query(origin, &parsed, mode.into());
+    hits.truncate(limit);
+    if hits.is_empty() {
+        return Err(Failure::not_found(format!("nothing in {origin} matches `{query}`")));
+    }
+    let hits: Vec<_> = hits.into_iter().map(|(h, n)| (h, n, matched_alias(n, query))).collect();
+    Ok(Output {
+        text: hits
+            .iter()
+            .map(|(h, n, alias)| match alias {
+                Some(a) => format!("{}\t{}\t({})", hex_out(*h), n, a.text),
+                None => format!("{}\t{}", hex_out(*h), n),
+            })
+            .collect(),
+        json: Value::Array(
+            hits.iter()
+                .map(|(h, n, alias)| json!({ "hex": hex_out(*h), "name": n.as_str(), "alias": alias.map(|a| a.text) }))
+                .collect(),
+        ),
+    })
+}
+
+fn nearest(color: &str, k: usize, origin: &str, metric: Metric) -> Result<Output, Failure> {
+    let rgb = color_arg(color)?.rgb();
+    let origin = origin_arg(origin)?;
+    let metric = DistanceMetric::from(metric);
+    let hits = nearest_colors(rgb, k, origin, metric);
+    if hits.is_empty() {
+        return Err(Failure::not_found(format!("{origin} has no colors")));
+    }
+    Ok(Output {
+        text: hits
+            .iter()
+            .map(|(h, n, d)| format!("{}\t{}\t{:.2}", hex_out(*h), n, d))
+            .collect(),
+        json: json!({
+            "hex": rgb_to_hex(rgb),
+            "metric": metric.display_name(),
+            "matches": hits
+                .iter()
+                .map(|(h, n, d)| json!({ "hex": hex_out(*h), "name": n.as_str(), "distance": d }))
+                .collect::<Vec<_>>(),
+        }),
+    })
+}
+
+fn convert(color: &str, to: Target) -> Result<Output, Failure> {
+    let rgba = color_arg(color)?;
+    let rgb: Rgb = rgba.rgb();
+    let copy = |format| (format_rgba(rgba, format), Value::Null);
+    let (text, components) = match to {
+        Target::Hex => copy(CopyFormat::Hex),
+        Target::Rgb => copy(CopyFormat::Rgb),
+        Target::Rgba => copy(CopyFormat::Rgba),
+        Target::Hsl => copy(CopyFormat::Hsl),
+        Target::Hsla => copy(CopyFormat::Hsla),
+        Target::Hsv => {
+            let c = Hsv::from(rgb);
+            (format!("hsv({:.0}, {:.0}%, {:.0}%)", c.h, c.s, c.v), json!([c.h, c.s, c.v]))
+        }
+        Target::Hwb => {
+            let c = Hwb::from(rgb);
+            (format!("hwb({:.0} {:.0}% {:.0}%)", c.h, c.w, c.b), json!([c.h, c.w, c.b]))
+        }
+        Target::Lab => {
+            let c = Lab::from(rgb);
+            (format!("lab({:.2} {:.2} {:.2})", c.l, c.a, c.b), json!([c.l, c.a, c.b]))
+        }
+        Target::Lch => {
+            let c = Lch::from(rgb);
+            (format!("lch({:.2} {:.2} {:.2})", c.l, c.c, c.h), json!([c.l, c.c, c.h]))
+        }
+        Target::Oklab => {
+            let c = Oklab::from(rgb);
+            (format!("oklab({:.4} {:.4} {:.4})", c.l, c.a, c.b), json!([c.l, c.a, c.b]))
+        }
+        Target::Oklch => {
+            let c = Oklch::from(rgb);
+            (format!("oklch({:.4} {:.4} {:.2})", c.l, c.c, c.h), json!([c.l, c.c, c.h]))
+        }
+        Target::Xyz => {
+            let c = Xyz::from(rgb);
+            (format!("xyz({:.4} {:.4} {:.4})", c.x, c.y, c.z), json!([c.x, c.y, c.z]))
+        }
+        Target::Cmyk => {
+            let c = Cmyk::from(rgb);
+            (
+                format!("cmyk({:.0}%, {:.0}%, {:.0}%, {:.0}%)", c.c, c.m, c.y, c.k),
+                json!([c.c, c.m, c.y, c.k]),
+            )
+        }
+    };
+    let target = to.to_possible_value().expect("no skipped variants");
+    Ok(Output {
+        json: json!({ "hex": rgb_to_hex(rgb), "to": target.get_name(), "value": text, "components": components }),
+        text: vec![text],
+    })
+}
+
+fn contrast(fg: &str, bg: &str) -> Result<Output, Failure> {
+    let report = contrast::check(color_arg(fg)?.rgb(), color_arg(bg)?.rgb());
+    let pass = |level, size| report.passes(level, size);
+    let mark = |level, size| if pass(level, size) { "pass" } else { "fail" };
+    let (aa, aaa, normal, large) = (WcagLevel::Aa, WcagLevel::Aaa, TextSize::Normal, TextSize::Large);
+    Ok(Output {
+        text: vec![
+            format!("{:.2}:1  APCA Lc {:.1}", report.ratio, report.apca),
+            format!(
+                "AA {}  AA large {}  AAA {}  AAA large {}",
+                mark(aa, normal),
+                mark(aa, large),
+                mark(aaa, normal),
+                mark(aaa, large)
+            ),
+        ],
+        json: json!({
+            "fg": rgb_to_hex(report.fg),
+            "bg": rgb_to_hex(report.bg),
+            "ratio": report.ratio,
+            "apca": report.apca,
+            "aa": pass(aa, normal),
+            "aa_large": pass(aa, large),
+            "aaa": pass(aaa, normal),
+            "aaa_large": pass(aaa, large),
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // parse `args` as the shell would pass them and run the command
+    fn cli(args: &[&str]) -> Result<Output, Failure> {
+        let cli = Cli::try_parse_from(std::iter::once("colorum-cli").chain(args.iter().copied()))
+            .unwrap_or_else(|e| panic!("{args:?}: {e}"));
+        run(cli.command)
+    }
+
+    fn text(args: &[&str]) -> Vec<String> {
+        cli(args).unwrap_or_else(|f| panic!("{args:?}: {}", f.message)).text
+    }
+
+    fn code(args: &[&str]) -> u8 {
+        cli(args).err().map_or(0, |f| f.code)
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(text(&["name", "#FF6347"]), ["tomato"]);
+        let out = cli(&["--json", "name", "rgb(255 99 72)"]).ok().unwrap();
+        assert_eq!(out.json["exact"], false);
+        assert_eq!(code(&["name", "not a color"]), 2);
+    }
+
+    #[test]
+    fn hex() {
+        // a name in several palettes resolves CSS first, as in the GUI
+        assert_eq!(text(&["hex", "tomato"]), ["#FF6347"]);
+        assert_eq!(text(&["hex", "TOMATO", "--origin", "xkcd"]), ["#EF4026"]);
+        // words are joined; lowercase catalog hex comes out uppercase
+        assert_eq!(text(&["hex", "benelli", "(firearms)", "red"]), ["#E10600"]);
+        assert_eq!(text(&["hex", "RAL", "3020"]), ["#BB1E10"]);
+        let out = cli(&["hex", "Tomato", "--json"]).ok().unwrap();
+        assert_eq!(out.json, json!({ "name": "tomato", "hex": "#FF6347" }));
+        assert_eq!(code(&["hex", "no such color"]), 1);
+        assert_eq!(code(&["hex", "tomato", "--origin", "nowhere"]), 2);
+        assert!(Cli::try_parse_from(["colorum-cli", "hex"]).is_err());
+    }
+
+    #[test]
+    fn search() {
+        let lines = text(&["search", "sky", "blue", "--origin", "xkcd", "--mode", "all"]);
+        assert!(lines.iter().any(|l| l.ends_with("\tsky blue")), "{lines:?}");
+        assert!(lines.iter().all(|l| l.starts_with('#')));
+        assert_eq!(text(&["search", "red", "--limit", "2"]).len(), 2);
+        assert_eq!(code(&["search", "hue:abc"]), 2);
+        assert_eq!(code(&["search", "zzzzqqq", "--origin", "css"]), 1);
+        assert!(Cli::try_parse_from(["colorum-cli", "search", "red", "--mode", "nope"]).is_err());
+    }
+
+    #[test]
+    fn nearest() {
+        let lines = text(&["nearest", "#e10600", "-k", "3", "--metric", "oklab"]);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("#E10600\t"));
+        assert!(lines.iter().all(|l| l[..7] == l[..7].to_uppercase()));
+        let out = cli(&["nearest", "tomato", "-k", "1", "--origin", "css", "--json"]).ok().unwrap();
+        assert_eq!(out.json["matches"][0]["hex"], "#FF6347");
+        assert_eq!(out.json["metric"], DistanceMetric::Ciede2000.display_name());
+        assert!(Cli::try_parse_from(["colorum-cli", "nearest", "tomato", "-k", "0"]).is_err());
+    }
+
+    #[test]
+    fn convert() {
+        assert_eq!(text(&["convert", "tomato", "--to", "hex"]), ["#FF6347"]);
+        assert_eq!(text(&["convert", "#FF0000", "--to", "rgb"]), ["rgb(255, 0, 0)"]);
+        assert_eq!(text(&["convert", "#FFFFFF", "--to", "cmyk"]), ["cmyk(0%, 0%, 0%, 0%)"]);
+        let out = cli(&["convert", "white", "--to", "oklch", "--json"]).ok().unwrap();
+        assert_eq!(out.json["to"], "oklch");
+        assert!(Cli::try_parse_from(["colorum-cli", "convert", "red"]).is_err());
+    }
+
+    #[test]
+    fn contrast() {
+        assert_eq!(text(&["contrast", "black", "white"])[0], "21.00:1  APCA Lc 106.0");
+        let out = cli(&["contrast", "#777", "white", "--json"]).ok().unwrap();
+        assert_eq!((out.json["aa"].clone(), out.json["aa_large"].clone()), (json!(false), json!(true)));
+        assert_eq!(code(&["contrast", "black", "nope"]), 2);
+    }
+}
@@ -22,5 +22,7 @@ pub mod seasons;
 
 // Canadian provinces and territories palette
 pub mod canadian_provinces;
-mod ral_colors;
+
+// RAL Classic industrial colors
+pub mod ral_colors;
 
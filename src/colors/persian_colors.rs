@@ -1,16 +1,24 @@
-use crate::core::color_types::{HexCode, ColorName, Entity, Ordering};
+use crate::core::color_types::{Alias, HexCode, ColorName, Entity, Ordering};
 use crate::colors_helper::Origin;
 
 /// Persian color names with structured types.
-/// Each entry is a tuple of (HexCode, ColorName) with sortable components.
+/// Each entry is a tuple of (HexCode, ColorName) with sortable components;
+/// aliases give the Persian name and its transliteration.
 pub const COLORS_PERSIAN: &[(HexCode, ColorName)] = &[
-    (HexCode::new("#C81D11"), ColorName::new_full("Persian red", Entity::Place, Origin::Persian, Ordering::Name)),
+    (HexCode::new("#C81D11"), ColorName::new_full("Persian red", Entity::Place, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "قرمز ایرانی"), Alias::transliteration("fa", "qermez-e irani")])),
     (HexCode::new("#701C1C"), ColorName::new_full("Persian plum", Entity::Place, Origin::Persian, Ordering::Name)),
-    (HexCode::new("#F77FBE"), ColorName::new_full("Persian pink", Entity::Place, Origin::Persian, Ordering::Name)),
+    (HexCode::new("#F77FBE"), ColorName::new_full("Persian pink", Entity::Place, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "صورتی ایرانی"), Alias::transliteration("fa", "surati-ye irani")])),
     (HexCode::new("#FE28A2"), ColorName::new_full("Persian rose", Entity::Place, Origin::Persian, Ordering::Name)),
-    (HexCode::new("#00A693"), ColorName::new_full("Persian green", Entity::Place, Origin::Persian, Ordering::Name)),
-    (HexCode::new("#D99058"), ColorName::new_full("Persian orange", Entity::Place, Origin::Persian, Ordering::Name)),
-    (HexCode::new("#32127A"), ColorName::new_full("Persian indigo", Entity::Place, Origin::Persian, Ordering::Name)),
-    (HexCode::new("#1C39BB"), ColorName::new_full("Persian blue", Entity::Place, Origin::Persian, Ordering::Name)),
+    (HexCode::new("#00A693"), ColorName::new_full("Persian green", Entity::Place, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "سبز ایرانی"), Alias::transliteration("fa", "sabz-e irani")])),
+    (HexCode::new("#D99058"), ColorName::new_full("Persian orange", Entity::Place, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "نارنجی ایرانی"), Alias::transliteration("fa", "narenji-ye irani")])),
+    (HexCode::new("#32127A"), ColorName::new_full("Persian indigo", Entity::Place, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "نیلی ایرانی"), Alias::transliteration("fa", "nili-ye irani")])),
+    (HexCode::new("#1C39BB"), ColorName::new_full("Persian blue", Entity::Place, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "آبی ایرانی"), Alias::transliteration("fa", "abi-ye irani")])),
     (HexCode::new("#0067A5"), ColorName::new_full("Persian medium blue", Entity::Place, Origin::Persian, Ordering::Name)),
+    // colors named in Persian, or after it
+    (HexCode::new("#40E0D0"), ColorName::new_full("Turquoise", Entity::Color, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "فیروزه"), Alias::transliteration("fa", "firuzeh")])),
+    (HexCode::new("#26619C"), ColorName::new_full("Lapis lazuli", Entity::Color, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "لاجورد"), Alias::transliteration("fa", "lajvard")])),
+    (HexCode::new("#F4C430"), ColorName::new_full("Saffron", Entity::Color, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "زعفرانی"), Alias::transliteration("fa", "zafarani")])),
+    (HexCode::new("#C3B091"), ColorName::new_full("Khaki", Entity::Color, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "خاکی"), Alias::transliteration("fa", "khaki")])),
+    (HexCode::new("#C8A2C8"), ColorName::new_full("Lilac", Entity::Color, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "یاسی"), Alias::transliteration("fa", "yasi")])),
+    (HexCode::new("#93C572"), ColorName::new_full("Pistachio", Entity::Color, Origin::Persian, Ordering::Name).with_aliases(&[Alias::translation("fa", "پسته‌ای"), Alias::transliteration("fa", "pesteh-i")])),
 ];
@@ -1,69 +1,240 @@
-/// RAL Classic color palette (approximate sRGB hex values)
+use crate::core::color_types::{HexCode, ColorName, Entity, Ordering};
+use crate::colors_helper::Origin;
+
+/// RAL Classic colors (approximate sRGB hex values), ordered by RAL number.
 /// Source: RAL Digital / public conversion tables
-pub const COLORS_RAL_CLASSIC: &[(&str, &str)] = &[
-    ("#F6B600", "RAL 1003 Signal Yellow"),
-    ("#E5BE01", "RAL 1004 Golden Yellow"),
-    ("#CDA434", "RAL 1005 Honey Yellow"),
-    ("#A98307", "RAL 1006 Maize Yellow"),
-    ("#E4A010", "RAL 1007 Daffodil Yellow"),
-    ("#F5D033", "RAL 1018 Zinc Yellow"),
-    ("#FFCC00", "RAL 1021 Rape Yelloenw"),
-    ("#F39F18", "RAL 1023 Traffic Yellow"),
-    ("#FAFA37", "RAL 1026 Luminous Yellow"),
-    ("#E1CC4F", "RAL 1032 Broom Yellow"),
-    ("#6A5D4D", "RAL 1036 Pearl Beige"),
-    ("#D36E2D", "RAL 2000 Yellow Orange"),
-    ("#E55137", "RAL 2002 Vermilion"),
-    ("#F44611", "RAL 2004 Pure Orange"),
-    ("#FF7514", "RAL 2008 Bright Red Orange"),
-    ("#FF4F00", "RAL 2009 Traffic Orange"),
-    ("#FF2301", "RAL 2010 Signal Orange"),
-    ("#CA3C3C", "RAL 3000 Flame Red"),
-    ("#CC0605", "RAL 3020 Traffic Red"),
-    ("#7B1113", "RAL 3005 Wine Red"),
-    ("#B32428", "RAL 3003 Ruby Red"),
-    ("#B42041", "RAL 3011 Brown Red"),
-    ("#E63244", "RAL 3028 Pure Red"),
-    ("#5E2129", "RAL 3007 Black Red"),
-    ("#6D3F5B", "RAL 4001 Red Lilac"),
-    ("#70193D", "RAL 4004 Claret Violet"),
-    ("#A03472", "RAL 4006 Traffic Purple"),
-    ("#4A192C", "RAL 4007 Purple Violet"),
-    ("#7F3A44", "RAL 4002 Red Violet"),
-    ("#924E7D", "RAL 4008 Signal Violet"),
-    ("#44D7A8", "RAL 6019 Pastel Green"),
-    ("#287233", "RAL 6001 Emerald Green"),
-    ("#497E76", "RAL 6004 Blue Green"),
-    ("#316650", "RAL 6005 Moss Green"),
-    ("#1B542C", "RAL 6009 Fir Green"),
-    ("#2D572C", "RAL 6011 Reseda Green"),
-    ("#2F4538", "RAL 6012 Black Green"),
-    ("#3F888F", "RAL 5018 Turquoise Blue"),
-    ("#0072BB", "RAL 5015 Sky Blue"),
-    ("#1D1E33", "RAL 5004 Black Blue"),
-    ("#354D73", "RAL 5009 Azure Blue"),
-    ("#00416A", "RAL 5013 Cobalt Blue"),
-    ("#4169E1", "RAL 5017 Traffic Blue"),
-    ("#0E294B", "RAL 5003 Sapphire Blue"),
-    ("#20214F", "RAL 5002 Ultramarine Blue"),
-    ("#1F3438", "RAL 7016 Anthracite Grey"),
-    ("#8A9597", "RAL 7035 Light Grey"),
-    ("#474A51", "RAL 7021 Black Grey"),
-    ("#6C7C7C", "RAL 7031 Blue Grey"),
-    ("#4E5452", "RAL 7022 Umbra Grey"),
-    ("#817066", "RAL 8000 Green Brown"),
-    ("#955F20", "RAL 8003 Copper Brown"),
-    ("#592321", "RAL 8017 Chocolate Brown"),
-    ("#3B3C36", "RAL 8019 Grey Brown"),
-    ("#A65E2E", "RAL 8023 Orange Brown"),
-    ("#6F4C37", "RAL 8024 Beige Brown"),
-    ("#49392D", "RAL 8028 Terra Brown"),
-    ("#1E1E1E", "RAL 9005 Jet Black"),
-    ("#FDF4E3", "RAL 9001 Cream"),
-    ("#E7EBDA", "RAL 9002 Grey White"),
-    ("#FFFFFF", "RAL 9010 Pure White"),
-    ("#1C1C1C", "RAL 9011 Graphite Black"),
-    ("#D7D7D7", "RAL 9006 White Aluminium"),
-    ("#A5A5A5", "RAL 9007 Grey Aluminium"),
-    ("#EAE6CA", "RAL 9018 Papyrus White"),
-];
\ No newline at end of file
+pub const COLORS_RAL_CLASSIC: &[(HexCode, ColorName)] = &[
+    // 1xxx Yellow and beige
+    (HexCode::new("#CDBA88"), ColorName::new_full("RAL 1000 Green Beige", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#D0B084"), ColorName::new_full("RAL 1001 Beige", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#D2AA6D"), ColorName::new_full("RAL 1002 Sand Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F9A800"), ColorName::new_full("RAL 1003 Signal Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#E49E00"), ColorName::new_full("RAL 1004 Golden Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#CB8E00"), ColorName::new_full("RAL 1005 Honey Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#E29000"), ColorName::new_full("RAL 1006 Maize Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#E88C00"), ColorName::new_full("RAL 1007 Daffodil Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#AF804F"), ColorName::new_full("RAL 1011 Brown Beige", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#DDAF27"), ColorName::new_full("RAL 1012 Lemon Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#E3D9C6"), ColorName::new_full("RAL 1013 Oyster White", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#DDC49A"), ColorName::new_full("RAL 1014 Ivory", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#E6D2B5"), ColorName::new_full("RAL 1015 Light Ivory", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F1DD38"), ColorName::new_full("RAL 1016 Sulfur Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F6A950"), ColorName::new_full("RAL 1017 Saffron Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#FACA30"), ColorName::new_full("RAL 1018 Zinc Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#A48F7A"), ColorName::new_full("RAL 1019 Grey Beige", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#A08F65"), ColorName::new_full("RAL 1020 Olive Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F6B600"), ColorName::new_full("RAL 1021 Rape Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F7B500"), ColorName::new_full("RAL 1023 Traffic Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#BA8F4C"), ColorName::new_full("RAL 1024 Ochre Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#FFFF00"), ColorName::new_full("RAL 1026 Luminous Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#A77F0E"), ColorName::new_full("RAL 1027 Curry", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#FF9B00"), ColorName::new_full("RAL 1028 Melon Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#E2A300"), ColorName::new_full("RAL 1032 Broom Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F99A1C"), ColorName::new_full("RAL 1033 Dahlia Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#EB9C52"), ColorName::new_full("RAL 1034 Pastel Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#908370"), ColorName::new_full("RAL 1035 Pearl Beige", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#80643F"), ColorName::new_full("RAL 1036 Pearl Gold", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F09200"), ColorName::new_full("RAL 1037 Sun Yellow", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+
+    // 2xxx Orange
+    (HexCode::new("#DA6E00"), ColorName::new_full("RAL 2000 Yellow Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#BA481B"), ColorName::new_full("RAL 2001 Red Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#BF3922"), ColorName::new_full("RAL 2002 Vermilion", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F67828"), ColorName::new_full("RAL 2003 Pastel Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#E25303"), ColorName::new_full("RAL 2004 Pure Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#FF4D06"), ColorName::new_full("RAL 2005 Luminous Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#FFB200"), ColorName::new_full("RAL 2007 Luminous Bright Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#ED6B21"), ColorName::new_full("RAL 2008 Bright Red Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#DE5307"), ColorName::new_full("RAL 2009 Traffic Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#D05D28"), ColorName::new_full("RAL 2010 Signal Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#E26E0E"), ColorName::new_full("RAL 2011 Deep Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#D5654D"), ColorName::new_full("RAL 2012 Salmon Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#923E25"), ColorName::new_full("RAL 2013 Pearl Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#FC5500"), ColorName::new_full("RAL 2017 RAL Orange", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+
+    // 3xxx Red
+    (HexCode::new("#A72920"), ColorName::new_full("RAL 3000 Flame Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#9B2423"), ColorName::new_full("RAL 3001 Signal Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#9B2321"), ColorName::new_full("RAL 3002 Carmine Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#861A22"), ColorName::new_full("RAL 3003 Ruby Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#6B1C23"), ColorName::new_full("RAL 3004 Purple Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#59191F"), ColorName::new_full("RAL 3005 Wine Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#3E2022"), ColorName::new_full("RAL 3007 Black Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#6D342D"), ColorName::new_full("RAL 3009 Oxide Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#792423"), ColorName::new_full("RAL 3011 Brown Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#C6846D"), ColorName::new_full("RAL 3012 Beige Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#972E25"), ColorName::new_full("RAL 3013 Tomato Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#CB7375"), ColorName::new_full("RAL 3014 Antique Pink", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#D8A0A6"), ColorName::new_full("RAL 3015 Light Pink", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#A63D2F"), ColorName::new_full("RAL 3016 Coral Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#CB555D"), ColorName::new_full("RAL 3017 Rose", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#C73F4A"), ColorName::new_full("RAL 3018 Strawberry Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#BB1E10"), ColorName::new_full("RAL 3020 Traffic Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#CF6955"), ColorName::new_full("RAL 3022 Salmon Pink", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#FF2D21"), ColorName::new_full("RAL 3024 Luminous Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#FF2A1B"), ColorName::new_full("RAL 3026 Luminous Bright Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#AB273C"), ColorName::new_full("RAL 3027 Raspberry Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#CC2C24"), ColorName::new_full("RAL 3028 Pure Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#A63437"), ColorName::new_full("RAL 3031 Orient Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#701D23"), ColorName::new_full("RAL 3032 Pearl Ruby Red", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#A53A2D"), ColorName::new_full("RAL 3033 Pearl Pink", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+
+    // 4xxx Violet
+    (HexCode::new("#816183"), ColorName::new_full("RAL 4001 Red Lilac", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#8D3C4B"), ColorName::new_full("RAL 4002 Red Violet", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#C4618C"), ColorName::new_full("RAL 4003 Heather Violet", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#651E38"), ColorName::new_full("RAL 4004 Claret Violet", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#76689A"), ColorName::new_full("RAL 4005 Blue Lilac", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#903373"), ColorName::new_full("RAL 4006 Traffic Purple", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#47243C"), ColorName::new_full("RAL 4007 Purple Violet", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#844C82"), ColorName::new_full("RAL 4008 Signal Violet", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#9D8692"), ColorName::new_full("RAL 4009 Pastel Violet", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#BC4077"), ColorName::new_full("RAL 4010 Telemagenta", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#6E6387"), ColorName::new_full("RAL 4011 Pearl Violet", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#6B6B7F"), ColorName::new_full("RAL 4012 Pearl Blackberry", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+
+    // 5xxx Blue
+    (HexCode::new("#314F6F"), ColorName::new_full("RAL 5000 Violet Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#0F4C64"), ColorName::new_full("RAL 5001 Green Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#00387B"), ColorName::new_full("RAL 5002 Ultramarine Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#1F3855"), ColorName::new_full("RAL 5003 Sapphire Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#191E28"), ColorName::new_full("RAL 5004 Black Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#005387"), ColorName::new_full("RAL 5005 Signal Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#376B8C"), ColorName::new_full("RAL 5007 Brilliant Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#2B3A44"), ColorName::new_full("RAL 5008 Grey Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#225F78"), ColorName::new_full("RAL 5009 Azure Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#004F7C"), ColorName::new_full("RAL 5010 Gentian Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#1A2B3C"), ColorName::new_full("RAL 5011 Steel Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#0089B6"), ColorName::new_full("RAL 5012 Light Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#193153"), ColorName::new_full("RAL 5013 Cobalt Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#637D96"), ColorName::new_full("RAL 5014 Pigeon Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#007CB0"), ColorName::new_full("RAL 5015 Sky Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#005B8C"), ColorName::new_full("RAL 5017 Traffic Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#058B8C"), ColorName::new_full("RAL 5018 Turquoise Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#005E83"), ColorName::new_full("RAL 5019 Capri Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#00414B"), ColorName::new_full("RAL 5020 Ocean Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#007577"), ColorName::new_full("RAL 5021 Water Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#222D5A"), ColorName::new_full("RAL 5022 Night Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#42698C"), ColorName::new_full("RAL 5023 Distant Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#6093AC"), ColorName::new_full("RAL 5024 Pastel Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#21697C"), ColorName::new_full("RAL 5025 Pearl Gentian Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#0F3052"), ColorName::new_full("RAL 5026 Pearl Night Blue", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+
+    // 6xxx Green
+    (HexCode::new("#3C7460"), ColorName::new_full("RAL 6000 Patina Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#366735"), ColorName::new_full("RAL 6001 Emerald Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#325928"), ColorName::new_full("RAL 6002 Leaf Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#50533C"), ColorName::new_full("RAL 6003 Olive Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#024442"), ColorName::new_full("RAL 6004 Blue Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#114232"), ColorName::new_full("RAL 6005 Moss Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#3C392E"), ColorName::new_full("RAL 6006 Grey Olive", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#2C3222"), ColorName::new_full("RAL 6007 Bottle Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#37342A"), ColorName::new_full("RAL 6008 Brown Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#27352A"), ColorName::new_full("RAL 6009 Fir Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#4D6F39"), ColorName::new_full("RAL 6010 Grass Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#6B7C59"), ColorName::new_full("RAL 6011 Reseda Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#2F3D3A"), ColorName::new_full("RAL 6012 Black Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#7C765A"), ColorName::new_full("RAL 6013 Reed Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#474135"), ColorName::new_full("RAL 6014 Yellow Olive", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#3D3D36"), ColorName::new_full("RAL 6015 Black Olive", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#00694C"), ColorName::new_full("RAL 6016 Turquoise Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#587F40"), ColorName::new_full("RAL 6017 May Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#61993B"), ColorName::new_full("RAL 6018 Yellow Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#B9CEAC"), ColorName::new_full("RAL 6019 Pastel Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#37422F"), ColorName::new_full("RAL 6020 Chrome Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#8A9977"), ColorName::new_full("RAL 6021 Pale Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#3A3327"), ColorName::new_full("RAL 6022 Olive Drab", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#008351"), ColorName::new_full("RAL 6024 Traffic Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#5E6E3B"), ColorName::new_full("RAL 6025 Fern Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#005F4E"), ColorName::new_full("RAL 6026 Opal Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#7EBAB5"), ColorName::new_full("RAL 6027 Light Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#315442"), ColorName::new_full("RAL 6028 Pine Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#006F3D"), ColorName::new_full("RAL 6029 Mint Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#237F52"), ColorName::new_full("RAL 6032 Signal Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#46877F"), ColorName::new_full("RAL 6033 Mint Turquoise", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#7AACAC"), ColorName::new_full("RAL 6034 Pastel Turquoise", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#194D25"), ColorName::new_full("RAL 6035 Pearl Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#04574B"), ColorName::new_full("RAL 6036 Pearl Opal Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#008B29"), ColorName::new_full("RAL 6037 Pure Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#00B51A"), ColorName::new_full("RAL 6038 Luminous Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#B3C43E"), ColorName::new_full("RAL 6039 Fibrous Green", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+
+    // 7xxx Grey
+    (HexCode::new("#7A888E"), ColorName::new_full("RAL 7000 Squirrel Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#8C979C"), ColorName::new_full("RAL 7001 Silver Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#817863"), ColorName::new_full("RAL 7002 Olive Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#797669"), ColorName::new_full("RAL 7003 Moss Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#9A9B9B"), ColorName::new_full("RAL 7004 Signal Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#6B6E6B"), ColorName::new_full("RAL 7005 Mouse Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#766A5E"), ColorName::new_full("RAL 7006 Beige Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#745F3D"), ColorName::new_full("RAL 7008 Khaki Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#5D6058"), ColorName::new_full("RAL 7009 Green Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#585C56"), ColorName::new_full("RAL 7010 Tarpaulin Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#52595D"), ColorName::new_full("RAL 7011 Iron Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#575D5E"), ColorName::new_full("RAL 7012 Basalt Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#575044"), ColorName::new_full("RAL 7013 Brown Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#4F5358"), ColorName::new_full("RAL 7015 Slate Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#383E42"), ColorName::new_full("RAL 7016 Anthracite Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#2F3234"), ColorName::new_full("RAL 7021 Black Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#4C4A44"), ColorName::new_full("RAL 7022 Umbra Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#808076"), ColorName::new_full("RAL 7023 Concrete Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#45494E"), ColorName::new_full("RAL 7024 Graphite Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#374345"), ColorName::new_full("RAL 7026 Granite Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#928E85"), ColorName::new_full("RAL 7030 Stone Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#5B686D"), ColorName::new_full("RAL 7031 Blue Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#B5B0A1"), ColorName::new_full("RAL 7032 Pebble Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#7F8274"), ColorName::new_full("RAL 7033 Cement Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#92886F"), ColorName::new_full("RAL 7034 Yellow Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#C5C7C4"), ColorName::new_full("RAL 7035 Light Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#979392"), ColorName::new_full("RAL 7036 Platinum Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#7A7B7A"), ColorName::new_full("RAL 7037 Dusty Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#B0B0A9"), ColorName::new_full("RAL 7038 Agate Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#6B665E"), ColorName::new_full("RAL 7039 Quartz Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#989EA1"), ColorName::new_full("RAL 7040 Window Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#8E9291"), ColorName::new_full("RAL 7042 Traffic Grey A", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#4F5250"), ColorName::new_full("RAL 7043 Traffic Grey B", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#B7B3A8"), ColorName::new_full("RAL 7044 Silk Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#8D9295"), ColorName::new_full("RAL 7045 Telegrey 1", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#7F868A"), ColorName::new_full("RAL 7046 Telegrey 2", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#C8C8C7"), ColorName::new_full("RAL 7047 Telegrey 4", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#817B73"), ColorName::new_full("RAL 7048 Pearl Mouse Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+
+    // 8xxx Brown
+    (HexCode::new("#89693E"), ColorName::new_full("RAL 8000 Green Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#9D622B"), ColorName::new_full("RAL 8001 Ochre Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#794D3E"), ColorName::new_full("RAL 8002 Signal Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#7E4B26"), ColorName::new_full("RAL 8003 Clay Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#8D4931"), ColorName::new_full("RAL 8004 Copper Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#70452A"), ColorName::new_full("RAL 8007 Fawn Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#724A25"), ColorName::new_full("RAL 8008 Olive Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#5A3826"), ColorName::new_full("RAL 8011 Nut Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#66332B"), ColorName::new_full("RAL 8012 Red Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#4A3526"), ColorName::new_full("RAL 8014 Sepia Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#5E2F26"), ColorName::new_full("RAL 8015 Chestnut Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#4C2B20"), ColorName::new_full("RAL 8016 Mahogany Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#442F29"), ColorName::new_full("RAL 8017 Chocolate Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#3D3635"), ColorName::new_full("RAL 8019 Grey Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#1A1719"), ColorName::new_full("RAL 8022 Black Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#A45729"), ColorName::new_full("RAL 8023 Orange Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#795038"), ColorName::new_full("RAL 8024 Beige Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#755847"), ColorName::new_full("RAL 8025 Pale Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#513A2A"), ColorName::new_full("RAL 8028 Terra Brown", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#7F4031"), ColorName::new_full("RAL 8029 Pearl Copper", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+
+    // 9xxx White and black
+    (HexCode::new("#E9E0D2"), ColorName::new_full("RAL 9001 Cream", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#D7D5CB"), ColorName::new_full("RAL 9002 Grey White", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#ECECE7"), ColorName::new_full("RAL 9003 Signal White", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#2B2B2C"), ColorName::new_full("RAL 9004 Signal Black", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#0E0E10"), ColorName::new_full("RAL 9005 Jet Black", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#A1A1A0"), ColorName::new_full("RAL 9006 White Aluminium", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#878581"), ColorName::new_full("RAL 9007 Grey Aluminium", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F1ECE1"), ColorName::new_full("RAL 9010 Pure White", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#27292B"), ColorName::new_full("RAL 9011 Graphite Black", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F8F2E1"), ColorName::new_full("RAL 9012 Cleanroom White", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#F1F0EA"), ColorName::new_full("RAL 9016 Traffic White", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#2A292A"), ColorName::new_full("RAL 9017 Traffic Black", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#C8CBC4"), ColorName::new_full("RAL 9018 Papyrus White", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#858583"), ColorName::new_full("RAL 9022 Pearl Light Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+    (HexCode::new("#797B7A"), ColorName::new_full("RAL 9023 Pearl Dark Grey", Entity::Color, Origin::RalClassic, Ordering::Integer)),
+];
@@ -0,0 +1,142 @@
+// src/colors_helper/aliases.rs
+//
+// Other names a color can be found by. Palette data attaches translations and
+// transliterations with `ColorName::with_aliases`; British/American spellings
+// (grey/gray, colour/color) are generated here, so every "Slate Gray" is also
+// "slate grey" without listing it. Search indexes all of them and
+// `matched_alias` tells the UI which one a query hit.
+use super::*;
+use super::search::{fold_text, tokenize_lc};
+use crate::core::color_types::{Alias, ColorName};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+// Either spelling finds the other
+const SPELLINGS: &[(&str, &str)] = &[("grey", "gray"), ("colour", "color")];
+
+// The name with every grey/gray-style word swapped for its other spelling, if it has one.
+// Only whole words are swapped, plus the tail of a compound like "darkslategray";
+// "Colorado" and "grayish" are left alone.
+fn spelling_variant(name: &str) -> Option<String> {
+    let lower = name.to_lowercase();
+    // byte offsets only line up when lowercasing kept the length
+    let mut src = if lower.len() == name.len() { name.to_string() } else { lower.clone() };
+    let mut changed = false;
+    for &(a, b) in SPELLINGS {
+        for (from, to) in [(a, b), (b, a)] {
+            let lower_now = src.to_lowercase();
+            let mut out = String::with_capacity(src.len());
+            let mut last = 0;
+            for (at, _) in lower_now.match_indices(from) {
+                if lower_now[at + from.len()..].starts_with(char::is_alphanumeric) {
+                    continue;
+                }
+                out.push_str(&src[last..at]);
+                // keep a capital first letter ("Grey" → "Gray")
+                if src[at..].starts_with(|c: char| c.is_uppercase()) {
+                    out.push_str(&to[..1].to_uppercase());
+                    out.push_str(&to[1..]);
+                } else {
+                    out.push_str(to);
+                }
+                last = at + from.len();
+                changed = true;
+            }
+            if last > 0 {
+                out.push_str(&src[last..]);
+                src = out;
+                // a name spelled one way only gets the other; don't swap it back
+                break;
+            }
+        }
+    }
+    changed.then_some(src)
+}
+
+// Generated spellings are made once and kept, so they can be handed out as `Alias`
+fn intern(s: String) -> &'static str {
+    static SEEN: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+    let mut seen = SEEN.lock().unwrap_or_else(|e| e.into_inner());
+    match seen.get(s.as_str()) {
+        Some(&interned) => interned,
+        None => {
+            let interned: &'static str = s.leak();
+            seen.insert(interned);
+            interned
+        }
+    }
+}
+
+/// Every alias of `name`: the ones in the palette data, then its other spelling
+/// ("Dark Slate Gray" → "Dark Slate Grey") when it has one
+pub fn aliases_of(name: ColorName) -> Vec<Alias> {
+    let mut out = name.aliases().to_vec();
+    if let Some(variant) = spelling_variant(name.as_str())
+        && !out.iter().any(|a| a.text.eq_ignore_ascii_case(&variant))
+    {
+        out.push(Alias::spelling(intern(variant)));
+    }
+    out
+}
+
+/// Everything `name` can be found by, folded and one per line. Lines keep a
+/// phrase from matching across two aliases.
+pub(super) fn haystack(name: ColorName) -> String {
+    let mut out = fold_text(name.as_str());
+    let spelling = spelling_variant(name.as_str());
+    for text in name.aliases().iter().map(|a| a.text).chain(spelling.as_deref()) {
+        out.push('\n');
+        out.push_str(&fold_text(text));
+    }
+    out
+}
+
+/// The primary name and every alias, for building search keys
+pub(super) fn search_names(name: ColorName) -> impl Iterator<Item = Cow<'static, str>> {
+    let spelling = spelling_variant(name.as_str());
+    std::iter::once(Cow::Borrowed(name.as_str()))
+        .chain(name.aliases().iter().map(|a| Cow::Borrowed(a.text)))
+        .chain(spelling.map(Cow::Owned))
+}
+
+/// The alias a search for `query` found `name` by, or `None` when the primary
+/// name matches by itself (or nothing does). Clauses in `query` are ignored.
+///
+/// ```
+/// use colorum::colors_helper::{Origin, TokenMode, matched_alias, search_in_origin};
+///
+/// let (_, name) = search_in_origin(Origin::Hindi, "aasmani", TokenMode::All)[0];
+/// assert_eq!(name.as_str(), "Sky Blue");
+/// assert_eq!(matched_alias(name, "aasmani").map(|a| a.text), Some("aasmani"));
+/// assert_eq!(matched_alias(name, "sky"), None);
+/// ```
+pub fn matched_alias(name: ColorName, query: &str) -> Option<Alias> {
+    let text = parse_query(query).ok()?.text();
+    let words: Vec<String> = tokenize_lc(&text).collect();
+    if words.is_empty() {
+        return None;
+    }
+    let hits = |candidate: &str| {
+        let folded = fold_text(candidate);
+        words.iter().all(|w| folded.contains(w.as_str()))
+    };
+    if hits(name.as_str()) {
+        return None;
+    }
+    let aliases = aliases_of(name);
+    aliases
+        .iter()
+        .find(|a| hits(a.text))
+        // typo-tolerant searches can reach an alias the plain check misses
+        .or_else(|| {
+            let primary = score_name(&text, name.as_str()).map_or(f32::MIN, |(_, score)| score);
+            aliases
+                .iter()
+                .filter_map(|a| score_name(&text, a.text).map(|(_, score)| (a, score)))
+                .filter(|&(_, score)| score > primary)
+                .max_by(|x, y| x.1.total_cmp(&y.1))
+                .map(|(a, _)| a)
+        })
+        .copied()
+}
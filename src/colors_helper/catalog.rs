@@ -1,7 +1,6 @@
 // src/colors_helper/catalog.rs
 use super::*;
 use std::collections::HashMap;
-use crate::colors::kelvin_colors::KELVIN_COLORS;
 use crate::core::color_types::{HexCode, ColorName};
 
 pub enum ColorsFor {
@@ -34,63 +33,51 @@ impl ColorsFor {
     }
 }
 
-// Per-origin slice
+// Per-origin slice, looked up from the registry
 pub fn origin_slice(origin: Origin) -> &'static [(HexCode, ColorName)] {
-    match origin {
-        Origin::All => COMBINED_COLORS.as_slice(),
-        Origin::Css => COLORS_CSS,
-        Origin::XKCD => COLORS_XKCD,
-        Origin::Pantone => COLORS_PANTONE,
-        Origin::Hindi => COLORS_HINDI,
-        Origin::Persian => COLORS_PERSIAN,
-        Origin::National => COLORS_NATIONAL.as_slice(),
-        Origin::Brands => COLORS_BRANDS,
-        Origin::ItalianBrands => COLORS_ITALIANBRANDS.as_slice(),
-        Origin::MetalFlames => COLORS_METALS_FLAME,
-        Origin::KelvinColors => KELVIN_COLORS.as_slice(),
-        #[cfg(feature = "github-colors")]
-        Origin::GitHub => COLORS_GITHUB,
-        // New palette system - direct access to ensure data is available
-        Origin::Seasons => crate::colors::seasons::DATA,
-        Origin::CanadianProvinces => crate::colors::canadian_provinces::DATA,
+    if origin == Origin::All {
+        return COMBINED_COLORS.as_slice();
+    }
+    if let Some(c) = origin.catalog() {
+        return (c.data)();
     }
+    imported_palette(origin).map_or(&[], |p| p.colors)
 }
 
 pub static REGISTRY_MAP: LazyLock<
     HashMap<Origin, fn() -> &'static [(HexCode, ColorName)]>,
 > = LazyLock::new(|| {
-    let mut m = HashMap::with_capacity(REGISTRY.len());
-    for c in REGISTRY {
+    let mut m = HashMap::with_capacity(catalogs().len());
+    for c in catalogs() {
         m.insert(c.origin, c.data); // store the function pointer
     }
     m
 });
 
 pub fn colors_for(origin: Origin) -> &'static [(HexCode, ColorName)] {
-    if let Origin::All = origin {
-        return COMBINED_COLORS.as_slice();
-    }
-    REGISTRY_MAP.get(&origin).map(|f| f()).unwrap_or(&[])
+    origin_slice(origin)
 }
 
 pub static COMBINED_COLORS: LazyLock<Vec<(HexCode, ColorName)>> = LazyLock::new(|| {
     let mut v = Vec::new();
-    for c in REGISTRY {
+    for c in catalogs() {
         v.extend_from_slice((c.data)());
     }
     v
 });
-// Name lookups
+// Name lookups. A name shared by several palettes resolves to the first one
+// in registry order (CSS first), the same entry a scan of COMBINED_COLORS finds.
 pub static COLORS_BY_NAME: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
-    COMBINED_COLORS
-        .iter()
-        .map(|(hex, name)| (name.as_str(), hex.as_str()))
-        .collect()
+    let mut m = HashMap::new();
+    for (hex, name) in COMBINED_COLORS.as_slice() {
+        m.entry(name.as_str()).or_insert(hex.as_str());
+    }
+    m
 });
 pub static COLORS_BY_NAME_LC: LazyLock<HashMap<String, &'static str>> = LazyLock::new(|| {
     let mut m = HashMap::new();
     for (hex, name) in COMBINED_COLORS.as_slice() {
-        m.insert(name.as_str().to_lowercase(), hex.as_str());
+        m.entry(name.as_str().to_lowercase()).or_insert(hex.as_str());
     }
     m
 });
@@ -101,11 +88,27 @@ pub fn lookup_by_name_ci(name: &str) -> Option<&'static str> {
     COLORS_BY_NAME_LC.get(&name.to_lowercase()).copied()
 }
 
+// The same first-wins lookup per origin, keeping the whole entry
+static ORIGIN_NAMES_LC_CACHE: PaletteCache<HashMap<String, (HexCode, ColorName)>> = PaletteCache::new();
+
+/// [`lookup_by_name_ci`] within one origin, returning the catalog entry so the
+/// caller also gets the name as the catalog spells it
+pub fn lookup_in_origin_ci(origin: Origin, name: &str) -> Option<(HexCode, ColorName)> {
+    let map = ORIGIN_NAMES_LC_CACHE.get_or_build(origin, || {
+        let mut m = HashMap::new();
+        for &(hex, name) in origin_slice(origin) {
+            m.entry(name.as_str().to_lowercase()).or_insert((hex, name));
+        }
+        m
+    });
+    map.get(&name.to_lowercase()).copied()
+}
+
 // Sorted names & ranks
 fn build_sorted_names(origin: Origin) -> Box<[&'static str]> {
     let slice = colors_for(origin);
-    let mut names: Vec<&'static str> = slice.iter().map(|(_h, n)| n.as_str()).collect();
     if matches!(origin, Origin::All) {
+        let mut names: Vec<&'static str> = slice.iter().map(|(_h, n)| n.as_str()).collect();
         names.sort_unstable_by(|a, b| {
             let ha = lookup_by_name(a).unwrap_or("#000000");
             let hb = lookup_by_name(b).unwrap_or("#000000");
@@ -113,9 +116,14 @@ fn build_sorted_names(origin: Origin) -> Box<[&'static str]> {
                 .cmp(&super::sort::origin_group_priority(hb, b))
                 .then_with(|| a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase()))
         });
+        names.into_boxed_slice()
     } else {
-        names.sort_unstable_by(|a, b| a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase()));
+        build_sorted_names_for(slice)
     }
+}
+fn build_sorted_names_for(slice: &[(HexCode, ColorName)]) -> Box<[&'static str]> {
+    let mut names: Vec<&'static str> = slice.iter().map(|(_h, n)| n.as_str()).collect();
+    names.sort_unstable_by_key(|a| a.to_ascii_lowercase());
     names.into_boxed_slice()
 }
 fn build_rank_map(names: &[&'static str]) -> HashMap<&'static str, usize> {
@@ -125,45 +133,14 @@ fn build_rank_map(names: &[&'static str]) -> HashMap<&'static str, usize> {
     }
     m
 }
-// Consolidated origin name caching
-pub static ORIGIN_NAMES_CACHE: LazyLock<HashMap<Origin, Box<[&'static str]>>> = LazyLock::new(|| {
-    let mut cache = HashMap::new();
-
-    // Build for all origins
-    for &origin in &[
-        Origin::All, Origin::Css, Origin::XKCD, Origin::Pantone,
-        Origin::Hindi, Origin::Persian, Origin::National, Origin::Brands,
-        Origin::ItalianBrands, Origin::MetalFlames, Origin::KelvinColors,
-        #[cfg(feature = "github-colors")]
-        Origin::GitHub,
-    ] {
-        cache.insert(origin, build_sorted_names(origin));
-    }
-
-    cache
-});
-// Consolidated origin rank caching
-pub static ORIGIN_RANK_CACHE: LazyLock<HashMap<Origin, HashMap<&'static str, usize>>> = LazyLock::new(|| {
-    let mut cache = HashMap::new();
-
-    // Build ranks for all origins using the name cache
-    for (&origin, names) in ORIGIN_NAMES_CACHE.iter() {
-        cache.insert(origin, build_rank_map(names));
-    }
-
-    cache
-});
+// Sorted names and ranks per origin, built on first use
+static ORIGIN_NAMES_CACHE: PaletteCache<Box<[&'static str]>> = PaletteCache::new();
+static ORIGIN_RANK_CACHE: PaletteCache<HashMap<&'static str, usize>> = PaletteCache::new();
 
 pub fn origin_names(origin: Origin) -> &'static [&'static str] {
-    ORIGIN_NAMES_CACHE.get(&origin)
-        .map(|names| names.as_ref())
-        .unwrap_or(&[])
+    ORIGIN_NAMES_CACHE.get_or_build(origin, || build_sorted_names(origin))
 }
 
 pub fn origin_rank(origin: Origin) -> &'static HashMap<&'static str, usize> {
-    ORIGIN_RANK_CACHE.get(&origin)
-        .unwrap_or(&EMPTY_RANK_MAP)
+    ORIGIN_RANK_CACHE.get_or_build(origin, || build_rank_map(origin_names(origin)))
 }
-
-// Empty fallback to avoid Option handling
-static EMPTY_RANK_MAP: LazyLock<HashMap<&'static str, usize>> = LazyLock::new(|| HashMap::new());
@@ -0,0 +1,216 @@
+// src/colors_helper/fuzzy.rs
+//
+// Ranked, typo-tolerant name search behind `TokenMode::Fuzzy`.
+//
+// Every name is scored against the query and results come back best first,
+// instead of through the CSS / XKCD / other buckets of `sort_dropdown_by_origin`.
+// A score is the match kind (exact > prefix > token > fuzzy) plus a fraction
+// for how close the match is within that kind, so any exact match outranks
+// every prefix match and so on.
+use super::*;
+use super::search::{fold_text, tokenize_lc};
+use crate::core::color_types::{Alias, ColorName, HexCode};
+
+/// How a name matched the query, weakest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchKind {
+    /// Within a few edits of the query ("turquise" → "turquoise")
+    Fuzzy,
+    /// Every query word is a word of the name, or the start of one
+    Token,
+    /// The name starts with the query
+    Prefix,
+    /// The name is the query, ignoring case, spaces and punctuation
+    Exact,
+}
+
+/// One ranked search hit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoredMatch {
+    pub hex: HexCode,
+    pub name: ColorName,
+    pub kind: MatchKind,
+    /// `kind` as 0..=3 plus a closeness fraction in `[0, 1)`; higher is better
+    pub score: f32,
+    /// The alias that scored best, when it beat the primary name
+    pub alias: Option<Alias>,
+}
+
+// folded letters and digits only: "Sky-Blue" → "skyblue", "Crème" → "creme"
+fn compact(s: &str) -> Vec<char> {
+    fold_text(s).chars().filter(|c| c.is_alphanumeric()).collect()
+}
+
+// typos allowed for a word of this many characters
+fn max_edits(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+// Optimal string alignment distance (insert, delete, substitute, swap
+// neighbours), or None as soon as it must exceed `limit`.
+fn edit_distance(a: &[char], b: &[char], limit: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > limit {
+        return None;
+    }
+    let mut prev2: Vec<usize> = Vec::new();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut cur = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cur[j] = cur[j].min(prev2[j - 2] + 1);
+            }
+        }
+        if cur.iter().min().is_some_and(|&m| m > limit) {
+            return None;
+        }
+        prev2 = std::mem::replace(&mut prev, cur);
+    }
+    Some(prev[b.len()]).filter(|&d| d <= limit)
+}
+
+// A name broken up once for scoring
+struct NameKey {
+    compact: Vec<char>,
+    tokens: Vec<Vec<char>>,
+}
+
+impl NameKey {
+    fn new(name: &str) -> Self {
+        Self {
+            compact: compact(name),
+            tokens: tokenize_lc(name).map(|t| t.chars().collect()).collect(),
+        }
+    }
+}
+
+// A palette entry's primary name and aliases, broken up once
+struct EntryKeys {
+    primary: NameKey,
+    aliases: Vec<(Alias, NameKey)>,
+}
+
+impl EntryKeys {
+    fn new(name: ColorName) -> Self {
+        Self {
+            primary: NameKey::new(name.as_str()),
+            aliases: aliases_of(name).into_iter().map(|a| (a, NameKey::new(a.text))).collect(),
+        }
+    }
+
+    // The best score over the primary name and every alias
+    fn score(&self, query_compact: &[char], query_tokens: &[Vec<char>]) -> Option<(MatchKind, f32, Option<Alias>)> {
+        let primary = score_key(query_compact, query_tokens, &self.primary).map(|(k, c)| (k, c, None));
+        self.aliases
+            .iter()
+            .filter_map(|(alias, key)| score_key(query_compact, query_tokens, key).map(|(k, c)| (k, c, Some(*alias))))
+            .fold(primary, |best, hit| match best {
+                Some(b) if (b.0, b.1) >= (hit.0, hit.1) => Some(b),
+                _ => Some(hit),
+            })
+    }
+}
+
+static NAME_KEYS: PaletteCache<Vec<EntryKeys>> = PaletteCache::new();
+
+fn name_keys(origin: Origin) -> &'static [EntryKeys] {
+    NAME_KEYS.get_or_build(origin, || origin_slice(origin).iter().map(|(_, n)| EntryKeys::new(*n)).collect())
+}
+
+// How well one query word matches the best word of the name: (is fuzzy, quality 0..=1)
+fn token_match(q: &[char], tokens: &[Vec<char>]) -> Option<(bool, f32)> {
+    let mut best: Option<(bool, f32)> = None;
+    let mut consider = |hit: (bool, f32)| {
+        if best.is_none_or(|b| (!hit.0, hit.1) > (!b.0, b.1)) {
+            best = Some(hit);
+        }
+    };
+    for t in tokens {
+        if t == q {
+            return Some((false, 1.0));
+        }
+        if t.starts_with(q) {
+            consider((false, q.len() as f32 / t.len() as f32));
+        } else if let Some(d) = edit_distance(q, t, max_edits(q.len())) {
+            consider((true, 1.0 - d as f32 / (q.len() + 1) as f32));
+        }
+    }
+    best
+}
+
+fn score_key(query_compact: &[char], query_tokens: &[Vec<char>], key: &NameKey) -> Option<(MatchKind, f32)> {
+    if query_compact.is_empty() || key.compact.is_empty() {
+        return None;
+    }
+    // how much of the name the query covers
+    let coverage = (query_compact.len() as f32 / key.compact.len() as f32).min(1.0);
+    // keep closeness strictly below 1 so it never spills into the next kind
+    let closeness = |x: f32| x.clamp(0.0, 1.0) * 0.999;
+
+    if key.compact == query_compact {
+        return Some((MatchKind::Exact, closeness(1.0)));
+    }
+    if key.compact.starts_with(query_compact) {
+        return Some((MatchKind::Prefix, closeness(coverage)));
+    }
+
+    let words: Option<Vec<(bool, f32)>> =
+        query_tokens.iter().map(|q| token_match(q, &key.tokens)).collect();
+    if let Some(words) = words.filter(|w| !w.is_empty()) {
+        let fuzzy = words.iter().any(|w| w.0);
+        let quality = words.iter().map(|w| w.1).sum::<f32>() / words.len() as f32;
+        let kind = if fuzzy { MatchKind::Fuzzy } else { MatchKind::Token };
+        return Some((kind, closeness((quality + coverage) / 2.0)));
+    }
+
+    // the whole name with a typo, spaces ignored ("skyblu", "dodgerbleu")
+    let d = edit_distance(query_compact, &key.compact, max_edits(query_compact.len()))?;
+    Some((MatchKind::Fuzzy, closeness(1.0 - d as f32 / (query_compact.len() + 1) as f32)))
+}
+
+/// Score `name` against `query`; `None` when it doesn't match at all
+pub fn score_name(query: &str, name: &str) -> Option<(MatchKind, f32)> {
+    let query_tokens: Vec<Vec<char>> = tokenize_lc(query).map(|t| t.chars().collect()).collect();
+    score_key(&compact(query), &query_tokens, &NameKey::new(name))
+        .map(|(kind, closeness)| (kind, kind as u8 as f32 + closeness))
+}
+
+/// Every name in `origin` that matches `query`, best first (ties by name).
+/// Clauses such as `entity:brand` or `hue:0..30` filter the matches; see [`parse_query`].
+pub fn search_scored(origin: Origin, query: &str) -> Vec<ScoredMatch> {
+    parse_query(query).map_or_else(|_| Vec::new(), |q| scored_for_query(origin, &q))
+}
+
+pub(super) fn scored_for_query(origin: Origin, query: &Query) -> Vec<ScoredMatch> {
+    let text = query.text();
+    let query_compact = compact(&text);
+    let query_tokens: Vec<Vec<char>> = tokenize_lc(&text).map(|t| t.chars().collect()).collect();
+
+    let mut out: Vec<ScoredMatch> = Vec::new();
+    for source in query.sources(origin) {
+        out.extend(
+            origin_slice(source)
+                .iter()
+                .zip(name_keys(source))
+                .filter(|((hex, name), _)| query.matches(*hex, *name))
+                .filter_map(|(&(hex, name), keys)| {
+                    let (kind, closeness, alias) = keys.score(&query_compact, &query_tokens)?;
+                    Some(ScoredMatch { hex, name, kind, score: kind as u8 as f32 + closeness, alias })
+                }),
+        );
+    }
+
+    out.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.name.as_str().to_lowercase().cmp(&b.name.as_str().to_lowercase()))
+    });
+    out.truncate(MAX_RESULTS);
+    out
+}
@@ -0,0 +1,70 @@
+// src/colors_helper/imported.rs
+//
+// Palettes registered at runtime (e.g. loaded from a user's .gpl/.ase/.csv/.json).
+// Each one gets a `PaletteId` after the built-in ones and lives for the rest of
+// the program, so its strings are leaked to fit the `&'static` catalog types.
+use super::*;
+use crate::core::color_types::{Entity, Ordering};
+use crate::core::rgb::{Rgb, rgb_to_hex};
+use std::sync::RwLock;
+
+/// A palette registered at runtime
+pub struct ImportedPalette {
+    pub name: &'static str,
+    pub colors: &'static [(HexCode, ColorName)],
+}
+
+static IMPORTED: RwLock<Vec<&'static ImportedPalette>> = RwLock::new(Vec::new());
+
+/// Register a palette under `name` and return its id.
+/// Entries with an empty name are named after their hex.
+pub fn register_palette(
+    name: &str,
+    colors: impl IntoIterator<Item = (String, Rgb)>,
+) -> Origin {
+    let mut list = IMPORTED.write().unwrap_or_else(|e| e.into_inner());
+    let origin = u16::try_from(list.len())
+        .ok()
+        .and_then(|n| n.checked_add(PaletteId::FIRST_IMPORTED))
+        .map(PaletteId::from_raw)
+        .expect("too many imported palettes");
+
+    let colors: Vec<(HexCode, ColorName)> = colors
+        .into_iter()
+        .map(|(swatch_name, rgb)| {
+            let hex = rgb_to_hex(rgb);
+            let swatch_name = match swatch_name.trim() {
+                "" => hex.clone(),
+                n => n.to_string(),
+            };
+            (
+                HexCode::new(hex.leak()),
+                ColorName::new_full(swatch_name.leak(), Entity::Color, origin, Ordering::Name),
+            )
+        })
+        .collect();
+
+    list.push(Box::leak(Box::new(ImportedPalette {
+        name: name.trim().to_string().leak(),
+        colors: colors.leak(),
+    })));
+    origin
+}
+
+/// The palette behind an imported `origin`, if one was registered
+pub fn imported_palette(origin: Origin) -> Option<&'static ImportedPalette> {
+    let index = origin.raw().checked_sub(PaletteId::FIRST_IMPORTED)?;
+    IMPORTED
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(index as usize)
+        .copied()
+}
+
+/// Ids of every imported palette, in registration order
+pub fn imported_origins() -> Vec<Origin> {
+    let count = IMPORTED.read().unwrap_or_else(|e| e.into_inner()).len() as u16;
+    (0..count)
+        .map(|i| PaletteId::from_raw(PaletteId::FIRST_IMPORTED + i))
+        .collect()
+}
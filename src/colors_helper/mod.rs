@@ -1,8 +1,8 @@
 // src/colors_helper/mod.rs
 use core::fmt::{self, Display};
 use std::collections::HashMap;
-use std::hash::{Hash, Hasher};
 use crate::core::color_types::{HexCode, ColorName};
+use crate::core::distance::DistanceMetric;
 
 pub use std::sync::LazyLock;
 
@@ -64,58 +64,24 @@ mod sort;
 pub use sort::*;
 mod search;
 pub use search::*;
+mod fuzzy;
+pub use fuzzy::{MatchKind, ScoredMatch, score_name, search_scored};
+mod aliases;
+pub use aliases::{aliases_of, matched_alias};
+mod query;
+pub use query::{Bound, Clause, Filter, Query, QueryError, parse_query};
+mod spatial;
+pub use spatial::{ColorIndex, IndexSpace, color_index};
+mod imported;
+pub use imported::{ImportedPalette, imported_origins, imported_palette, register_palette};
 mod ui;
 // Unused for now: pub use ui::*;
 pub mod palette_registry;
 
-// ===== origin facade (kept public) =====
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub enum Origin {
-    #[default]
-    All,
-    Css,
-    Hindi,
-    Persian,
-    Pantone,
-    XKCD,
-    ItalianBrands,
-    National,
-    Brands,
-    MetalFlames,
-    KelvinColors,
-    #[cfg(feature = "github-colors")]
-    GitHub,
-    Seasons,
-    CanadianProvinces,
-}
-impl Hash for Origin {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        std::mem::discriminant(self).hash(state);
-    }
-}
-impl Display for Origin {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s = match self {
-            Origin::All => "ALL",
-            Origin::Css => "CSS",
-            Origin::Hindi => "Hindi",
-            Origin::Persian => "Persian",
-            Origin::Pantone => "Pantone",
-            Origin::XKCD => "XKCD",
-            Origin::National => "National",
-            Origin::Brands => "Brands",
-            Origin::ItalianBrands => "Italian Brands",
-            Origin::MetalFlames => "Metal Flames",
-            Origin::KelvinColors => "Kelvin Colors",
-
-            #[cfg(feature = "github-colors")]
-            Origin::GitHub => "github",
-            Origin::Seasons => "Seasons",
-            Origin::CanadianProvinces => "Canadian Provinces",
-        };
-        f.write_str(s)
-    }
-}
+// ===== palette ids (kept public as `Origin`) =====
+mod palette_id;
+pub use palette_id::{Origin, PaletteId, UnknownPalette};
+pub(crate) use palette_id::PaletteCache;
 
 pub fn colors_for(origin: Origin) -> &'static [(HexCode, ColorName)] {
     // Use the implementation from catalog.rs
@@ -128,6 +94,8 @@ pub struct ColorCatalog {
     pub data: fn() -> &'static [(HexCode, ColorName)],
 }
 
+/// Every built-in palette. Adding one takes a `PaletteId` constant and an entry
+/// here; lookups, search indexes, name caches and the UI list follow from it.
 pub static REGISTRY: &[ColorCatalog] = &[
     ColorCatalog {
         name: "CSS",
@@ -196,6 +164,11 @@ pub static REGISTRY: &[ColorCatalog] = &[
         origin: Origin::CanadianProvinces,
         data: data_canadian_provinces,
     },
+    ColorCatalog {
+        name: "RAL Classic",
+        origin: Origin::RalClassic,
+        data: data_ral_classic,
+    },
 ];
 fn data_national() -> &'static [(HexCode, ColorName)] {
     COLORS_NATIONAL.as_slice() // this runs at runtime, not in a const context
@@ -241,45 +214,127 @@ fn data_canadian_provinces() -> &'static [(HexCode, ColorName)] {
     crate::colors::canadian_provinces::DATA
 }
 
-// COMBINED_COLORS moved to catalog.rs with new types
+fn data_ral_classic() -> &'static [(HexCode, ColorName)] {
+    crate::colors::ral_colors::COLORS_RAL_CLASSIC
+}
+
+/// Look up a RAL Classic color by code: `RAL 3020`, `ral3020` or `RAL-3020`.
+pub fn lookup_ral(code: &str) -> Option<(HexCode, ColorName)> {
+    let code = code.trim();
+    let digits = code
+        .get(..3)
+        .filter(|p| p.eq_ignore_ascii_case("ral"))
+        .map(|_| code[3..].trim_start_matches([' ', '-']))?;
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let number: u32 = digits.parse().ok()?;
+    let table = crate::colors::ral_colors::COLORS_RAL_CLASSIC;
+    // the table is ordered by RAL number
+    table
+        .binary_search_by_key(&number, |(_, name)| ral_number(name.as_str()))
+        .ok()
+        .map(|i| table[i])
+}
 
-// BEFORE (wrong value type)
-// pub static REGISTRY_MAP: LazyLock<HashMap<Origin, &'static [(&'static str, &'static str)]>> = ...
+// "RAL 3020 Traffic Red" -> 3020
+fn ral_number(name: &str) -> u32 {
+    name.get(4..8).and_then(|d| d.parse().ok()).unwrap_or(0)
+}
 
-// AFTER (store fn pointers)
-pub static REGISTRY_MAP: LazyLock<
-    HashMap<Origin, fn() -> &'static [(HexCode, ColorName)]>,
-> = LazyLock::new(|| {
-    let mut m = HashMap::with_capacity(REGISTRY.len());
-    for c in REGISTRY {
-        m.insert(c.origin, c.data); // store the function pointer
+// COMBINED_COLORS and REGISTRY_MAP live in catalog.rs
+
+// REGISTRY plus `define_palette!` palettes, see `catalogs`
+static CATALOGS: LazyLock<Vec<ColorCatalog>> = LazyLock::new(|| {
+    let mut v: Vec<ColorCatalog> = REGISTRY
+        .iter()
+        .map(|c| ColorCatalog { name: c.name, origin: c.origin, data: c.data })
+        .collect();
+    for p in palette_registry::registered_palettes() {
+        if !v.iter().any(|c| c.origin == p.origin) {
+            v.push(ColorCatalog { name: p.name, origin: p.origin, data: p.data });
+        }
     }
-    m
+    v
 });
 
-/// Find the closest color name to the given RGB value from COMBINED_COLORS
+/// Every palette known at compile time: `REGISTRY`, then each palette defined
+/// with `define_palette!` whose id isn't already taken.
+pub fn catalogs() -> &'static [ColorCatalog] {
+    CATALOGS.as_slice()
+}
+
+/// Find the closest color name to the given RGB value from COMBINED_COLORS,
+/// using the default (CIEDE2000) metric.
 pub fn find_closest_color_name(target_rgb: crate::core::rgb::Rgb) -> Option<&'static str> {
-    let mut closest_distance = u32::MAX;
-    let mut closest_name: Option<&'static str> = None;
+    find_closest_color_name_with(target_rgb, DistanceMetric::default())
+}
+
+/// Find the closest color name in COMBINED_COLORS under the given metric.
+pub fn find_closest_color_name_with(
+    target_rgb: crate::core::rgb::Rgb,
+    metric: DistanceMetric,
+) -> Option<&'static str> {
+    color_index(Origin::All, metric)
+        .nearest(target_rgb, 1, metric)
+        .first()
+        .map(|(_hex, name, _d)| name.as_str())
+}
+
+/// Find the entry of `colors` closest to `target_rgb`, with its distance (linear scan).
+pub fn closest_in(
+    colors: &[(HexCode, ColorName)],
+    target_rgb: crate::core::rgb::Rgb,
+    metric: DistanceMetric,
+) -> Option<(HexCode, ColorName, f64)> {
+    let mut best: Option<(HexCode, ColorName, f64)> = None;
 
-    for (hex_code, color_name) in COMBINED_COLORS.iter() {
+    for (hex_code, color_name) in colors {
         if let Some(rgb) = crate::core::rgb::hex_to_rgb(hex_code.as_str()) {
-            let distance = color_distance(target_rgb, rgb);
-            if distance < closest_distance {
-                closest_distance = distance;
-                closest_name = Some(color_name.as_str());
+            let distance = metric.distance(target_rgb, rgb);
+            if best.is_none_or(|(_, _, d)| distance < d) {
+                best = Some((*hex_code, *color_name, distance));
             }
         }
     }
 
-    closest_name
+    best
+}
+
+/// The `k` entries of `origin` closest to `target_rgb`, nearest first.
+/// `Origin::All` searches every palette. Backed by the per-origin spatial index.
+pub fn nearest_colors(
+    target_rgb: crate::core::rgb::Rgb,
+    k: usize,
+    origin: Origin,
+    metric: DistanceMetric,
+) -> Vec<(HexCode, ColorName, f64)> {
+    color_index(origin, metric).nearest(target_rgb, k, metric)
 }
 
-/// Calculate the Euclidean distance between two RGB colors
-fn color_distance(rgb1: crate::core::rgb::Rgb, rgb2: crate::core::rgb::Rgb) -> u32 {
-    let dr = rgb1.r as i32 - rgb2.r as i32;
-    let dg = rgb1.g as i32 - rgb2.g as i32;
-    let db = rgb1.b as i32 - rgb2.b as i32;
+/// The `k` entries of `colors` closest to `target_rgb`, nearest first (linear scan).
+pub fn nearest_in(
+    colors: &[(HexCode, ColorName)],
+    target_rgb: crate::core::rgb::Rgb,
+    k: usize,
+    metric: DistanceMetric,
+) -> Vec<(HexCode, ColorName, f64)> {
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut scored: Vec<(HexCode, ColorName, f64)> = colors
+        .iter()
+        .filter_map(|(hex, name)| {
+            let rgb = crate::core::rgb::hex_to_rgb(hex.as_str())?;
+            Some((*hex, *name, metric.distance(target_rgb, rgb)))
+        })
+        .collect();
 
-    (dr * dr + dg * dg + db * db) as u32
+    let by_distance = |a: &(HexCode, ColorName, f64), b: &(HexCode, ColorName, f64)| a.2.total_cmp(&b.2);
+    if scored.len() > k {
+        scored.select_nth_unstable_by(k - 1, by_distance);
+        scored.truncate(k);
+    }
+    scored.sort_by(by_distance);
+    scored
 }
@@ -0,0 +1,145 @@
+// src/colors_helper/palette_id.rs
+//
+// Palettes are identified by a small integer. Built-in palettes get a named
+// constant here and one `ColorCatalog` entry in `REGISTRY`; other crates use
+// `define_palette!` with a `PaletteId::custom` id. Everything else (display
+// name, data, search index, name and rank caches, the UI list) is looked up
+// from `catalogs()`.
+use super::*;
+use std::sync::RwLock;
+
+/// Identifies a palette: `PaletteId::All`, a `REGISTRY` entry, a
+/// `define_palette!` palette, or one registered at runtime with [`register_palette`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct PaletteId(u16);
+
+/// The name most of the crate uses for a palette id
+pub type Origin = PaletteId;
+
+// Named like the enum variants they replace, so `Origin::Css` keeps working.
+#[allow(non_upper_case_globals)]
+impl PaletteId {
+    /// Every built-in palette at once
+    pub const All: PaletteId = PaletteId(0);
+    pub const Css: PaletteId = PaletteId(1);
+    pub const XKCD: PaletteId = PaletteId(2);
+    pub const Pantone: PaletteId = PaletteId(3);
+    pub const Hindi: PaletteId = PaletteId(4);
+    pub const Persian: PaletteId = PaletteId(5);
+    pub const National: PaletteId = PaletteId(6);
+    pub const Brands: PaletteId = PaletteId(7);
+    pub const ItalianBrands: PaletteId = PaletteId(8);
+    pub const MetalFlames: PaletteId = PaletteId(9);
+    pub const KelvinColors: PaletteId = PaletteId(10);
+    #[cfg(feature = "github-colors")]
+    pub const GitHub: PaletteId = PaletteId(11);
+    pub const Seasons: PaletteId = PaletteId(12);
+    pub const CanadianProvinces: PaletteId = PaletteId(13);
+    pub const RalClassic: PaletteId = PaletteId(14);
+}
+
+impl PaletteId {
+    // ids below this are reserved for built-in palettes
+    const FIRST_CUSTOM: u16 = 512;
+    // ids from here on are handed out by `register_palette`
+    pub(super) const FIRST_IMPORTED: u16 = 1024;
+
+    /// Id `n` of the range set aside for `define_palette!` in other crates (`n < 512`)
+    pub const fn custom(n: u16) -> Self {
+        assert!(n < Self::FIRST_IMPORTED - Self::FIRST_CUSTOM, "custom palette id out of range");
+        Self(Self::FIRST_CUSTOM + n)
+    }
+
+    pub const fn from_raw(raw: u16) -> Self {
+        Self(raw)
+    }
+
+    pub const fn raw(self) -> u16 {
+        self.0
+    }
+
+    /// Registered at runtime rather than built in
+    pub const fn is_imported(self) -> bool {
+        self.0 >= Self::FIRST_IMPORTED
+    }
+
+    /// The catalog entry for a built-in or `define_palette!` palette
+    pub fn catalog(self) -> Option<&'static ColorCatalog> {
+        catalogs().iter().find(|c| c.origin == self)
+    }
+
+    /// Display name from the registry
+    pub fn name(self) -> &'static str {
+        if self == PaletteId::All {
+            return "ALL";
+        }
+        if let Some(c) = self.catalog() {
+            return c.name;
+        }
+        imported_palette(self).map_or("Unknown", |p| p.name)
+    }
+
+    /// `All`, every catalog palette, then every imported one
+    pub fn all() -> Vec<PaletteId> {
+        std::iter::once(PaletteId::All)
+            .chain(catalogs().iter().map(|c| c.origin))
+            .chain(imported_origins())
+            .collect()
+    }
+}
+
+impl Display for PaletteId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// No palette has the given name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownPalette(pub String);
+
+impl Display for UnknownPalette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown palette `{}`", self.0)
+    }
+}
+impl std::error::Error for UnknownPalette {}
+
+/// By display name, ignoring case, spaces, `-` and `_` ("ral-classic" finds "RAL Classic")
+impl std::str::FromStr for PaletteId {
+    type Err = UnknownPalette;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = |s: &str| -> String {
+            s.chars()
+                .filter(|c| !matches!(c, ' ' | '-' | '_'))
+                .flat_map(char::to_lowercase)
+                .collect()
+        };
+        let wanted = key(s);
+        PaletteId::all()
+            .into_iter()
+            .find(|id| key(id.name()) == wanted)
+            .ok_or_else(|| UnknownPalette(s.to_string()))
+    }
+}
+
+/// A value derived from each palette's colors, built on first use and kept
+/// for the rest of the program.
+pub(crate) struct PaletteCache<T: 'static> {
+    map: LazyLock<RwLock<HashMap<PaletteId, &'static T>>>,
+}
+
+impl<T: Send + Sync + 'static> PaletteCache<T> {
+    pub(crate) const fn new() -> Self {
+        Self { map: LazyLock::new(|| RwLock::new(HashMap::new())) }
+    }
+
+    pub(crate) fn get_or_build(&self, id: PaletteId, build: impl FnOnce() -> T) -> &'static T {
+        if let Some(v) = self.map.read().unwrap_or_else(|e| e.into_inner()).get(&id) {
+            return v;
+        }
+        let mut map = self.map.write().unwrap_or_else(|e| e.into_inner());
+        map.entry(id).or_insert_with(|| Box::leak(Box::new(build())))
+    }
+}
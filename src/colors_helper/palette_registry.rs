@@ -4,30 +4,59 @@ use crate::core::color_types::{HexCode, ColorName};
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
+// so `define_palette!` works in crates that don't depend on inventory themselves
+#[doc(hidden)]
+pub use inventory;
+
 /// Trait for color palettes - implement this for your palette
 pub trait ColorPalette {
     /// The data for this palette as (hex, name) tuples
     const DATA: &'static [(HexCode, ColorName)];
     /// Display name for this palette
     const NAME: &'static str;
-    /// The origin enum variant for this palette
+    /// The palette id for this palette
     const ORIGIN: Origin;
 }
 
-/// Macro to easily define and register a new color palette
+/// Define a color palette and register it with the catalog.
+///
+/// The palette shows up in `COMBINED_COLORS`, in `search_in_origin`, in
+/// `PaletteId::all()` and in the GUI origin picker. Downstream crates should
+/// take their id from `PaletteId::custom`; an id that is already taken (by a
+/// built-in palette or an earlier registration) is ignored.
+///
+/// ```
+/// mod harbour {
+///     colorum::define_palette!(Harbour, colorum::colors_helper::PaletteId::custom(3), "Harbour", [
+///         ("#A7B8C2", "harbour fog"),
+///         ("#1C2B39", "harbour night"),
+///     ]);
+/// }
+///
+/// use colorum::colors_helper::{COMBINED_COLORS, PaletteId, TokenMode, search_in_origin};
 ///
-/// This macro would be used to define new color palettes with a struct name,
-/// origin enum variant, display name, and color data.
+/// let id = PaletteId::custom(3);
+/// assert_eq!(id.to_string(), "Harbour");
+/// assert!(PaletteId::all().contains(&id));
+/// assert_eq!(search_in_origin(id, "harbour fog", TokenMode::All).len(), 1);
+/// assert!(COMBINED_COLORS.iter().any(|(_, n)| n.as_str() == "harbour night"));
+/// assert_eq!(harbour::DATA[0].1.origin(), id);
+/// ```
 #[macro_export]
 macro_rules! define_palette {
     ($struct_name:ident, $origin:expr, $display_name:literal, [$(($hex:literal, $name:literal)),* $(,)?]) => {
         pub struct $struct_name;
 
         impl $crate::colors_helper::palette_registry::ColorPalette for $struct_name {
-            const DATA: &'static [(colorum::color_types::HexCode, colorum::color_types::ColorName)] = &[
+            const DATA: &'static [($crate::core::color_types::HexCode, $crate::core::color_types::ColorName)] = &[
                 $((
-                    colorum::color_types::HexCode::new($hex),
-                    colorum::color_types::ColorName::new($name)
+                    $crate::core::color_types::HexCode::new($hex),
+                    $crate::core::color_types::ColorName::new_full(
+                        $name,
+                        $crate::core::color_types::Entity::Color,
+                        $origin,
+                        $crate::core::color_types::Ordering::Name,
+                    )
                 )),*
             ];
             const NAME: &'static str = $display_name;
@@ -35,15 +64,11 @@ macro_rules! define_palette {
         }
 
         // Export the data directly for easy access
-        pub const DATA: &'static [(colorum::color_types::HexCode, colorum::color_types::ColorName)] = &[
-            $((
-                colorum::color_types::HexCode::new($hex),
-                colorum::color_types::ColorName::new($name)
-            )),*
-        ];
+        pub const DATA: &'static [($crate::core::color_types::HexCode, $crate::core::color_types::ColorName)] =
+            <$struct_name as $crate::colors_helper::palette_registry::ColorPalette>::DATA;
 
         // Auto-register the palette
-        inventory::submit! {
+        $crate::colors_helper::palette_registry::inventory::submit! {
             $crate::colors_helper::palette_registry::PaletteRegistration {
                 origin: $origin,
                 name: $display_name,
@@ -62,26 +87,37 @@ pub struct PaletteRegistration {
 
 inventory::collect!(PaletteRegistration);
 
-/// Get all registered palettes
+// Collected once and sorted by id, so the order doesn't depend on link order.
+// When two registrations share an id the one with the smaller name is kept.
+static REGISTERED: LazyLock<Vec<&'static PaletteRegistration>> = LazyLock::new(|| {
+    let mut v: Vec<_> = inventory::iter::<PaletteRegistration>().collect();
+    v.sort_by_key(|p| (p.origin, p.name));
+    v.dedup_by_key(|p| p.origin);
+    v
+});
+
+/// Get all registered palettes, sorted by id, one per id
 pub fn registered_palettes() -> &'static [&'static PaletteRegistration] {
-    inventory::iter::<PaletteRegistration>().collect::<Vec<_>>().leak()
+    REGISTERED.as_slice()
 }
 
-/// Get palette data by origin (legacy function - returns empty for compatibility)
-pub fn palette_data(_origin: Origin) -> &'static [(&'static str, &'static str)] {
-    // This function is deprecated - use the new type system instead
-    &[]
+/// Get palette data by origin; empty if nothing registered it
+pub fn palette_data(origin: Origin) -> &'static [(HexCode, ColorName)] {
+    REGISTRY_MAP_AUTO.get(&origin).map_or(&[], |f| f())
 }
 
-/// Get all combined colors from all registered palettes (legacy - returns empty for compatibility)
-pub static COMBINED_COLORS_AUTO: LazyLock<Vec<(&'static str, &'static str)>> = LazyLock::new(|| {
-    // This is deprecated - use the new type system instead
-    Vec::new()
+/// Get all combined colors from all registered palettes
+pub static COMBINED_COLORS_AUTO: LazyLock<Vec<(HexCode, ColorName)>> = LazyLock::new(|| {
+    let mut v = Vec::new();
+    for p in registered_palettes() {
+        v.extend_from_slice((p.data)());
+    }
+    v
 });
 
 /// Get palette name by origin
 pub fn palette_name(origin: Origin) -> &'static str {
-    for palette in inventory::iter::<PaletteRegistration>() {
+    for palette in registered_palettes() {
         if palette.origin == origin {
             return palette.name;
         }
@@ -89,8 +125,7 @@ pub fn palette_name(origin: Origin) -> &'static str {
     "Unknown"
 }
 
-/// Build registry map for all registered palettes (legacy - returns empty for compatibility)
-pub static REGISTRY_MAP_AUTO: LazyLock<HashMap<Origin, fn() -> &'static [(&'static str, &'static str)]>> = LazyLock::new(|| {
-    // This is deprecated - use the new type system instead
-    HashMap::new()
-});
\ No newline at end of file
+/// Build registry map for all registered palettes
+pub static REGISTRY_MAP_AUTO: LazyLock<HashMap<Origin, fn() -> &'static [(HexCode, ColorName)]>> = LazyLock::new(|| {
+    registered_palettes().iter().map(|p| (p.origin, p.data)).collect()
+});
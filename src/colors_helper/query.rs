@@ -0,0 +1,348 @@
+// src/colors_helper/query.rs
+//
+// The search box language. A query is free text plus clauses:
+//
+//   origin:pantone|xkcd        palette(s) to search (display names, case and spaces ignored)
+//   entity:brand|place         kind of name (color, object, material, place, brand, …)
+//   hue:200..240               HSL hue in degrees; `340..20` wraps through red
+//   light:>60                  HSL lightness 0-100 (also sat:, for saturation)
+//   near:#3366ff~10            within ΔE00 10 of a color (any `parse_color` input; `~` defaults to 10)
+//   "navy blue"                the name (or an alias) contains this phrase
+//   -word, -"phrase", -key:…   negate any term or clause
+//
+// Numbers take a range `a..b`, a comparison `>x`, `>=x`, `<x`, `<=x`, or a
+// single value. Clause keys are case-insensitive, so the old `Entity:Brand`
+// prefix still works anywhere in the query. Text, phrases and words are run
+// through `fold_text`, so "creme" finds "Crème" and "preussen" finds "Preußen".
+use super::*;
+use crate::core::color_types::{ColorName, Entity, HexCode};
+use crate::core::parse::parse_color;
+use crate::core::rgb::{Hsl, Rgb, rgb_to_hsl};
+use std::collections::HashSet;
+
+// ΔE00 used by `near:` when no `~tolerance` is given
+const DEFAULT_NEAR_TOLERANCE: f64 = 10.0;
+
+/// A query that failed to parse, naming the clause at fault
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    /// The clause as typed, e.g. `hue:abc`
+    pub clause: String,
+    pub reason: String,
+}
+
+impl QueryError {
+    fn new(clause: &str, reason: impl Into<String>) -> Self {
+        Self { clause: clause.to_string(), reason: reason.into() }
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid clause `{}`: {}", self.clause, self.reason)
+    }
+}
+impl std::error::Error for QueryError {}
+
+/// A numeric condition: `a..b`, `>x`, `>=x`, `<x`, `<=x` or `x`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bound {
+    /// Inclusive; for hue, `min > max` wraps around 360
+    Range(f64, f64),
+    Gt(f64),
+    Ge(f64),
+    Lt(f64),
+    Le(f64),
+    Eq(f64),
+}
+
+impl Bound {
+    fn parse(s: &str, max: f64) -> Result<Bound, String> {
+        let num = |t: &str| -> Result<f64, String> {
+            let v: f64 = t.trim().parse().map_err(|_| format!("`{t}` is not a number"))?;
+            if (0.0..=max).contains(&v) {
+                Ok(v)
+            } else {
+                Err(format!("{v} is outside 0..{max}"))
+            }
+        };
+        if s.is_empty() {
+            return Err("expected a range like 10..20, a comparison like >60, or a number".into());
+        }
+        Ok(if let Some((a, b)) = s.split_once("..") {
+            Bound::Range(num(a)?, num(b)?)
+        } else if let Some(v) = s.strip_prefix(">=") {
+            Bound::Ge(num(v)?)
+        } else if let Some(v) = s.strip_prefix("<=") {
+            Bound::Le(num(v)?)
+        } else if let Some(v) = s.strip_prefix('>') {
+            Bound::Gt(num(v)?)
+        } else if let Some(v) = s.strip_prefix('<') {
+            Bound::Lt(num(v)?)
+        } else {
+            Bound::Eq(num(s)?)
+        })
+    }
+
+    fn contains(self, v: f64, wraps: bool) -> bool {
+        match self {
+            Bound::Range(a, b) if wraps && a > b => v >= a || v <= b,
+            Bound::Range(a, b) => (a..=b).contains(&v),
+            Bound::Gt(x) => v > x,
+            Bound::Ge(x) => v >= x,
+            Bound::Lt(x) => v < x,
+            Bound::Le(x) => v <= x,
+            Bound::Eq(x) => (v - x).abs() < 0.5,
+        }
+    }
+}
+
+/// One filter of a query
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    Origin(Vec<Origin>),
+    Entity(Vec<Entity>),
+    Hue(Bound),
+    Saturation(Bound),
+    Lightness(Bound),
+    Near { color: Rgb, tolerance: f64 },
+    /// Case-insensitive substring of the name
+    Phrase(String),
+    /// A whole word of the name (only used negated: `-dark`)
+    Word(String),
+}
+
+/// A filter, possibly negated
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clause {
+    pub negated: bool,
+    pub filter: Filter,
+}
+
+/// A parsed search query; see [`parse_query`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Query {
+    /// Words to look up with the token index, including the words of phrases
+    pub text: Vec<String>,
+    pub clauses: Vec<Clause>,
+}
+
+fn parse_entity(s: &str) -> Option<Entity> {
+    Some(match s.trim().to_lowercase().as_str() {
+        "color" | "colors" => Entity::Color,
+        "object" | "objects" => Entity::Object,
+        "material" | "materials" => Entity::Material,
+        "place" | "places" => Entity::Place,
+        "brand" | "brands" => Entity::Brand,
+        "person" | "persons" | "people" => Entity::Person,
+        "abstract" => Entity::Abstract,
+        "chemical" | "chemicals" => Entity::Chemical,
+        "temperature" | "temperatures" => Entity::Temperature,
+        "other" => Entity::Other,
+        _ => return None,
+    })
+}
+
+// `a|b,c` → ["a", "b", "c"]
+fn list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(['|', ',']).map(str::trim).filter(|s| !s.is_empty())
+}
+
+// Split on whitespace outside double quotes. Each piece keeps its quotes so
+// the caller can tell a phrase from a word.
+fn split_terms(query: &str) -> Result<Vec<&str>, QueryError> {
+    let mut terms = Vec::new();
+    let mut start = None;
+    let mut quoted = false;
+    for (i, c) in query.char_indices() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                start.get_or_insert(i);
+            }
+            c if c.is_whitespace() && !quoted => {
+                if let Some(s) = start.take() {
+                    terms.push(&query[s..i]);
+                }
+            }
+            _ => {
+                start.get_or_insert(i);
+            }
+        }
+    }
+    if let Some(s) = start {
+        if quoted {
+            return Err(QueryError::new(&query[s..], "missing closing quote"));
+        }
+        terms.push(&query[s..]);
+    }
+    Ok(terms)
+}
+
+fn unquote(s: &str) -> &str {
+    s.strip_prefix('"').and_then(|s| s.strip_suffix('"')).unwrap_or(s)
+}
+
+fn parse_filter(term: &str, key: &str, value: &str) -> Result<Option<Filter>, QueryError> {
+    let err = |reason: String| QueryError::new(term, reason);
+    let value = unquote(value);
+    let filter = match key.to_ascii_lowercase().as_str() {
+        "origin" => {
+            let origins = list(value)
+                .map(|o| o.parse::<Origin>().map_err(|e| err(e.to_string())))
+                .collect::<Result<Vec<_>, _>>()?;
+            if origins.is_empty() {
+                return Err(err("expected a palette name".into()));
+            }
+            Filter::Origin(origins)
+        }
+        "entity" => {
+            let entities = list(value)
+                .map(|e| parse_entity(e).ok_or_else(|| err(format!("unknown entity `{e}`"))))
+                .collect::<Result<Vec<_>, _>>()?;
+            if entities.is_empty() {
+                return Err(err("expected an entity such as brand or place".into()));
+            }
+            Filter::Entity(entities)
+        }
+        "hue" => Filter::Hue(Bound::parse(value, 360.0).map_err(err)?),
+        "sat" | "saturation" => Filter::Saturation(Bound::parse(value, 100.0).map_err(err)?),
+        "light" | "lightness" => Filter::Lightness(Bound::parse(value, 100.0).map_err(err)?),
+        "near" => {
+            let (color, tolerance) = match value.rsplit_once('~') {
+                Some((c, t)) => {
+                    let t: f64 = t.trim().parse().map_err(|_| err(format!("tolerance `{t}` is not a number")))?;
+                    (c, t)
+                }
+                None => (value, DEFAULT_NEAR_TOLERANCE),
+            };
+            let color = parse_color(color).map_err(|e| err(e.to_string()))?.rgb();
+            Filter::Near { color, tolerance }
+        }
+        // "Red: carmine" is just a name
+        _ if value.is_empty() => return Ok(None),
+        _ => return Err(err(format!("unknown clause `{key}:`"))),
+    };
+    Ok(Some(filter))
+}
+
+/// Parse a search-box query into free text and clauses.
+///
+/// ```
+/// use colorum::colors_helper::{Filter, parse_query};
+///
+/// let q = parse_query(r#"origin:pantone -entity:brand hue:200..240 "navy blue""#).unwrap();
+/// assert_eq!(q.text, ["navy", "blue"]);
+/// assert_eq!(q.clauses.len(), 4);
+/// assert!(q.clauses[1].negated);
+///
+/// let err = parse_query("blue light:bright").unwrap_err();
+/// assert_eq!(err.clause, "light:bright");
+/// ```
+pub fn parse_query(query: &str) -> Result<Query, QueryError> {
+    let mut q = Query::default();
+    for term in split_terms(query)? {
+        let (negated, body) = match term.strip_prefix('-') {
+            Some(rest) if !rest.is_empty() => (true, rest),
+            _ => (false, term),
+        };
+        let words = |s: &str| -> Vec<String> { fold_text(s).split_whitespace().map(str::to_string).collect() };
+
+        let filter = if body.starts_with('"') {
+            let phrase = unquote(body);
+            if !negated {
+                q.text.extend(words(phrase));
+            }
+            Some(Filter::Phrase(fold_text(phrase)))
+        } else if let Some((key, value)) = body.split_once(':')
+            && !key.is_empty()
+            && key.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            match parse_filter(term, key, value)? {
+                Some(f) => Some(f),
+                None if negated => Some(Filter::Word(fold_text(body))),
+                None => {
+                    q.text.push(fold_text(body));
+                    None
+                }
+            }
+        } else if negated {
+            Some(Filter::Word(fold_text(body)))
+        } else {
+            q.text.push(fold_text(body));
+            None
+        };
+        if let Some(filter) = filter {
+            q.clauses.push(Clause { negated, filter });
+        }
+    }
+    Ok(q)
+}
+
+// Entries of each palette, for `origin:` membership. The `origin` stored on a
+// name isn't reliable: palettes built with `ColorName::new` report `All`.
+static MEMBERS: PaletteCache<HashSet<(HexCode, ColorName)>> = PaletteCache::new();
+
+fn in_origin(origin: Origin, hex: HexCode, name: ColorName) -> bool {
+    origin == Origin::All
+        || MEMBERS.get_or_build(origin, || origin_slice(origin).iter().copied().collect()).contains(&(hex, name))
+}
+
+impl Filter {
+    fn matches(&self, hex: HexCode, name: ColorName) -> bool {
+        let hsl = || -> Option<Hsl> {
+            hex.to_rgb().map(|(r, g, b)| rgb_to_hsl(Rgb { r, g, b }))
+        };
+        match self {
+            Filter::Origin(origins) => origins.iter().any(|&o| in_origin(o, hex, name)),
+            Filter::Entity(entities) => entities.contains(&name.entity()),
+            Filter::Hue(b) => hsl().is_some_and(|c| b.contains(c.h as f64, true)),
+            Filter::Saturation(b) => hsl().is_some_and(|c| b.contains(c.s as f64, false)),
+            Filter::Lightness(b) => hsl().is_some_and(|c| b.contains(c.l as f64, false)),
+            Filter::Near { color, tolerance } => hex.to_rgb().is_some_and(|(r, g, b)| {
+                DistanceMetric::Ciede2000.distance(*color, Rgb { r, g, b }) <= *tolerance
+            }),
+            Filter::Phrase(p) => super::aliases::haystack(name).contains(p.as_str()),
+            Filter::Word(w) => super::aliases::search_names(name)
+                .any(|n| super::search::tokenize_lc(&n).any(|t| t == *w)),
+        }
+    }
+}
+
+impl Query {
+    /// True when the query is only free text
+    pub fn is_plain(&self) -> bool {
+        self.clauses.is_empty()
+    }
+
+    /// The free text, words separated by spaces
+    pub fn text(&self) -> String {
+        self.text.join(" ")
+    }
+
+    /// Does an entry satisfy every clause?
+    pub fn matches(&self, hex: HexCode, name: ColorName) -> bool {
+        self.clauses.iter().all(|c| c.filter.matches(hex, name) != c.negated)
+    }
+
+    /// Palettes to search when the user picked `origin`: the `origin:` clause
+    /// narrows `All` down to the named palettes (imported ones included).
+    pub(super) fn sources(&self, origin: Origin) -> Vec<Origin> {
+        let named = self.clauses.iter().find_map(|c| match &c.filter {
+            Filter::Origin(o) if !c.negated => Some(o),
+            _ => None,
+        });
+        match named {
+            Some(o) if origin == Origin::All => o.clone(),
+            _ => vec![origin],
+        }
+    }
+
+    /// Target of a positive `near:` clause, for ordering by distance
+    pub(super) fn near(&self) -> Option<Rgb> {
+        self.clauses.iter().find_map(|c| match c.filter {
+            Filter::Near { color, .. } if !c.negated => Some(color),
+            _ => None,
+        })
+    }
+}
@@ -1,8 +1,12 @@
 // src/colors_helper/search.rs
 use super::*;
 use std::collections::HashMap;
-use crate::core::color_types::{HexCode, ColorName, Entity};
+use crate::core::color_types::{HexCode, ColorName};
 use super::sort;
+use super::fuzzy::scored_for_query;
+use super::aliases::{haystack, search_names};
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
 
 pub struct ColorEntry {
     pub hex: &'static str,
@@ -10,95 +14,43 @@ pub struct ColorEntry {
     pub name_lc: String,
 }
 
-fn tokenize_lc(s: &str) -> impl Iterator<Item = String> + '_ {
-    s.split(|c: char| !c.is_alphanumeric())
-        .filter(|t| !t.is_empty())
-        .map(|t| t.to_lowercase())
-}
-
-/// Parse entity filter from query like "Entity:Temperature|Brand some query"
-/// Returns (filtered_entities, cleaned_query)
-fn parse_entity_filter(query: &str) -> (Option<Vec<Entity>>, String) {
-    // Make parsing case-insensitive by checking both "Entity:" and "entity:"
-    let entity_prefix = if query.starts_with("Entity:") {
-        Some("Entity:")
-    } else if query.starts_with("entity:") {
-        Some("entity:")
-    } else {
-        None
-    };
-
-    if let Some(prefix) = entity_prefix {
-        let entity_part = &query[prefix.len()..];
-
-        if let Some(space_pos) = entity_part.find(' ') {
-            let entities_str = &entity_part[..space_pos];
-            let remaining_query = &entity_part[space_pos + 1..];
-
-            let entities = parse_entities(entities_str);
-            println!("DEBUG: Parsed entities from '{}': {:?}", entities_str, entities);
-            if !entities.is_empty() {
-                return (Some(entities), remaining_query.to_string());
-            }
-        } else {
-            // Just "Entity:Temperature" with no additional query
-            let entities = parse_entities(entity_part);
-            println!("DEBUG: Parsed entities from '{}': {:?}", entity_part, entities);
-            if !entities.is_empty() {
-                return (Some(entities), String::new());
-            }
+/// Lowercase `s` and fold it to the plain letters people type: NFKD with the
+/// accents dropped ("crème" → "creme"), "ß" → "ss", and typographic
+/// apostrophes turned into `'` ("À L’Orange" → "a l'orange").
+pub fn fold_text(s: &str) -> String {
+    if s.is_ascii() {
+        return s.to_ascii_lowercase();
+    }
+    let mut out = String::with_capacity(s.len());
+    for c in s.nfkd().filter(|&c| !is_diacritic(c)) {
+        match c {
+            '\u{2018}' | '\u{2019}' | '\u{201B}' | '\u{02BC}' | '\u{2032}' | '`' | '\u{00B4}' => out.push('\''),
+            'ß' | 'ẞ' => out.push_str("ss"),
+            _ => out.extend(c.to_lowercase()),
         }
     }
-    (None, query.to_string())
+    out
 }
 
-/// Parse entity names separated by | into Entity enum values
-fn parse_entities(entities_str: &str) -> Vec<Entity> {
-    entities_str.split('|')
-        .filter_map(|s| {
-            let s = s.trim().to_lowercase();
-            match s.as_str() {
-                "color" | "colors" => Some(Entity::Color),
-                "object" | "objects" => Some(Entity::Object),
-                "material" | "materials" => Some(Entity::Material),
-                "place" | "places" => Some(Entity::Place),
-                "brand" | "brands" => Some(Entity::Brand),
-                "person" | "persons" | "people" => Some(Entity::Person),
-                "abstract" => Some(Entity::Abstract),
-                "chemical" | "chemicals" => Some(Entity::Chemical),
-                "temperature" | "temperatures" => Some(Entity::Temperature),
-                "other" => Some(Entity::Other),
-                _ => None,
-            }
-        })
-        .collect()
+// Accents on Latin, Greek and Cyrillic letters. Other combining marks (the
+// vowel signs of Devanagari, say) are part of the letter and stay.
+fn is_diacritic(c: char) -> bool {
+    matches!(c, '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}' | '\u{20D0}'..='\u{20FF}' | '\u{FE20}'..='\u{FE2F}')
 }
 
-/// Apply entity filter to search results
-fn apply_entity_filter(results: Vec<(HexCode, ColorName)>, entity_filter: &Option<Vec<Entity>>) -> Vec<(HexCode, ColorName)> {
-    if let Some(entities) = entity_filter {
-        println!("DEBUG: Filtering {} results for entities: {:?}", results.len(), entities);
-        let filtered: Vec<_> = results
-            .into_iter()
-            .filter(|(_, name)| {
-                let matches = entities.contains(&name.entity());
-                if matches {
-                    println!("DEBUG: Matched '{}' with entity {:?}", name.as_str(), name.entity());
-                }
-                matches
-            })
-            .collect();
-        println!("DEBUG: Filtered down to {} results", filtered.len());
-        filtered
-    } else {
-        results
-    }
+pub(super) fn tokenize_lc(s: &str) -> impl Iterator<Item = String> + use<> {
+    fold_text(s)
+        .split(|c: char| !c.is_alphanumeric() && !is_combining_mark(c))
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>()
+        .into_iter()
 }
 
 fn build_token_index_for(slice: &[(HexCode, ColorName)]) -> HashMap<String, Box<[usize]>> {
     let mut idx: HashMap<String, Vec<usize>> = HashMap::new();
     for (i, (_, name)) in slice.iter().enumerate() {
-        for tok in tokenize_lc(name.as_str()) {
+        for tok in search_names(*name).flat_map(|n| tokenize_lc(&n)) {
             idx.entry(tok).or_default().push(i);
         }
     }
@@ -122,76 +74,32 @@ pub static COLORS_LC: LazyLock<Vec<ColorEntry>> = LazyLock::new(|| {
         .collect()
 });
 
-static IDX_ALL: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::All)));
-static IDX_CSS: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::Css)));
-static IDX_XKCD: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::XKCD)));
-static IDX_PANTONE: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::Pantone)));
-static IDX_HINDI: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::Hindi)));
-static IDX_PERSIAN: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::Persian)));
-static IDX_NATIONAL: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::National)));
-static IDX_BRANDS: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::Brands)));
-static IDX_ITALIANBRANDS: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::ItalianBrands)));
-
-static IDX_METALFLAMES: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::MetalFlames)));
-static IDX_KELVINCOLORS: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::KelvinColors)));
-
-#[cfg(feature = "github-colors")]
-static IDX_GITHUB: LazyLock<HashMap<String, Box<[usize]>>> =
-    LazyLock::new(|| build_token_index_for(catalog::origin_slice(Origin::GitHub)));
+// Token index per origin, built on first use
+static ORIGIN_TOKEN_INDEX: PaletteCache<HashMap<String, Box<[usize]>>> = PaletteCache::new();
 
 fn origin_index(origin: Origin) -> &'static HashMap<String, Box<[usize]>> {
-    match origin {
-        Origin::All => &IDX_ALL,
-        Origin::Css => &IDX_CSS,
-        Origin::XKCD => &IDX_XKCD,
-        Origin::Pantone => &IDX_PANTONE,
-        Origin::Hindi => &IDX_HINDI,
-        Origin::Persian => &IDX_PERSIAN,
-        Origin::National => &IDX_NATIONAL,
-        Origin::Brands => &IDX_BRANDS,
-        Origin::ItalianBrands => &IDX_ITALIANBRANDS,
-        Origin::MetalFlames => &IDX_METALFLAMES,
-        Origin::KelvinColors => &IDX_KELVINCOLORS,
-
-        #[cfg(feature = "github-colors")]
-        Origin::GitHub => &IDX_GITHUB,
-
-        // New palette system - these use the auto-generated indices
-        Origin::Seasons | Origin::CanadianProvinces => &IDX_ALL, // fallback for now
-    }
+    ORIGIN_TOKEN_INDEX.get_or_build(origin, || build_token_index_for(origin_slice(origin)))
 }
 
+/// Case-insensitive substring search over every built-in palette.
+/// Clauses (`entity:brand`, `hue:0..30`, …) filter the matches; see [`parse_query`].
 pub fn search_substring(query: &str) -> Vec<(HexCode, ColorName)> {
-    let (entity_filter, cleaned_query) = parse_entity_filter(query);
-    let qlc = cleaned_query.to_lowercase();
+    let Ok(query) = parse_query(query) else {
+        return Vec::new();
+    };
+    let mut out = substring_all(&fold_text(&query.text()));
+    out.retain(|&(h, n)| query.matches(h, n));
+    out
+}
 
+fn substring_all(qlc: &str) -> Vec<(HexCode, ColorName)> {
     let mut out: Vec<(HexCode, ColorName)> = catalog::COMBINED_COLORS
         .iter()
-        .filter(|(_, name)| {
-            // First check entity filter if specified
-            if let Some(ref entities) = entity_filter {
-                if !entities.contains(&name.entity()) {
-                    return false;
-                }
-            }
-            // Then check name contains query (if query is not empty)
-            qlc.is_empty() || name.as_str().to_lowercase().contains(&qlc)
-        })
-        .map(|(hex, name)| (*hex, *name))
+        .filter(|(_, name)| qlc.is_empty() || haystack(*name).contains(qlc))
+        .copied()
         .collect();
 
-    sort::sort_dropdown_by_origin(&qlc, &mut out);
+    sort::sort_dropdown_by_origin(qlc, &mut out);
     if out.len() > super::MAX_RESULTS {
         out.truncate(super::MAX_RESULTS);
     }
@@ -207,40 +115,84 @@ pub enum TokenMode {
     Any,
     All,
     Substring,
+    /// Typo-tolerant and ranked by relevance; see [`search_scored`]
+    Fuzzy,
 }
 // short inputs use substring (prefix) instead of token index
 pub const SUBSTRING_THRESHOLD: usize = 3;
 
+/// Search `origin` for `query`, which may mix free text with clauses such as
+/// `origin:`, `entity:`, `hue:`, `light:`, `near:`, quoted phrases and `-`
+/// negation (see [`parse_query`]). A query that doesn't parse finds nothing;
+/// call `parse_query` first to report why.
 pub fn search_in_origin(
     origin: Origin,
     query: &str,
     mode: TokenMode,
 ) -> Vec<(HexCode, ColorName)> {
-    let q = query.trim();
-    let (entity_filter, cleaned_query) = parse_entity_filter(q);
-    let slice = origin_slice(origin);
+    match parse_query(query) {
+        Ok(q) => search_query(origin, &q, mode),
+        Err(_) => Vec::new(),
+    }
+}
 
-    // Handle entity-only filtering (no text query)
-    if cleaned_query.is_empty() && entity_filter.is_some() {
-        let entities = entity_filter.unwrap();
-        return slice
-            .iter()
-            .filter(|(_, name)| entities.contains(&name.entity()))
-            .map(|(hex, name)| (*hex, *name))
-            .collect();
+/// Evaluate a parsed query: free text through the token index (or fuzzy
+/// scoring), then every clause as a filter.
+pub fn search_query(origin: Origin, query: &Query, mode: TokenMode) -> Vec<(HexCode, ColorName)> {
+    let text = query.text();
+    if matches!(mode, TokenMode::Fuzzy) && !text.is_empty() {
+        return scored_for_query(origin, query).into_iter().map(|m| (m.hex, m.name)).collect();
     }
 
-    // AFTER
-    if cleaned_query.is_empty() {
-        // For All, don't return the entire universe — let the UI show nothing or a small default.
-        if matches!(origin, Origin::All) {
-            return Vec::new();
+    let mut out: Vec<(HexCode, ColorName)> = Vec::new();
+    for source in query.sources(origin) {
+        let hits = if !text.is_empty() {
+            search_text(source, &text, mode.clone())
+        } else if query.is_plain() {
+            // For All, don't return the entire universe — let the UI show nothing or a small default.
+            if source == Origin::All {
+                return Vec::new();
+            }
+            return origin_slice(source).to_vec();
+        } else {
+            origin_slice(source).to_vec()
+        };
+        out.extend(hits.into_iter().filter(|&(h, n)| query.matches(h, n)));
+    }
+
+    // a bare `near:` lists the closest colors first
+    if text.is_empty()
+        && let Some(target) = query.near()
+    {
+        let dist = |h: HexCode| {
+            h.to_rgb().map_or(f64::MAX, |(r, g, b)| {
+                DistanceMetric::Ciede2000.distance(target, crate::core::rgb::Rgb { r, g, b })
+            })
+        };
+        out.sort_by(|a, b| dist(a.0).total_cmp(&dist(b.0)));
+    }
+    out.truncate(MAX_RESULTS);
+    out
+}
+
+// Free-text search in one palette, without clauses
+fn search_text(origin: Origin, q: &str, mode: TokenMode) -> Vec<(HexCode, ColorName)> {
+    let slice = origin_slice(origin);
+
+    // A RAL code ("RAL 3020", or just "3020" inside RAL Classic) is an exact lookup
+    let ral = match origin {
+        Origin::All => lookup_ral(q),
+        Origin::RalClassic => {
+            lookup_ral(q).or_else(|| lookup_ral(&format!("RAL {q}")))
         }
-        return apply_entity_filter(slice.to_vec(), &entity_filter);
+        _ => None,
+    };
+    if let Some(hit) = ral {
+        return vec![hit];
     }
 
     // Tokenize once
-    let tokens: Vec<String> = tokenize_lc(&cleaned_query).collect();
+    let tokens: Vec<String> = tokenize_lc(q).collect();
 
     // Partition tokens by length: short tokens use substring, full tokens use index
     let mut full_toks: Vec<&str> = Vec::new();
@@ -255,11 +207,11 @@ pub fn search_in_origin(
 
     // Helper: substring check that ALL short tokens appear in the name
     #[inline]
-    fn name_matches_all_short(name: &str, shorts: &[&str]) -> bool {
+    fn name_matches_all_short(name: ColorName, shorts: &[&str]) -> bool {
         if shorts.is_empty() {
             return true;
         }
-        let nlc = name.to_lowercase();
+        let nlc = haystack(name);
         shorts.iter().all(|s| nlc.contains(s))
     }
 
@@ -267,25 +219,22 @@ pub fn search_in_origin(
     if full_toks.is_empty() {
         // Fast path for All on short tokens: use cached substring search
         if matches!(origin, Origin::All) {
-            return search_substring(q);
+            return substring_all(&fold_text(q));
         }
 
         // Special case: single character queries should do simple substring matching, not token matching
-        if cleaned_query.len() == 1 {
-            let qlc = cleaned_query.to_lowercase();
+        if q.len() == 1 {
+            let qlc = fold_text(q);
             let mut out: Vec<(HexCode, ColorName)> = slice
                 .iter()
                 .copied()
-                .filter(|&(_h, n)| n.as_str().to_lowercase().contains(&qlc))
+                .filter(|&(_h, n)| haystack(n).contains(&qlc))
                 .collect();
 
             if matches!(origin, Origin::All) {
                 sort_dropdown_by_origin(&qlc, &mut out);
             }
 
-            // Apply entity filtering to final results
-            out = apply_entity_filter(out, &entity_filter);
-
             if out.len() > MAX_RESULTS {
                 out.truncate(MAX_RESULTS);
             }
@@ -303,7 +252,7 @@ pub fn search_in_origin(
                         .iter()
                         .copied()
                         .filter(|&(_h, n)| {
-                            let nlc = n.as_str().to_lowercase();
+                            let nlc = haystack(n);
                             short_toks.iter().any(|s| nlc.contains(s))
                         })
                         .collect()
@@ -314,18 +263,15 @@ pub fn search_in_origin(
                 slice
                     .iter()
                     .copied()
-                    .filter(|&(_h, n)| name_matches_all_short(n.as_str(), &short_toks))
+                    .filter(|&(_h, n)| name_matches_all_short(n, &short_toks))
                     .collect()
             }
         };
 
         if matches!(origin, Origin::All) {
-            sort_dropdown_by_origin(&cleaned_query.to_lowercase(), &mut out);
+            sort_dropdown_by_origin(&q.to_lowercase(), &mut out);
         }
 
-        // Apply entity filtering to final results
-        out = apply_entity_filter(out, &entity_filter);
-
         if out.len() > MAX_RESULTS {
             out.truncate(MAX_RESULTS);
         }
@@ -333,23 +279,6 @@ pub fn search_in_origin(
     }
 
     // Case B: we have at least one full token → use per-origin index
-    // But skip token index for origins that don't have proper indices
-    if matches!(origin, Origin::Seasons | Origin::CanadianProvinces) {
-        // Fall back to substring search for these origins
-        let qlc = q.to_lowercase();
-        let mut out: Vec<(HexCode, ColorName)> = slice
-            .iter()
-            .copied()
-            .filter(|&(_h, n)| n.as_str().to_lowercase().contains(&qlc))
-            .collect();
-
-        out = apply_entity_filter(out, &entity_filter);
-        if out.len() > MAX_RESULTS {
-            out.truncate(MAX_RESULTS);
-        }
-        return out;
-    }
-
     let idx = origin_index(origin);
 
     // Build postings for the "full" tokens
@@ -370,17 +299,16 @@ pub fn search_in_origin(
     // fall back to substring over short tokens (or whole q if no shorts).
     if lists.is_empty() {
         if matches!(origin, Origin::All) {
-            return search_substring(q);
+            return substring_all(&fold_text(q));
         }
         // For non-All origins, fall back to substring search on the specific origin slice
-        let qlc = q.to_lowercase();
+        let qlc = fold_text(q);
         let mut out: Vec<(HexCode, ColorName)> = slice
             .iter()
             .copied()
-            .filter(|&(_h, n)| n.as_str().to_lowercase().contains(&qlc))
+            .filter(|&(_h, n)| haystack(n).contains(&qlc))
             .collect();
 
-        out = apply_entity_filter(out, &entity_filter);
         if out.len() > MAX_RESULTS {
             out.truncate(MAX_RESULTS);
         }
@@ -403,15 +331,12 @@ pub fn search_in_origin(
 
     // If index produced nothing in ANY mode, try a substring fallback on q
     if current.is_empty() {
-        let qlc = cleaned_query.to_lowercase();
-        return apply_entity_filter(
-            slice
-                .iter()
-                .copied()
-                .filter(|&(_h, n)| n.as_str().to_lowercase().contains(&qlc))
-                .collect(),
-            &entity_filter,
-        );
+        let qlc = fold_text(q);
+        return slice
+            .iter()
+            .copied()
+            .filter(|&(_h, n)| haystack(n).contains(&qlc))
+            .collect();
     }
 
     // Apply short-token substring filter to the candidate set (AND semantics for ALL/Substring; OR for ANY)
@@ -426,7 +351,7 @@ pub fn search_in_origin(
             } else {
                 for i in current {
                     let name = slice[i].1;
-                    let nlc = name.as_str().to_lowercase();
+                    let nlc = haystack(name);
                     if short_toks.iter().any(|s| nlc.contains(s)) {
                         out.push(slice[i]);
                     }
@@ -437,7 +362,7 @@ pub fn search_in_origin(
             // ALL or Substring → require ALL short tokens to appear
             for i in current {
                 let name = slice[i].1;
-                if name_matches_all_short(name.as_str(), &short_toks) {
+                if name_matches_all_short(name, &short_toks) {
                     out.push(slice[i]);
                 }
             }
@@ -445,12 +370,9 @@ pub fn search_in_origin(
     }
 
     if matches!(origin, Origin::All) {
-        sort_dropdown_by_origin(&cleaned_query.to_lowercase(), &mut out);
+        sort_dropdown_by_origin(&q.to_lowercase(), &mut out);
     }
 
-    // Apply entity filtering to final results
-    out = apply_entity_filter(out, &entity_filter);
-
     if out.len() > MAX_RESULTS {
         out.truncate(MAX_RESULTS);
     }
@@ -459,22 +381,6 @@ pub fn search_in_origin(
 
 use std::sync::LazyLock;
 
-// Build once over the full combined list.
-pub static COLORS_BY_NAME: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
-    catalog::COMBINED_COLORS
-        .iter()
-        .map(|(hex, name)| (name.as_str(), hex.as_str()))
-        .collect()
-});
-
-pub static COLORS_BY_NAME_LC: LazyLock<HashMap<String, &'static str>> = LazyLock::new(|| {
-    let mut m = HashMap::new();
-    for (hex, name) in catalog::COMBINED_COLORS.as_slice() {
-        m.insert(name.as_str().to_lowercase(), hex.as_str());
-    }
-    m
-});
-
 // Merge helpers
 fn intersect_sorted_slices(a: &[usize], b: &[usize]) -> Vec<usize> {
     let (mut i, mut j) = (0, 0);
@@ -539,7 +445,7 @@ pub static NAME_TOKEN_INDEX: LazyLock<HashMap<String, Box<[usize]>>> = LazyLock:
     let mut idx: HashMap<String, Vec<usize>> = HashMap::new();
 
     for (i, (_, name)) in catalog::COMBINED_COLORS.iter().enumerate() {
-        for tok in tokenize_lc(name.as_str()) {
+        for tok in search_names(*name).flat_map(|n| tokenize_lc(&n)) {
             idx.entry(tok).or_default().push(i);
         }
     }
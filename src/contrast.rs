@@ -0,0 +1,203 @@
+//! Text/background contrast.
+//!
+//! WCAG 2.1 contrast ratios with the AA/AAA thresholds for normal and large
+//! text, and the APCA lightness contrast (Lc) proposed for WCAG 3.
+//!
+//! ```
+//! use colorum::contrast::{TextSize, WcagLevel, apca_lc, check, contrast_ratio};
+//! use colorum::Rgb;
+//!
+//! let black = Rgb { r: 0, g: 0, b: 0 };
+//! let white = Rgb { r: 255, g: 255, b: 255 };
+//! assert_eq!(contrast_ratio(black, white), 21.0);
+//! assert!((apca_lc(black, white) - 106.04).abs() < 0.01);
+//!
+//! let gray = check(Rgb { r: 0x76, g: 0x76, b: 0x76 }, white);
+//! assert!(gray.passes(WcagLevel::Aa, TextSize::Normal));
+//! assert!(!gray.passes(WcagLevel::Aaa, TextSize::Normal));
+//! ```
+//!
+//! When a pair fails, [`suggest_foreground`] finds the smallest lightness
+//! change to the text color that reaches a target ratio.
+
+use crate::colors_helper::{Origin, nearest_in, origin_slice};
+use crate::core::color_types::{ColorName, HexCode};
+use crate::core::distance::DistanceMetric;
+use crate::core::rgb::{Rgb, hex_to_rgb};
+use crate::core::spaces::Oklch;
+use std::fmt;
+
+// OKLCH lightness step when searching for a passing foreground
+const LIGHTNESS_STEP: f64 = 0.001;
+
+/// WCAG conformance level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WcagLevel {
+    Aa,
+    Aaa,
+}
+
+/// WCAG text category. Large is at least 18pt, or 14pt bold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextSize {
+    #[default]
+    Normal,
+    Large,
+}
+
+impl WcagLevel {
+    /// Smallest ratio that passes for text of `size`
+    pub fn min_ratio(self, size: TextSize) -> f64 {
+        match (self, size) {
+            (WcagLevel::Aa, TextSize::Normal) => 4.5,
+            (WcagLevel::Aa, TextSize::Large) => 3.0,
+            (WcagLevel::Aaa, TextSize::Normal) => 7.0,
+            (WcagLevel::Aaa, TextSize::Large) => 4.5,
+        }
+    }
+}
+
+impl fmt::Display for WcagLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            WcagLevel::Aa => "AA",
+            WcagLevel::Aaa => "AAA",
+        })
+    }
+}
+
+/// Everything known about one text color on one background
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastReport {
+    pub fg: Rgb,
+    pub bg: Rgb,
+    /// WCAG 2.1 ratio, 1.0 to 21.0
+    pub ratio: f64,
+    /// APCA Lc, about -108 to 106; negative for light text on a dark background
+    pub apca: f64,
+}
+
+impl ContrastReport {
+    /// Whether the WCAG ratio reaches `level` for text of `size`
+    pub fn passes(&self, level: WcagLevel, size: TextSize) -> bool {
+        self.ratio >= level.min_ratio(size)
+    }
+}
+
+/// WCAG ratio and APCA Lc of `fg` text on `bg`
+pub fn check(fg: Rgb, bg: Rgb) -> ContrastReport {
+    ContrastReport { fg, bg, ratio: contrast_ratio(fg, bg), apca: apca_lc(fg, bg) }
+}
+
+// sRGB transfer function, byte -> linear light
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+/// WCAG 2.1 relative luminance, 0 for black to 1 for white
+pub fn relative_luminance(rgb: Rgb) -> f64 {
+    0.2126 * srgb_to_linear(rgb.r) + 0.7152 * srgb_to_linear(rgb.g) + 0.0722 * srgb_to_linear(rgb.b)
+}
+
+/// WCAG 2.1 contrast ratio; symmetric, so the order of the colors doesn't matter
+pub fn contrast_ratio(a: Rgb, b: Rgb) -> f64 {
+    let (l1, l2) = (relative_luminance(a), relative_luminance(b));
+    (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+}
+
+/// APCA lightness contrast (0.0.98G-4g) of `text` on `bg`. Unlike the WCAG
+/// ratio it depends on which color is the text: dark text on light gives a
+/// positive Lc, light text on dark a negative one. |Lc| 75 is the usual
+/// minimum for body text, 60 for large text, 45 for headlines.
+pub fn apca_lc(text: Rgb, bg: Rgb) -> f64 {
+    // APCA's own screen luminance: a plain 2.4 gamma, no linear toe
+    let y = |c: Rgb| {
+        let ch = |v: u8| (v as f64 / 255.0).powf(2.4);
+        0.2126729 * ch(c.r) + 0.7151522 * ch(c.g) + 0.0721750 * ch(c.b)
+    };
+    // soft clamp near black
+    let clamp_black = |y: f64| if y > 0.022 { y } else { y + (0.022 - y).powf(1.414) };
+
+    let (y_text, y_bg) = (clamp_black(y(text)), clamp_black(y(bg)));
+    if (y_bg - y_text).abs() < 0.0005 {
+        return 0.0;
+    }
+    let lc = if y_bg > y_text {
+        // dark text on a light background
+        let sapc = (y_bg.powf(0.56) - y_text.powf(0.57)) * 1.14;
+        if sapc < 0.1 { 0.0 } else { sapc - 0.027 }
+    } else {
+        let sapc = (y_bg.powf(0.65) - y_text.powf(0.62)) * 1.14;
+        if sapc > -0.1 { 0.0 } else { sapc + 0.027 }
+    };
+    lc * 100.0
+}
+
+/// A foreground that reaches a contrast target, from [`suggest_foreground`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastFix {
+    pub rgb: Rgb,
+    /// WCAG ratio of `rgb` on the background
+    pub ratio: f64,
+    /// The catalog entry `rgb` was snapped to, when snapping was asked for
+    pub name: Option<(HexCode, ColorName)>,
+}
+
+/// The foreground closest to `fg` in OKLCH lightness whose WCAG ratio on `bg`
+/// is at least `target`. Hue is kept and chroma only lowered where the new
+/// lightness can't hold it in sRGB. `fg` comes back unchanged when it
+/// already passes.
+///
+/// With `snap`, the result is then replaced by the nearest named color of
+/// that origin that still passes. `None` when no lightness (or no named
+/// color) reaches the target.
+///
+/// ```
+/// use colorum::contrast::{contrast_ratio, suggest_foreground};
+/// use colorum::{DistanceMetric, Origin, Oklch, Rgb};
+///
+/// let tomato = Rgb { r: 255, g: 99, b: 71 };
+/// let white = Rgb { r: 255, g: 255, b: 255 };
+/// let fix = suggest_foreground(tomato, white, 4.5, None).unwrap();
+/// assert!(fix.ratio >= 4.5 && contrast_ratio(fix.rgb, white) == fix.ratio);
+/// assert!(Oklch::from(fix.rgb).l < Oklch::from(tomato).l);
+///
+/// let named = suggest_foreground(tomato, white, 4.5, Some((Origin::Css, DistanceMetric::default()))).unwrap();
+/// assert!(named.ratio >= 4.5 && named.name.is_some());
+/// ```
+pub fn suggest_foreground(fg: Rgb, bg: Rgb, target: f64, snap: Option<(Origin, DistanceMetric)>) -> Option<ContrastFix> {
+    let passes = |rgb: Rgb| contrast_ratio(rgb, bg) >= target;
+    let start = Oklch::from(fg);
+
+    let adjusted = if passes(fg) {
+        Some(fg)
+    } else {
+        // first passing lightness going darker and going lighter; keep the nearer one
+        let first_pass = |dir: f64| {
+            let room = if dir < 0.0 { start.l } else { 1.0 - start.l };
+            let steps = (room / LIGHTNESS_STEP).ceil() as usize;
+            (1..=steps).find_map(|i| {
+                let l = (start.l + dir * i as f64 * LIGHTNESS_STEP).clamp(0.0, 1.0);
+                let rgb = Rgb::from(Oklch::new(l, start.c, start.h).to_gamut());
+                passes(rgb).then_some((i, rgb))
+            })
+        };
+        match (first_pass(-1.0), first_pass(1.0)) {
+            (Some(dark), Some(light)) => Some(if light.0 < dark.0 { light.1 } else { dark.1 }),
+            (dark, light) => dark.or(light).map(|(_, rgb)| rgb),
+        }
+    }?;
+
+    let Some((origin, metric)) = snap else {
+        return Some(ContrastFix { rgb: adjusted, ratio: contrast_ratio(adjusted, bg), name: None });
+    };
+    let passing: Vec<(HexCode, ColorName)> = origin_slice(origin)
+        .iter()
+        .filter(|(hex, _)| hex_to_rgb(hex.as_str()).is_some_and(passes))
+        .copied()
+        .collect();
+    let (hex, name, _) = nearest_in(&passing, adjusted, 1, metric).into_iter().next()?;
+    let rgb = hex_to_rgb(hex.as_str())?;
+    Some(ContrastFix { rgb, ratio: contrast_ratio(rgb, bg), name: Some((hex, name)) })
+}
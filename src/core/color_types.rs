@@ -1,5 +1,6 @@
 /// Entity type for color names - what kind of thing the color represents
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Entity {
     Color,        // Pure color name like "red", "blue"
     Object,       // Physical object like "tomato", "sky"
@@ -15,6 +16,7 @@ pub enum Entity {
 
 /// Ordering type for color names - how they should be sorted
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ordering {
     Name,         // Sort alphabetically by name
     Kelvin,       // Sort by temperature (for temperature-based colors)
@@ -24,6 +26,38 @@ pub enum Ordering {
     Custom(u16),  // Custom ordering with priority value
 }
 
+/// What an alias is to the primary name
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AliasKind {
+    Translation,      // The name in another language, in its own script: "आसमानी"
+    Transliteration,  // That name in Latin letters: "aasmani"
+    Spelling,         // Another spelling of the primary name: "Slate Grey"
+}
+
+/// Another name a color can be found by
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Alias {
+    pub text: &'static str,
+    pub kind: AliasKind,
+    /// BCP 47 language tag ("hi", "fa"); `None` for spellings
+    pub lang: Option<&'static str>,
+}
+
+impl Alias {
+    pub const fn translation(lang: &'static str, text: &'static str) -> Self {
+        Self { text, kind: AliasKind::Translation, lang: Some(lang) }
+    }
+
+    pub const fn transliteration(lang: &'static str, text: &'static str) -> Self {
+        Self { text, kind: AliasKind::Transliteration, lang: Some(lang) }
+    }
+
+    pub const fn spelling(text: &'static str) -> Self {
+        Self { text, kind: AliasKind::Spelling, lang: None }
+    }
+}
+
 /// Strongly typed color name that supports sorting and comparison
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct ColorName {
@@ -31,6 +65,7 @@ pub struct ColorName {
     entity: Entity,
     origin: crate::colors_helper::Origin,
     ordering: Ordering,
+    aliases: &'static [Alias],
 }
 
 impl ColorName {
@@ -41,7 +76,12 @@ impl ColorName {
         origin: crate::colors_helper::Origin,
         ordering: Ordering,
     ) -> Self {
-        Self { name, entity, origin, ordering }
+        Self { name, entity, origin, ordering, aliases: &[] }
+    }
+
+    /// The same name with translations, transliterations or other spellings attached
+    pub const fn with_aliases(self, aliases: &'static [Alias]) -> Self {
+        Self { aliases, ..self }
     }
 
     /// Create a new ColorName with just name (for backward compatibility)
@@ -51,6 +91,7 @@ impl ColorName {
             entity: Entity::Color,
             origin: crate::colors_helper::Origin::All,
             ordering: Ordering::Name,
+            aliases: &[],
         }
     }
 
@@ -73,6 +114,23 @@ impl ColorName {
     pub fn ordering(&self) -> Ordering {
         self.ordering
     }
+
+    /// Aliases given in the palette data; see `colors_helper::aliases_of` for
+    /// these plus the generated grey/gray-style spellings
+    pub fn aliases(&self) -> &'static [Alias] {
+        self.aliases
+    }
+
+    /// The catalog entry called `name` in `origin` (`Origin::All` searches
+    /// every built-in palette). An exact match wins over a case-insensitive one.
+    pub fn resolve(name: &str, origin: crate::colors_helper::Origin) -> Option<ColorName> {
+        let slice = crate::colors_helper::origin_slice(origin);
+        slice
+            .iter()
+            .find(|(_, n)| n.name == name)
+            .or_else(|| slice.iter().find(|(_, n)| n.name.eq_ignore_ascii_case(name)))
+            .map(|(_, n)| *n)
+    }
 }
 
 impl std::fmt::Display for ColorName {
@@ -228,6 +286,101 @@ impl Ord for HexCode {
     }
 }
 
+/// Owned counterpart of [`HexCode`], for hex strings that are not `'static`
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct OwnedHexCode(String);
+
+impl OwnedHexCode {
+    /// Wrap a hex string as-is (assumes valid format)
+    pub fn new(hex: impl Into<String>) -> Self {
+        Self(hex.into())
+    }
+
+    /// Normalize any `#RGB`/`#RRGGBB` form (alpha is dropped)
+    pub fn parse(hex: &str) -> Result<Self, crate::core::hex::HexError> {
+        crate::core::hex::normalize_hex(hex).map(Self)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get RGB components as (r, g, b) tuple
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        crate::core::rgb::hex_to_rgb(&self.0.to_ascii_uppercase()).map(|c| (c.r, c.g, c.b))
+    }
+}
+
+impl From<HexCode> for OwnedHexCode {
+    fn from(hex: HexCode) -> Self {
+        Self(hex.0.to_string())
+    }
+}
+
+impl std::fmt::Display for OwnedHexCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Owned counterpart of [`ColorName`], for names that are not in the catalog
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OwnedColorName {
+    name: String,
+    entity: Entity,
+    origin: crate::colors_helper::Origin,
+    ordering: Ordering,
+}
+
+impl OwnedColorName {
+    pub fn new_full(
+        name: impl Into<String>,
+        entity: Entity,
+        origin: crate::colors_helper::Origin,
+        ordering: Ordering,
+    ) -> Self {
+        Self { name: name.into(), entity, origin, ordering }
+    }
+
+    /// A plain color name with no particular origin
+    pub fn new(name: impl Into<String>) -> Self {
+        Self::new_full(name, Entity::Color, crate::colors_helper::Origin::All, Ordering::Name)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    pub fn origin(&self) -> crate::colors_helper::Origin {
+        self.origin
+    }
+
+    pub fn ordering(&self) -> Ordering {
+        self.ordering
+    }
+
+    /// The catalog entry with this name and origin, if there is one
+    pub fn resolve(&self) -> Option<ColorName> {
+        ColorName::resolve(&self.name, self.origin)
+    }
+}
+
+impl From<ColorName> for OwnedColorName {
+    fn from(name: ColorName) -> Self {
+        Self::new_full(name.name, name.entity, name.origin, name.ordering)
+    }
+}
+
+impl std::fmt::Display for OwnedColorName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
 /// Convenience macro for creating color arrays with typed objects
 #[macro_export]
 macro_rules! color_array {
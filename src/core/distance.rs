@@ -0,0 +1,158 @@
+//! Color difference metrics used for nearest-name matching.
+//!
+//! All metrics take two `Rgb` values and return a distance in the metric's
+//! native unit (ΔE for the CIE formulas, Euclidean units for RGB and OKLab).
+//! Only compare distances produced by the same metric.
+
+use crate::core::rgb::Rgb;
+use crate::core::spaces::Oklab;
+use std::fmt;
+
+pub use crate::core::spaces::Lab;
+
+/// Which color-difference formula to use when ranking nearest colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DistanceMetric {
+    /// Plain Euclidean distance in sRGB (0..~441).
+    RgbEuclid,
+    /// CIE 1976 ΔE*ab — Euclidean distance in CIELAB.
+    Cie76,
+    /// CIE 1994 ΔE*94 (graphic arts weights).
+    Cie94,
+    /// CIEDE2000 ΔE00 — the most perceptually uniform CIE formula.
+    #[default]
+    Ciede2000,
+    /// Euclidean distance in OKLab (0..~1).
+    Oklab,
+}
+
+impl DistanceMetric {
+    /// Every metric, in the order the UI lists them
+    pub const ALL: [DistanceMetric; 5] = [
+        DistanceMetric::RgbEuclid,
+        DistanceMetric::Cie76,
+        DistanceMetric::Cie94,
+        DistanceMetric::Ciede2000,
+        DistanceMetric::Oklab,
+    ];
+
+    /// Get a display name for the metric
+    pub fn display_name(self) -> &'static str {
+        match self {
+            DistanceMetric::RgbEuclid => "RGB",
+            DistanceMetric::Cie76 => "CIE76",
+            DistanceMetric::Cie94 => "CIE94",
+            DistanceMetric::Ciede2000 => "CIEDE2000",
+            DistanceMetric::Oklab => "OKLab",
+        }
+    }
+
+    /// Distance between two sRGB colors under this metric
+    pub fn distance(self, a: Rgb, b: Rgb) -> f64 {
+        match self {
+            DistanceMetric::RgbEuclid => (crate::core::rgb::dist2(a, b) as f64).sqrt(),
+            DistanceMetric::Cie76 => delta_e_76(Lab::from_rgb(a), Lab::from_rgb(b)),
+            DistanceMetric::Cie94 => delta_e_94(Lab::from_rgb(a), Lab::from_rgb(b)),
+            DistanceMetric::Ciede2000 => delta_e_2000(Lab::from_rgb(a), Lab::from_rgb(b)),
+            DistanceMetric::Oklab => {
+                let (x, y) = (Oklab::from(a), Oklab::from(b));
+                ((x.l - y.l).powi(2) + (x.a - y.a).powi(2) + (x.b - y.b).powi(2)).sqrt()
+            }
+        }
+    }
+}
+
+impl fmt::Display for DistanceMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
+
+/// CIE 1976 color difference
+pub fn delta_e_76(x: Lab, y: Lab) -> f64 {
+    ((x.l - y.l).powi(2) + (x.a - y.a).powi(2) + (x.b - y.b).powi(2)).sqrt()
+}
+
+/// CIE 1994 color difference (graphic arts: kL = 1, K1 = 0.045, K2 = 0.015).
+/// Not symmetric: `x` is the reference color.
+pub fn delta_e_94(x: Lab, y: Lab) -> f64 {
+    let c1 = x.a.hypot(x.b);
+    let c2 = y.a.hypot(y.b);
+    let dl = x.l - y.l;
+    let dc = c1 - c2;
+    let da = x.a - y.a;
+    let db = x.b - y.b;
+    let dh2 = (da * da + db * db - dc * dc).max(0.0);
+
+    let sc = 1.0 + 0.045 * c1;
+    let sh = 1.0 + 0.015 * c1;
+
+    (dl * dl + (dc / sc).powi(2) + dh2 / (sh * sh)).sqrt()
+}
+
+/// CIEDE2000 color difference (kL = kC = kH = 1), following Sharma, Wu & Dalal (2005).
+pub fn delta_e_2000(x: Lab, y: Lab) -> f64 {
+    let c1 = x.a.hypot(x.b);
+    let c2 = y.a.hypot(y.b);
+    let c_bar7 = ((c1 + c2) / 2.0).powi(7);
+    let g = 0.5 * (1.0 - (c_bar7 / (c_bar7 + 25f64.powi(7))).sqrt());
+
+    let a1p = (1.0 + g) * x.a;
+    let a2p = (1.0 + g) * y.a;
+    let c1p = a1p.hypot(x.b);
+    let c2p = a2p.hypot(y.b);
+
+    let hue = |b: f64, ap: f64| {
+        if b == 0.0 && ap == 0.0 {
+            0.0
+        } else {
+            b.atan2(ap).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let h1p = hue(x.b, a1p);
+    let h2p = hue(y.b, a2p);
+
+    let dlp = y.l - x.l;
+    let dcp = c2p - c1p;
+    let dhp = if c1p * c2p == 0.0 {
+        0.0
+    } else if (h2p - h1p).abs() <= 180.0 {
+        h2p - h1p
+    } else if h2p - h1p > 180.0 {
+        h2p - h1p - 360.0
+    } else {
+        h2p - h1p + 360.0
+    };
+    let d_hp = 2.0 * (c1p * c2p).sqrt() * (dhp / 2.0).to_radians().sin();
+
+    let l_bar_p = (x.l + y.l) / 2.0;
+    let c_bar_p = (c1p + c2p) / 2.0;
+    let h_bar_p = if c1p * c2p == 0.0 {
+        h1p + h2p
+    } else if (h1p - h2p).abs() <= 180.0 {
+        (h1p + h2p) / 2.0
+    } else if h1p + h2p < 360.0 {
+        (h1p + h2p + 360.0) / 2.0
+    } else {
+        (h1p + h2p - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_bar_p - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar_p).to_radians().cos()
+        + 0.32 * (3.0 * h_bar_p + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar_p - 63.0).to_radians().cos();
+    let d_theta = 30.0 * (-((h_bar_p - 275.0) / 25.0).powi(2)).exp();
+    let c_bar_p7 = c_bar_p.powi(7);
+    let r_c = 2.0 * (c_bar_p7 / (c_bar_p7 + 25f64.powi(7))).sqrt();
+    let l50 = (l_bar_p - 50.0).powi(2);
+    let s_l = 1.0 + 0.015 * l50 / (20.0 + l50).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar_p;
+    let s_h = 1.0 + 0.015 * c_bar_p * t;
+    let r_t = -(2.0 * d_theta).to_radians().sin() * r_c;
+
+    let tl = dlp / s_l;
+    let tc = dcp / s_c;
+    let th = d_hp / s_h;
+    (tl * tl + tc * tc + th * th + r_t * tc * th).sqrt()
+}
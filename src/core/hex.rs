@@ -4,22 +4,30 @@ use std::fmt;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HexError {
     BadFormat,
-    UnsupportedLength, // must be 3, 6, or 8 hex digits after '#'
+    UnsupportedLength, // must be 3, 4, 6, or 8 hex digits after '#'
 }
 
 impl fmt::Display for HexError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             HexError::BadFormat => write!(f, "invalid hex format"),
-            HexError::UnsupportedLength => write!(f, "supported: #RGB, #RRGGBB, #RRGGBBAA"),
+            HexError::UnsupportedLength => write!(f, "supported: #RGB, #RGBA, #RRGGBB, #RRGGBBAA"),
         }
     }
 }
 impl std::error::Error for HexError {}
 
-/// Normalize `#RGB`, `#RRGGBB`, or `#RRGGBBAA` into uppercase `#RRGGBB`.
-/// Alpha, if present, is ignored.
+/// Normalize `#RGB`, `#RGBA`, `#RRGGBB`, or `#RRGGBBAA` into uppercase `#RRGGBB`.
+/// Alpha, if present, is dropped; use [`normalize_hex_alpha`] to keep it.
 pub fn normalize_hex(s: &str) -> Result<String, HexError> {
+    let mut out = normalize_hex_alpha(s)?;
+    out.truncate(7);
+    Ok(out)
+}
+
+/// Normalize `#RGB`, `#RGBA`, `#RRGGBB`, or `#RRGGBBAA` into uppercase hex,
+/// keeping alpha: `#RRGGBB` for inputs without alpha, `#RRGGBBAA` otherwise.
+pub fn normalize_hex_alpha(s: &str) -> Result<String, HexError> {
     let s = s.trim();
     if !s.starts_with('#') {
         return Err(HexError::BadFormat);
@@ -28,25 +36,19 @@ pub fn normalize_hex(s: &str) -> Result<String, HexError> {
     if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
         return Err(HexError::BadFormat);
     }
-    let out = match digits.len() {
-        3 => {
-            // #RGB -> #RRGGBB
-            let mut o = String::with_capacity(7);
-            o.push('#');
+    let mut out = String::with_capacity(9);
+    out.push('#');
+    match digits.len() {
+        3 | 4 => {
+            // #RGB(A) -> #RRGGBB(AA)
             for ch in digits.chars() {
-                o.push(ch);
-                o.push(ch);
+                out.push(ch);
+                out.push(ch);
             }
-            o
-        }
-        6 | 8 => {
-            let mut o = String::with_capacity(7);
-            o.push('#');
-            o.push_str(&digits[..6]);
-            o
         }
+        6 | 8 => out.push_str(digits),
         _ => return Err(HexError::UnsupportedLength),
-    };
+    }
     Ok(out.to_ascii_uppercase())
 }
 
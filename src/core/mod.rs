@@ -1,7 +1,12 @@
 //! Core color types and utilities
 
 pub mod color_types;
+pub mod distance;
 pub mod hex;
+pub mod parse;
 pub mod rgb;
+pub mod spaces;
+#[cfg(feature = "serde")]
+mod serde_impls;
 
 // Re-export commonly used types
\ No newline at end of file
@@ -0,0 +1,245 @@
+//! Parse any CSS Color Level 4 color string into `Rgba`.
+//!
+//! Accepts `rgb()`/`rgba()`, `hsl()`/`hsla()`, `hwb()`, `lab()`, `lch()`,
+//! `oklab()`, `oklch()`, `color(srgb …)`, `#`-prefixed or bare hex, `transparent`,
+//! any color name in the catalog, and RAL Classic codes like `RAL 3020`. Both the
+//! legacy comma syntax (`rgb(12, 34, 56)`) and the modern space syntax
+//! (`rgb(12 34 56 / 50%)`) work.
+//! `lab()` and `lch()` are read on the D50 white the spec uses for them.
+//! Colors outside the sRGB gamut are clamped.
+
+use crate::colors_helper::lookup_ral;
+use crate::core::hex::{HexError, hex_for_name, normalize_hex_alpha};
+use crate::core::rgb::{Hsl, Rgb, Rgba, hex_to_rgba};
+use crate::core::spaces::{Hwb, Lab, Lch, Oklab, Oklch, css_lab_to_rgb};
+use std::fmt;
+use std::str::FromStr;
+
+// Functions handled by `parse_function`; any other `name(...)` is tried as a
+// catalog name first, since some contain parentheses ("Cu(I) Copper(I) Blue-green")
+const CSS_FUNCTIONS: [&str; 10] = ["rgb", "rgba", "hsl", "hsla", "hwb", "lab", "lch", "oklab", "oklch", "color"];
+
+/// Errors from [`parse_color`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    Hex(HexError),
+    UnknownFunction(String),
+    UnsupportedColorSpace(String),
+    WrongArgumentCount { function: String, expected: usize, found: usize },
+    BadValue(String),
+    UnknownColor(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty color string"),
+            ParseError::Hex(e) => write!(f, "{e}"),
+            ParseError::UnknownFunction(name) => write!(f, "unknown color function `{name}()`"),
+            ParseError::UnsupportedColorSpace(space) => {
+                write!(f, "unsupported color space `{space}` (only srgb)")
+            }
+            ParseError::WrongArgumentCount { function, expected, found } => {
+                write!(f, "`{function}()` takes {expected} components, got {found}")
+            }
+            ParseError::BadValue(v) => write!(f, "invalid component `{v}`"),
+            ParseError::UnknownColor(name) => write!(f, "unknown color `{name}`"),
+        }
+    }
+}
+impl std::error::Error for ParseError {}
+
+impl From<HexError> for ParseError {
+    fn from(e: HexError) -> Self {
+        ParseError::Hex(e)
+    }
+}
+
+impl FromStr for Rgba {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_color(s)
+    }
+}
+
+/// Parse a CSS color string, bare hex, or catalog color name.
+pub fn parse_color(input: &str) -> Result<Rgba, ParseError> {
+    let s = input.trim();
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let open = s.find('(');
+    if let Some(open) = open
+        && CSS_FUNCTIONS.contains(&s[..open].trim().to_ascii_lowercase().as_str())
+    {
+        return function_call(s, open);
+    }
+
+    if s.starts_with('#') {
+        return hex(s);
+    }
+
+    if s.eq_ignore_ascii_case("transparent") {
+        return Ok(Rgba::new(0, 0, 0, 0));
+    }
+
+    // names win over bare hex so "bead" or "face" find the catalog entry first
+    if let Some(h) = hex_for_name(s) {
+        return hex(h);
+    }
+    if let Some((h, _)) = lookup_ral(s) {
+        return hex(h.as_str());
+    }
+
+    // not a name either, so report it as the function it looks like
+    if let Some(open) = open {
+        return function_call(s, open);
+    }
+
+    if matches!(s.len(), 3 | 4 | 6 | 8) && s.chars().all(|c| c.is_ascii_hexdigit()) {
+        return hex(&format!("#{s}"));
+    }
+
+    Err(ParseError::UnknownColor(s.to_string()))
+}
+
+// `name(inner)` with `open` at the parenthesis
+fn function_call(s: &str, open: usize) -> Result<Rgba, ParseError> {
+    let Some(inner) = s[open + 1..].strip_suffix(')') else {
+        return Err(ParseError::BadValue(s.to_string()));
+    };
+    parse_function(&s[..open].trim().to_ascii_lowercase(), inner)
+}
+
+fn hex(s: &str) -> Result<Rgba, ParseError> {
+    let norm = normalize_hex_alpha(s)?;
+    hex_to_rgba(&norm).ok_or(ParseError::Hex(HexError::BadFormat))
+}
+
+/// A single component inside a color function
+#[derive(Debug, Clone, Copy)]
+enum Value {
+    Number(f64),
+    Percent(f64), // 0-100
+    Degrees(f64), // any angle unit, converted
+}
+
+impl Value {
+    fn parse(tok: &str) -> Result<Self, ParseError> {
+        let bad = || ParseError::BadValue(tok.to_string());
+        let t = tok.to_ascii_lowercase();
+        if t == "none" {
+            return Ok(Value::Number(0.0));
+        }
+        let num = |n: &str| n.parse::<f64>().ok().filter(|v| v.is_finite()).ok_or_else(bad);
+
+        if let Some(n) = t.strip_suffix('%') {
+            Ok(Value::Percent(num(n)?))
+        } else if let Some(n) = t.strip_suffix("deg") {
+            Ok(Value::Degrees(num(n)?))
+        } else if let Some(n) = t.strip_suffix("grad") {
+            Ok(Value::Degrees(num(n)? * 0.9))
+        } else if let Some(n) = t.strip_suffix("rad") {
+            Ok(Value::Degrees(num(n)?.to_degrees()))
+        } else if let Some(n) = t.strip_suffix("turn") {
+            Ok(Value::Degrees(num(n)? * 360.0))
+        } else {
+            Ok(Value::Number(num(&t)?))
+        }
+    }
+
+    /// A number where `100%` maps to `full`
+    fn scaled(self, full: f64, tok: &str) -> Result<f64, ParseError> {
+        match self {
+            Value::Number(n) => Ok(n),
+            Value::Percent(p) => Ok(p / 100.0 * full),
+            Value::Degrees(_) => Err(ParseError::BadValue(tok.to_string())),
+        }
+    }
+
+    fn hue(self, tok: &str) -> Result<f64, ParseError> {
+        match self {
+            Value::Number(n) | Value::Degrees(n) => Ok(n.rem_euclid(360.0)),
+            Value::Percent(_) => Err(ParseError::BadValue(tok.to_string())),
+        }
+    }
+}
+
+// Split "a b c / d" or "a, b, c, d" into the color components and an optional alpha
+fn split_args(inner: &str) -> (Vec<&str>, Option<&str>) {
+    let (main, slash_alpha) = match inner.split_once('/') {
+        Some((m, a)) => (m, Some(a.trim())),
+        None => (inner, None),
+    };
+    let mut parts: Vec<&str> = main
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+        .collect();
+    let alpha = match slash_alpha {
+        Some(a) => Some(a),
+        // legacy rgba(r, g, b, a)
+        None if parts.len() == 4 => parts.pop(),
+        None => None,
+    };
+    (parts, alpha)
+}
+
+fn parse_alpha(tok: Option<&str>) -> Result<u8, ParseError> {
+    let Some(tok) = tok else {
+        return Ok(255);
+    };
+    let a = Value::parse(tok)?.scaled(1.0, tok)?;
+    Ok((a.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+fn parse_function(name: &str, inner: &str) -> Result<Rgba, ParseError> {
+    // color(srgb r g b) names its space first
+    let inner = if name == "color" {
+        let inner = inner.trim_start();
+        let end = inner.find(|c: char| c.is_whitespace() || c == ',').unwrap_or(inner.len());
+        let space = inner[..end].to_ascii_lowercase();
+        if space != "srgb" {
+            return Err(ParseError::UnsupportedColorSpace(space));
+        }
+        &inner[end..]
+    } else {
+        inner
+    };
+    let (parts, alpha_tok) = split_args(inner);
+
+    if parts.len() != 3 {
+        return Err(ParseError::WrongArgumentCount {
+            function: name.to_string(),
+            expected: 3,
+            found: parts.len(),
+        });
+    }
+    let v: Vec<Value> = parts.iter().map(|p| Value::parse(p)).collect::<Result<_, _>>()?;
+    let (t0, t1, t2) = (parts[0], parts[1], parts[2]);
+
+    let rgb: Rgb = match name {
+        "rgb" | "rgba" => Rgb::from_unit(
+            v[0].scaled(255.0, t0)? / 255.0,
+            v[1].scaled(255.0, t1)? / 255.0,
+            v[2].scaled(255.0, t2)? / 255.0,
+        ),
+        "color" => Rgb::from_unit(v[0].scaled(1.0, t0)?, v[1].scaled(1.0, t1)?, v[2].scaled(1.0, t2)?),
+        "hsl" | "hsla" => Hsl {
+            h: v[0].hue(t0)? as f32,
+            s: v[1].scaled(100.0, t1)? as f32,
+            l: v[2].scaled(100.0, t2)? as f32,
+        }
+        .into(),
+        "hwb" => Hwb::new(v[0].hue(t0)?, v[1].scaled(100.0, t1)?, v[2].scaled(100.0, t2)?).into(),
+        "lab" => css_lab_to_rgb(Lab::new(v[0].scaled(100.0, t0)?, v[1].scaled(125.0, t1)?, v[2].scaled(125.0, t2)?)),
+        "lch" => css_lab_to_rgb(Lch::new(v[0].scaled(100.0, t0)?, v[1].scaled(150.0, t1)?, v[2].hue(t2)?).into()),
+        "oklab" => Oklab::new(v[0].scaled(1.0, t0)?, v[1].scaled(0.4, t1)?, v[2].scaled(0.4, t2)?).into(),
+        "oklch" => Oklch::new(v[0].scaled(1.0, t0)?, v[1].scaled(0.4, t1)?, v[2].hue(t2)?).into(),
+        _ => return Err(ParseError::UnknownFunction(name.to_string())),
+    };
+
+    Ok(Rgba::from(rgb).with_alpha(parse_alpha(alpha_tok)?))
+}
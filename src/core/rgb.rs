@@ -1,5 +1,6 @@
 /// Simple RGB struct.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rgb {
     pub r: u8,
     pub g: u8,
@@ -22,6 +23,75 @@ pub fn rgb_to_hex(rgb: Rgb) -> String {
     format!("#{:02X}{:02X}{:02X}", rgb.r, rgb.g, rgb.b)
 }
 
+/// RGB plus an alpha byte (255 = opaque).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Rgba {
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// The color channels without alpha
+    pub const fn rgb(self) -> Rgb {
+        Rgb { r: self.r, g: self.g, b: self.b }
+    }
+
+    /// Same color with a different alpha
+    pub const fn with_alpha(self, a: u8) -> Self {
+        Self { a, ..self }
+    }
+
+    pub const fn is_opaque(self) -> bool {
+        self.a == 255
+    }
+
+    /// Alpha as 0-1
+    pub fn alpha(self) -> f32 {
+        self.a as f32 / 255.0
+    }
+}
+
+impl From<Rgb> for Rgba {
+    fn from(rgb: Rgb) -> Self {
+        Rgba::new(rgb.r, rgb.g, rgb.b, 255)
+    }
+}
+
+impl From<Rgba> for Rgb {
+    fn from(rgba: Rgba) -> Self {
+        rgba.rgb()
+    }
+}
+
+/// Convert normalized "#RRGGBB" or "#RRGGBBAA" to `Rgba`.
+pub fn hex_to_rgba(norm: &str) -> Option<Rgba> {
+    match norm.len() {
+        7 => hex_to_rgb(norm).map(Rgba::from),
+        9 => {
+            let rgb = hex_to_rgb(norm.get(..7)?)?;
+            let a = u8::from_str_radix(norm.get(7..9)?, 16).ok()?;
+            Some(Rgba::from(rgb).with_alpha(a))
+        }
+        _ => None,
+    }
+}
+
+/// Convert `Rgba` to `#RRGGBB` when opaque, `#RRGGBBAA` otherwise.
+pub fn rgba_to_hex(rgba: Rgba) -> String {
+    if rgba.is_opaque() {
+        rgb_to_hex(rgba.rgb())
+    } else {
+        format!("#{:02X}{:02X}{:02X}{:02X}", rgba.r, rgba.g, rgba.b, rgba.a)
+    }
+}
+
 /// Euclidean distance squared in RGB (no gamma/perc.).
 pub fn dist2(a: Rgb, b: Rgb) -> u32 {
     let dr = a.r as i32 - b.r as i32;
@@ -32,10 +102,13 @@ pub fn dist2(a: Rgb, b: Rgb) -> u32 {
 
 /// Copy format for cycling through different color representations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CopyFormat {
-    Hex,        // #FF5733
+    Hex,        // #FF5733 (#FF573380 with alpha)
     Rgb,        // rgb(255, 87, 51)
+    Rgba,       // rgba(255, 87, 51, 0.5)
     Hsl,        // hsl(14, 100%, 60%)
+    Hsla,       // hsla(14, 100%, 60%, 0.5)
     RgbValues,  // 255, 87, 51
 }
 
@@ -44,8 +117,10 @@ impl CopyFormat {
     pub fn next(self) -> Self {
         match self {
             CopyFormat::Hex => CopyFormat::Rgb,
-            CopyFormat::Rgb => CopyFormat::Hsl,
-            CopyFormat::Hsl => CopyFormat::RgbValues,
+            CopyFormat::Rgb => CopyFormat::Rgba,
+            CopyFormat::Rgba => CopyFormat::Hsl,
+            CopyFormat::Hsl => CopyFormat::Hsla,
+            CopyFormat::Hsla => CopyFormat::RgbValues,
             CopyFormat::RgbValues => CopyFormat::Hex,
         }
     }
@@ -55,7 +130,9 @@ impl CopyFormat {
         match self {
             CopyFormat::Hex => "HEX",
             CopyFormat::Rgb => "RGB",
+            CopyFormat::Rgba => "RGBA",
             CopyFormat::Hsl => "HSL",
+            CopyFormat::Hsla => "HSLA",
             CopyFormat::RgbValues => "RGB Values",
         }
     }
@@ -69,6 +146,7 @@ impl Default for CopyFormat {
 
 /// HSL representation
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hsl {
     pub h: f32, // hue 0-360
     pub s: f32, // saturation 0-100
@@ -116,13 +194,31 @@ pub fn rgb_to_hsl(rgb: Rgb) -> Hsl {
 
 /// Format RGB in various formats
 pub fn format_rgb(rgb: Rgb, format: CopyFormat) -> String {
+    format_rgba(Rgba::from(rgb), format)
+}
+
+/// Format RGBA in various formats. Formats without an alpha slot
+/// (`Rgb`, `Hsl`, `RgbValues`) drop it; `Hex` keeps it when not opaque.
+pub fn format_rgba(rgba: Rgba, format: CopyFormat) -> String {
+    let rgb = rgba.rgb();
     match format {
-        CopyFormat::Hex => rgb_to_hex(rgb),
+        CopyFormat::Hex => rgba_to_hex(rgba),
         CopyFormat::Rgb => format!("rgb({}, {}, {})", rgb.r, rgb.g, rgb.b),
+        CopyFormat::Rgba => format!("rgba({}, {}, {}, {})", rgb.r, rgb.g, rgb.b, alpha_str(rgba.a)),
         CopyFormat::Hsl => {
             let hsl = rgb_to_hsl(rgb);
             format!("hsl({:.0}, {:.0}%, {:.0}%)", hsl.h, hsl.s, hsl.l)
         },
+        CopyFormat::Hsla => {
+            let hsl = rgb_to_hsl(rgb);
+            format!("hsla({:.0}, {:.0}%, {:.0}%, {})", hsl.h, hsl.s, hsl.l, alpha_str(rgba.a))
+        },
         CopyFormat::RgbValues => format!("{}, {}, {}", rgb.r, rgb.g, rgb.b),
     }
 }
+
+// CSS alpha: up to three decimals, no trailing zeros ("1", "0.5", "0.502")
+fn alpha_str(a: u8) -> String {
+    let s = format!("{:.3}", a as f32 / 255.0);
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
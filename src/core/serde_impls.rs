@@ -0,0 +1,142 @@
+//! `Serialize`/`Deserialize` for the types that can't simply derive them.
+//!
+//! - `HexCode` is a string; deserializing runs it through `normalize_hex`, so
+//!   `"#3af"` comes back as `"#33AAFF"`. Each distinct hex is leaked once to
+//!   get the `&'static str` a `HexCode` holds.
+//! - `PaletteId` is its display name (`"CSS"`, `"RAL Classic"`).
+//! - `ColorName` is `{name, entity, origin, ordering}` and deserializes to the
+//!   catalog entry with that name and origin; a bare string is looked up in
+//!   every built-in palette. Names that aren't in the catalog are an error, use
+//!   `OwnedColorName` for those.
+
+use crate::colors_helper::PaletteId;
+use crate::core::color_types::{ColorName, Entity, HexCode, Ordering, OwnedColorName, OwnedHexCode};
+use crate::core::hex::normalize_hex;
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex};
+
+static INTERNED_HEX: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Default::default);
+
+fn intern_hex(hex: String) -> &'static str {
+    let mut set = INTERNED_HEX.lock().unwrap_or_else(|e| e.into_inner());
+    match set.get(hex.as_str()) {
+        Some(s) => s,
+        None => {
+            let s: &'static str = hex.leak();
+            set.insert(s);
+            s
+        }
+    }
+}
+
+fn normalized<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    let raw = String::deserialize(d)?;
+    normalize_hex(&raw).map_err(|e| de::Error::custom(format!("`{raw}`: {e}")))
+}
+
+impl Serialize for HexCode {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for HexCode {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        normalized(d).map(|hex| HexCode::new(intern_hex(hex)))
+    }
+}
+
+impl Serialize for OwnedHexCode {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for OwnedHexCode {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        normalized(d).map(OwnedHexCode::new)
+    }
+}
+
+impl Serialize for PaletteId {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for PaletteId {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        String::deserialize(d)?.parse().map_err(de::Error::custom)
+    }
+}
+
+fn serialize_name<S: Serializer>(
+    s: S,
+    name: &str,
+    entity: Entity,
+    origin: PaletteId,
+    ordering: Ordering,
+) -> Result<S::Ok, S::Error> {
+    let mut st = s.serialize_struct("ColorName", 4)?;
+    st.serialize_field("name", name)?;
+    st.serialize_field("entity", &entity)?;
+    st.serialize_field("origin", &origin)?;
+    st.serialize_field("ordering", &ordering)?;
+    st.end()
+}
+
+// what either name type accepts: a bare name, or the serialized struct
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NameRepr {
+    Bare(String),
+    Full {
+        name: String,
+        #[serde(default)]
+        entity: Option<Entity>,
+        #[serde(default)]
+        origin: Option<PaletteId>,
+        #[serde(default)]
+        ordering: Option<Ordering>,
+    },
+}
+
+impl Serialize for ColorName {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_name(s, self.as_str(), self.entity(), self.origin(), self.ordering())
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorName {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let (name, origin) = match NameRepr::deserialize(d)? {
+            NameRepr::Bare(name) => (name, PaletteId::All),
+            NameRepr::Full { name, origin, .. } => (name, origin.unwrap_or(PaletteId::All)),
+        };
+        ColorName::resolve(&name, origin)
+            .ok_or_else(|| de::Error::custom(format!("no color named `{name}` in {origin}")))
+    }
+}
+
+impl Serialize for OwnedColorName {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_name(s, self.as_str(), self.entity(), self.origin(), self.ordering())
+    }
+}
+
+impl<'de> Deserialize<'de> for OwnedColorName {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        Ok(match NameRepr::deserialize(d)? {
+            NameRepr::Bare(name) => OwnedColorName::new(name),
+            NameRepr::Full { name, entity, origin, ordering } => OwnedColorName::new_full(
+                name,
+                entity.unwrap_or(Entity::Color),
+                origin.unwrap_or(PaletteId::All),
+                ordering.unwrap_or(Ordering::Name),
+            ),
+        })
+    }
+}
@@ -0,0 +1,433 @@
+//! Typed color spaces with conversions to and from `Rgb`.
+//!
+//! Every space converts from `Rgb` and back with `From`/`Into`. Going
+//! `Rgb -> space -> Rgb` returns the original color; values that fall outside
+//! the sRGB gamut are clamped on the way back.
+//!
+//! Scales follow the usual CSS conventions: hues in degrees (0-360),
+//! percentages as 0-100, CIELAB/LCH lightness 0-100, OKLab/OKLCH lightness 0-1,
+//! and XYZ with white at Y = 1 (D65).
+
+use crate::core::rgb::{Hsl, Rgb, rgb_to_hsl};
+use palette::chromatic_adaptation::AdaptInto;
+use palette::convert::IntoColorUnclamped;
+use palette::{IntoColor, Srgb};
+
+/// CIELAB coordinates (D65 white point)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lab {
+    pub l: f64, // lightness 0-100
+    pub a: f64, // green(-) .. red(+)
+    pub b: f64, // blue(-) .. yellow(+)
+}
+
+/// CIE LCh(ab), the cylindrical form of `Lab`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lch {
+    pub l: f64, // lightness 0-100
+    pub c: f64, // chroma 0..~150
+    pub h: f64, // hue 0-360
+}
+
+/// OKLab coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklab {
+    pub l: f64, // lightness 0-1
+    pub a: f64, // green(-) .. red(+)
+    pub b: f64, // blue(-) .. yellow(+)
+}
+
+/// OKLCH, the cylindrical form of `Oklab`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklch {
+    pub l: f64, // lightness 0-1
+    pub c: f64, // chroma 0..~0.37
+    pub h: f64, // hue 0-360
+}
+
+/// HSV / HSB representation
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsv {
+    pub h: f64, // hue 0-360
+    pub s: f64, // saturation 0-100
+    pub v: f64, // value 0-100
+}
+
+/// HWB representation
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hwb {
+    pub h: f64, // hue 0-360
+    pub w: f64, // whiteness 0-100
+    pub b: f64, // blackness 0-100
+}
+
+/// CIE XYZ (D65), white at Y = 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xyz {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// Naive device CMYK (no ICC profile)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cmyk {
+    pub c: f64, // cyan 0-100
+    pub m: f64, // magenta 0-100
+    pub y: f64, // yellow 0-100
+    pub k: f64, // black 0-100
+}
+
+impl Lab {
+    pub const fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Convert an sRGB color to CIELAB (D65)
+    pub fn from_rgb(rgb: Rgb) -> Self {
+        rgb.into()
+    }
+}
+
+impl Lch {
+    pub const fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+}
+
+impl Oklab {
+    pub const fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+}
+
+impl Oklch {
+    pub const fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+
+    /// Whether the color is displayable in sRGB without clamping
+    pub fn in_gamut(self) -> bool {
+        let (a, b) = from_polar(self.c, self.h);
+        // `into_color` would clamp and hide the overshoot
+        let c: Srgb<f64> = palette::Oklab::new(self.l, a, b).into_color_unclamped();
+        [c.red, c.green, c.blue].iter().all(|v| (-1e-6..=1.0 + 1e-6).contains(v))
+    }
+
+    /// The same lightness and hue with chroma lowered until it fits sRGB.
+    /// Clamping channels instead would shift the hue.
+    pub fn to_gamut(self) -> Self {
+        if self.in_gamut() {
+            return self;
+        }
+        let (mut lo, mut hi) = (0.0, self.c);
+        for _ in 0..24 {
+            let mid = (lo + hi) / 2.0;
+            if Oklch::new(self.l, mid, self.h).in_gamut() {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Oklch::new(self.l, lo, self.h)
+    }
+}
+
+impl Hsv {
+    pub const fn new(h: f64, s: f64, v: f64) -> Self {
+        Self { h, s, v }
+    }
+}
+
+impl Hwb {
+    pub const fn new(h: f64, w: f64, b: f64) -> Self {
+        Self { h, w, b }
+    }
+}
+
+impl Xyz {
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Cmyk {
+    pub const fn new(c: f64, m: f64, y: f64, k: f64) -> Self {
+        Self { c, m, y, k }
+    }
+}
+
+// ---- helpers ---------------------------------------------------------------
+
+fn to_srgb(rgb: Rgb) -> Srgb<f64> {
+    Srgb::new(rgb.r, rgb.g, rgb.b).into_format::<f64>()
+}
+
+fn from_srgb(c: Srgb<f64>) -> Rgb {
+    Rgb::from_unit(c.red, c.green, c.blue)
+}
+
+impl Rgb {
+    /// Build an `Rgb` from 0-1 channels, clamping and rounding to the nearest byte
+    pub fn from_unit(r: f64, g: f64, b: f64) -> Self {
+        let ch = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Rgb { r: ch(r), g: ch(g), b: ch(b) }
+    }
+
+    /// Channels as 0-1 floats
+    pub fn to_unit(self) -> (f64, f64, f64) {
+        (self.r as f64 / 255.0, self.g as f64 / 255.0, self.b as f64 / 255.0)
+    }
+}
+
+// polar <-> rectangular for the LCH-style spaces
+fn to_polar(a: f64, b: f64) -> (f64, f64) {
+    let c = a.hypot(b);
+    let h = if c < 1e-9 { 0.0 } else { b.atan2(a).to_degrees().rem_euclid(360.0) };
+    (c, h)
+}
+
+fn from_polar(c: f64, h: f64) -> (f64, f64) {
+    let (sin, cos) = h.to_radians().sin_cos();
+    (c * cos, c * sin)
+}
+
+// hue (0-360) plus chroma and min channel -> rgb, shared by HSV and HWB
+fn hue_to_rgb(h: f64, chroma: f64, min: f64) -> (f64, f64, f64) {
+    let hp = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (hp % 2.0 - 1.0).abs());
+    let (r, g, b) = match hp as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    (r + min, g + min, b + min)
+}
+
+// ---- CIELAB / LCH ----------------------------------------------------------
+
+impl From<Rgb> for Lab {
+    fn from(rgb: Rgb) -> Self {
+        let lab: palette::Lab<palette::white_point::D65, f64> = to_srgb(rgb).into_color();
+        Lab::new(lab.l, lab.a, lab.b)
+    }
+}
+
+impl From<Lab> for Rgb {
+    fn from(lab: Lab) -> Self {
+        let c: Srgb<f64> = palette::Lab::<palette::white_point::D65, f64>::new(lab.l, lab.a, lab.b).into_color();
+        from_srgb(c)
+    }
+}
+
+/// The color of a CSS `lab()`/`lch()` value. CSS Color 4 puts those on a D50
+/// white, not the D65 of [`Lab`], so the coordinates are Bradford-adapted to
+/// D65 before reaching sRGB.
+pub(crate) fn css_lab_to_rgb(lab: Lab) -> Rgb {
+    let d50: palette::Xyz<palette::white_point::D50, f64> =
+        palette::Lab::<palette::white_point::D50, f64>::new(lab.l, lab.a, lab.b).into_color();
+    let d65: palette::Xyz<palette::white_point::D65, f64> = d50.adapt_into();
+    from_srgb(d65.into_color())
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        let (c, h) = to_polar(lab.a, lab.b);
+        Lch::new(lab.l, c, h)
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        let (a, b) = from_polar(lch.c, lch.h);
+        Lab::new(lch.l, a, b)
+    }
+}
+
+impl From<Rgb> for Lch {
+    fn from(rgb: Rgb) -> Self {
+        Lab::from(rgb).into()
+    }
+}
+
+impl From<Lch> for Rgb {
+    fn from(lch: Lch) -> Self {
+        Lab::from(lch).into()
+    }
+}
+
+// ---- OKLab / OKLCH ---------------------------------------------------------
+
+impl From<Rgb> for Oklab {
+    fn from(rgb: Rgb) -> Self {
+        let ok: palette::Oklab<f64> = to_srgb(rgb).into_color();
+        Oklab::new(ok.l, ok.a, ok.b)
+    }
+}
+
+impl From<Oklab> for Rgb {
+    fn from(ok: Oklab) -> Self {
+        let c: Srgb<f64> = palette::Oklab::new(ok.l, ok.a, ok.b).into_color();
+        from_srgb(c)
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(ok: Oklab) -> Self {
+        let (c, h) = to_polar(ok.a, ok.b);
+        Oklch::new(ok.l, c, h)
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        let (a, b) = from_polar(lch.c, lch.h);
+        Oklab::new(lch.l, a, b)
+    }
+}
+
+impl From<Rgb> for Oklch {
+    fn from(rgb: Rgb) -> Self {
+        Oklab::from(rgb).into()
+    }
+}
+
+impl From<Oklch> for Rgb {
+    fn from(lch: Oklch) -> Self {
+        Oklab::from(lch).into()
+    }
+}
+
+// ---- XYZ -------------------------------------------------------------------
+
+impl From<Rgb> for Xyz {
+    fn from(rgb: Rgb) -> Self {
+        let xyz: palette::Xyz<palette::white_point::D65, f64> = to_srgb(rgb).into_color();
+        Xyz::new(xyz.x, xyz.y, xyz.z)
+    }
+}
+
+impl From<Xyz> for Rgb {
+    fn from(xyz: Xyz) -> Self {
+        let c: Srgb<f64> = palette::Xyz::<palette::white_point::D65, f64>::new(xyz.x, xyz.y, xyz.z).into_color();
+        from_srgb(c)
+    }
+}
+
+// ---- HSL / HSV / HWB -------------------------------------------------------
+
+impl From<Rgb> for Hsl {
+    fn from(rgb: Rgb) -> Self {
+        rgb_to_hsl(rgb)
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(hsl: Hsl) -> Self {
+        let s = (hsl.s as f64 / 100.0).clamp(0.0, 1.0);
+        let l = (hsl.l as f64 / 100.0).clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let (r, g, b) = hue_to_rgb(hsl.h as f64, chroma, l - chroma / 2.0);
+        Rgb::from_unit(r, g, b)
+    }
+}
+
+impl From<Rgb> for Hsv {
+    fn from(rgb: Rgb) -> Self {
+        let (r, g, b) = rgb.to_unit();
+        let max = r.max(g.max(b));
+        let min = r.min(g.min(b));
+        let delta = max - min;
+
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+
+        Hsv::new(h, s * 100.0, max * 100.0)
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(hsv: Hsv) -> Self {
+        let s = (hsv.s / 100.0).clamp(0.0, 1.0);
+        let v = (hsv.v / 100.0).clamp(0.0, 1.0);
+        let chroma = v * s;
+        let (r, g, b) = hue_to_rgb(hsv.h, chroma, v - chroma);
+        Rgb::from_unit(r, g, b)
+    }
+}
+
+impl From<Hsv> for Hwb {
+    fn from(hsv: Hsv) -> Self {
+        Hwb::new(hsv.h, (100.0 - hsv.s) * hsv.v / 100.0, 100.0 - hsv.v)
+    }
+}
+
+impl From<Hwb> for Hsv {
+    fn from(hwb: Hwb) -> Self {
+        let (mut w, mut b) = (hwb.w.max(0.0), hwb.b.max(0.0));
+        // whiteness + blackness over 100% is a gray; normalize like CSS does
+        if w + b > 100.0 {
+            let sum = w + b;
+            w = w * 100.0 / sum;
+            b = b * 100.0 / sum;
+        }
+        let v = 100.0 - b;
+        let s = if v == 0.0 { 0.0 } else { 100.0 - w * 100.0 / v };
+        Hsv::new(hwb.h, s, v)
+    }
+}
+
+impl From<Rgb> for Hwb {
+    fn from(rgb: Rgb) -> Self {
+        Hsv::from(rgb).into()
+    }
+}
+
+impl From<Hwb> for Rgb {
+    fn from(hwb: Hwb) -> Self {
+        Hsv::from(hwb).into()
+    }
+}
+
+// ---- CMYK ------------------------------------------------------------------
+
+impl From<Rgb> for Cmyk {
+    fn from(rgb: Rgb) -> Self {
+        let (r, g, b) = rgb.to_unit();
+        let k = 1.0 - r.max(g.max(b));
+        if k >= 1.0 {
+            return Cmyk::new(0.0, 0.0, 0.0, 100.0);
+        }
+        let c = (1.0 - r - k) / (1.0 - k);
+        let m = (1.0 - g - k) / (1.0 - k);
+        let y = (1.0 - b - k) / (1.0 - k);
+        Cmyk::new(c * 100.0, m * 100.0, y * 100.0, k * 100.0)
+    }
+}
+
+impl From<Cmyk> for Rgb {
+    fn from(cmyk: Cmyk) -> Self {
+        let k = (cmyk.k / 100.0).clamp(0.0, 1.0);
+        let ch = |v: f64| (1.0 - (v / 100.0).clamp(0.0, 1.0)) * (1.0 - k);
+        Rgb::from_unit(ch(cmyk.c), ch(cmyk.m), ch(cmyk.y))
+    }
+}
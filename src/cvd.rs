@@ -0,0 +1,314 @@
+//! Color vision deficiency simulation.
+//!
+//! Shows how a color looks with protanopia, deuteranopia or tritanopia (a
+//! missing L, M or S cone) and with achromatopsia (no color at all), and
+//! finds the colors of a palette that stop being told apart.
+//!
+//! Two models are available, both applied in linear sRGB:
+//! - **Brettel/Viénot**: Viénot, Brettel & Mollon (1999) projections for
+//!   protanopia and deuteranopia, Brettel, Viénot & Mollon (1997) two-plane
+//!   projection for tritanopia.
+//! - **Machado**: Machado, Oliveira & Fernandes (2009) matrices at full severity.
+//!
+//! Achromatopsia is the same in both: every color becomes the gray of its
+//! luminance.
+//!
+//! ```
+//! use colorum::cvd::{CvdModel, Deficiency, simulate};
+//! use colorum::Rgb;
+//!
+//! // red and green collapse onto one yellow-blue axis without L cones
+//! let red = simulate(Rgb { r: 255, g: 0, b: 0 }, Deficiency::Protanopia, CvdModel::BrettelVienot);
+//! assert_eq!(red.r, red.g);
+//!
+//! // grays look the same to everyone
+//! let gray = Rgb { r: 120, g: 120, b: 120 };
+//! assert_eq!(simulate(gray, Deficiency::Tritanopia, CvdModel::Machado), gray);
+//! ```
+
+use crate::colors_helper::{ColorIndex, IndexSpace};
+use crate::core::color_types::{ColorName, HexCode};
+use crate::core::distance::DistanceMetric;
+use crate::core::rgb::{Rgb, hex_to_rgb};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt;
+
+type Matrix = [[f64; 3]; 3];
+
+// Viénot 1999, linear sRGB -> linear sRGB
+const VIENOT_PROTAN: Matrix = [
+    [0.11238, 0.88762, 0.00000],
+    [0.11238, 0.88762, 0.00000],
+    [0.00401, -0.00401, 1.00000],
+];
+const VIENOT_DEUTAN: Matrix = [
+    [0.29275, 0.70725, 0.00000],
+    [0.29275, 0.70725, 0.00000],
+    [-0.02234, 0.02234, 1.00000],
+];
+
+// Brettel 1997 tritan: one projection per half-plane, picked by the side of
+// the plane through the neutral axis and the 485nm anchor
+const BRETTEL_TRITAN_1: Matrix = [
+    [1.01277, 0.13548, -0.14826],
+    [-0.01243, 0.86812, 0.14431],
+    [0.07589, 0.80500, 0.11911],
+];
+const BRETTEL_TRITAN_2: Matrix = [
+    [0.93678, 0.18979, -0.12657],
+    [0.06154, 0.81526, 0.12320],
+    [-0.37562, 1.12767, 0.24796],
+];
+const BRETTEL_TRITAN_NORMAL: [f64; 3] = [0.03901, -0.02788, -0.01113];
+
+// Machado 2009, severity 1.0
+const MACHADO_PROTAN: Matrix = [
+    [0.152286, 1.052583, -0.204868],
+    [0.114503, 0.786281, 0.099216],
+    [-0.003882, -0.048116, 1.051998],
+];
+const MACHADO_DEUTAN: Matrix = [
+    [0.367322, 0.860646, -0.227968],
+    [0.280085, 0.672501, 0.047413],
+    [-0.011820, 0.042940, 0.968881],
+];
+const MACHADO_TRITAN: Matrix = [
+    [1.255528, -0.076749, -0.178779],
+    [-0.078411, 0.930809, 0.147602],
+    [0.004733, 0.691367, 0.303900],
+];
+
+/// Kind of color vision deficiency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// No L (red) cones
+    Protanopia,
+    /// No M (green) cones
+    Deuteranopia,
+    /// No S (blue) cones
+    Tritanopia,
+    /// No cones; only lightness is seen
+    Achromatopsia,
+}
+
+impl Deficiency {
+    pub const ALL: [Deficiency; 4] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+        Deficiency::Achromatopsia,
+    ];
+}
+
+impl fmt::Display for Deficiency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Deficiency::Protanopia => "protanopia",
+            Deficiency::Deuteranopia => "deuteranopia",
+            Deficiency::Tritanopia => "tritanopia",
+            Deficiency::Achromatopsia => "achromatopsia",
+        })
+    }
+}
+
+/// Simulation model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CvdModel {
+    /// Viénot 1999 for protan/deutan, Brettel 1997 for tritan
+    #[default]
+    BrettelVienot,
+    /// Machado 2009
+    Machado,
+}
+
+impl CvdModel {
+    pub const ALL: [CvdModel; 2] = [CvdModel::BrettelVienot, CvdModel::Machado];
+}
+
+impl fmt::Display for CvdModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CvdModel::BrettelVienot => "Brettel/Viénot",
+            CvdModel::Machado => "Machado",
+        })
+    }
+}
+
+/// A deficiency and the model to simulate it with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Simulation {
+    pub deficiency: Deficiency,
+    pub model: CvdModel,
+}
+
+impl Simulation {
+    pub const fn new(deficiency: Deficiency, model: CvdModel) -> Self {
+        Self { deficiency, model }
+    }
+
+    /// `rgb` as seen with the deficiency
+    pub fn apply(self, rgb: Rgb) -> Rgb {
+        simulate(rgb, self.deficiency, self.model)
+    }
+}
+
+fn to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+fn from_linear(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+    (c * 255.0).round() as u8
+}
+
+fn mul(m: &Matrix, v: [f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+/// `rgb` as seen with `deficiency`, simulated with `model`
+pub fn simulate(rgb: Rgb, deficiency: Deficiency, model: CvdModel) -> Rgb {
+    let lin = [to_linear(rgb.r), to_linear(rgb.g), to_linear(rgb.b)];
+    let out = match (deficiency, model) {
+        (Deficiency::Achromatopsia, _) => {
+            let y = 0.2126 * lin[0] + 0.7152 * lin[1] + 0.0722 * lin[2];
+            [y; 3]
+        }
+        (Deficiency::Protanopia, CvdModel::BrettelVienot) => mul(&VIENOT_PROTAN, lin),
+        (Deficiency::Deuteranopia, CvdModel::BrettelVienot) => mul(&VIENOT_DEUTAN, lin),
+        (Deficiency::Tritanopia, CvdModel::BrettelVienot) => {
+            let n = BRETTEL_TRITAN_NORMAL;
+            let side = n[0] * lin[0] + n[1] * lin[1] + n[2] * lin[2];
+            mul(if side >= 0.0 { &BRETTEL_TRITAN_1 } else { &BRETTEL_TRITAN_2 }, lin)
+        }
+        (Deficiency::Protanopia, CvdModel::Machado) => mul(&MACHADO_PROTAN, lin),
+        (Deficiency::Deuteranopia, CvdModel::Machado) => mul(&MACHADO_DEUTAN, lin),
+        (Deficiency::Tritanopia, CvdModel::Machado) => mul(&MACHADO_TRITAN, lin),
+    };
+    Rgb { r: from_linear(out[0]), g: from_linear(out[1]), b: from_linear(out[2]) }
+}
+
+/// Settings for [`confusable_pairs`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfusionOptions {
+    pub model: CvdModel,
+    pub metric: DistanceMetric,
+    /// Colors closer than this (in `metric`'s units) count as the same
+    pub threshold: f64,
+    /// Keep only this many pairs, the hardest to tell apart; `None` keeps all.
+    /// A large palette can have hundreds of thousands.
+    pub limit: Option<usize>,
+}
+
+impl ConfusionOptions {
+    /// `metric` with a threshold on its scale: ΔE 5, 12 in RGB, 0.05 in OKLab
+    pub fn with_metric(metric: DistanceMetric) -> Self {
+        let threshold = match metric {
+            DistanceMetric::RgbEuclid => 12.0,
+            DistanceMetric::Cie76 | DistanceMetric::Cie94 | DistanceMetric::Ciede2000 => 5.0,
+            DistanceMetric::Oklab => 0.05,
+        };
+        Self { model: CvdModel::default(), metric, threshold, limit: None }
+    }
+}
+
+impl Default for ConfusionOptions {
+    fn default() -> Self {
+        Self::with_metric(DistanceMetric::default())
+    }
+}
+
+/// Two palette colors that only differ for normal vision
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfusablePair {
+    pub a: (HexCode, ColorName),
+    pub b: (HexCode, ColorName),
+    /// Distance with normal vision
+    pub before: f64,
+    /// Distance with the deficiency
+    pub after: f64,
+}
+
+// hardest to tell apart first: closest with the deficiency, then furthest apart without
+fn confusion_order(x: &ConfusablePair, y: &ConfusablePair) -> Ordering {
+    x.after.total_cmp(&y.after).then(y.before.total_cmp(&x.before))
+}
+
+// max-heap entry whose top is the pair easiest to tell apart, for keeping the top k
+struct Ranked(ConfusablePair);
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Ranked {}
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        confusion_order(&self.0, &other.0)
+    }
+}
+
+/// Pairs of `colors` that are at least `threshold` apart with normal vision
+/// but closer than that with `deficiency`, hardest to tell apart first, at
+/// most `limit` of them.
+///
+/// Only pairs that land near each other once simulated are compared: each
+/// color looks up its neighbours in a k-d tree of the simulated palette, in a
+/// radius that shrinks to the worst kept pair once `limit` are found.
+pub fn confusable_pairs(
+    colors: &[(HexCode, ColorName)],
+    deficiency: Deficiency,
+    options: &ConfusionOptions,
+) -> Vec<ConfusablePair> {
+    let metric = options.metric;
+    let limit = options.limit.unwrap_or(usize::MAX);
+    if limit == 0 {
+        return Vec::new();
+    }
+    let seen = |rgb: Rgb| simulate(rgb, deficiency, options.model);
+    let index = ColorIndex::build_mapped(colors, IndexSpace::for_metric(metric), seen);
+
+    let mut kept: BinaryHeap<Ranked> = BinaryHeap::new();
+    for (i, &a) in colors.iter().enumerate() {
+        let Some(a_rgb) = hex_to_rgb(a.0.as_str()) else {
+            continue;
+        };
+        // once `limit` pairs are kept, only closer ones can get in
+        let radius = match kept.peek() {
+            Some(worst) if kept.len() == limit => worst.0.after,
+            _ => options.threshold,
+        };
+        for (j, after) in index.within_radius(seen(a_rgb), radius, metric) {
+            // each pair once, in palette order
+            if j <= i || after >= options.threshold {
+                continue;
+            }
+            let b = colors[j];
+            let Some(b_rgb) = hex_to_rgb(b.0.as_str()) else {
+                continue;
+            };
+            let before = metric.distance(a_rgb, b_rgb);
+            if before < options.threshold {
+                continue;
+            }
+            let pair = Ranked(ConfusablePair { a, b, before, after });
+            if kept.len() < limit {
+                kept.push(pair);
+            } else if kept.peek().is_some_and(|worst| pair < *worst) {
+                kept.pop();
+                kept.push(pair);
+            }
+        }
+    }
+
+    let mut out: Vec<ConfusablePair> = kept.into_iter().map(|r| r.0).collect();
+    out.sort_by(confusion_order);
+    out
+}
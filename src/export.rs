@@ -0,0 +1,565 @@
+//! Palette files for other tools: GIMP/Inkscape `.gpl`, Adobe Swatch Exchange
+//! `.ase`, Photoshop `.aco`, and Procreate `.swatches`.
+//!
+//! Build a [`PaletteFile`] from an origin or any set of catalog entries, then
+//! write it with [`PaletteFile::to_bytes`] or [`PaletteFile::save`]. Every
+//! format can also be read back with [`PaletteFile::from_bytes`] / [`PaletteFile::load`].
+//!
+//! ```
+//! use colorum::export::{PaletteFile, PaletteFormat};
+//! use colorum::Origin;
+//!
+//! let css = PaletteFile::from_origin(Origin::Css);
+//! let gpl = css.to_bytes(PaletteFormat::Gpl).unwrap();
+//! let back = PaletteFile::from_bytes(PaletteFormat::Gpl, &gpl).unwrap();
+//! assert_eq!(back.swatches.len(), css.swatches.len());
+//! ```
+
+use crate::colors_helper::{Origin, origin_slice};
+use crate::core::color_types::{ColorName, HexCode};
+use crate::core::rgb::{Rgb, hex_to_rgb, rgb_to_hex};
+use crate::core::spaces::{Cmyk, Hsv, Lab};
+use std::fmt;
+use std::io::{Read, Write};
+use std::path::Path;
+
+/// Supported palette file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaletteFormat {
+    /// GIMP / Inkscape palette (text)
+    Gpl,
+    /// Adobe Swatch Exchange
+    Ase,
+    /// Photoshop color swatches (version 1 + 2, with names)
+    Aco,
+    /// Procreate swatches (zip with `Swatches.json`). Procreate shows at most 30
+    /// per palette, so larger sets are written as several palettes.
+    Swatches,
+}
+
+impl PaletteFormat {
+    pub const ALL: [PaletteFormat; 4] =
+        [PaletteFormat::Gpl, PaletteFormat::Ase, PaletteFormat::Aco, PaletteFormat::Swatches];
+
+    /// File extension without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            PaletteFormat::Gpl => "gpl",
+            PaletteFormat::Ase => "ase",
+            PaletteFormat::Aco => "aco",
+            PaletteFormat::Swatches => "swatches",
+        }
+    }
+
+    /// Pick a format from a file extension (case-insensitive, no dot)
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.extension().eq_ignore_ascii_case(ext))
+    }
+
+    /// Pick a format from a path's extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        Self::from_extension(path.extension()?.to_str()?)
+    }
+}
+
+impl fmt::Display for PaletteFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ".{}", self.extension())
+    }
+}
+
+/// Errors reading or writing palette files
+#[derive(Debug)]
+pub enum PaletteFileError {
+    Io(std::io::Error),
+    UnknownFormat(String),
+    Malformed(String),
+    /// More colors, or a longer name, than the format can hold
+    TooLarge(String),
+}
+
+impl fmt::Display for PaletteFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaletteFileError::Io(e) => write!(f, "{e}"),
+            PaletteFileError::UnknownFormat(ext) => write!(f, "unknown palette format `{ext}`"),
+            PaletteFileError::Malformed(why) => write!(f, "malformed palette file: {why}"),
+            PaletteFileError::TooLarge(why) => write!(f, "too large for the format: {why}"),
+        }
+    }
+}
+impl std::error::Error for PaletteFileError {}
+
+impl From<std::io::Error> for PaletteFileError {
+    fn from(e: std::io::Error) -> Self {
+        PaletteFileError::Io(e)
+    }
+}
+
+fn malformed(why: impl Into<String>) -> PaletteFileError {
+    PaletteFileError::Malformed(why.into())
+}
+
+/// One named color in a palette file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Swatch {
+    pub name: String,
+    pub rgb: Rgb,
+}
+
+impl Swatch {
+    pub fn new(name: impl Into<String>, rgb: Rgb) -> Self {
+        Self { name: name.into(), rgb }
+    }
+}
+
+/// A named list of swatches, independent of file format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteFile {
+    pub name: String,
+    pub swatches: Vec<Swatch>,
+}
+
+impl PaletteFile {
+    /// Any set of catalog entries. Entries with invalid hex are skipped.
+    pub fn from_colors(name: impl Into<String>, colors: &[(HexCode, ColorName)]) -> Self {
+        let swatches = colors
+            .iter()
+            .filter_map(|(hex, nm)| Some(Swatch::new(nm.as_str(), hex_to_rgb(hex.as_str())?)))
+            .collect();
+        Self { name: name.into(), swatches }
+    }
+
+    /// Every color of an origin, named after the origin
+    pub fn from_origin(origin: Origin) -> Self {
+        Self::from_colors(origin.to_string(), origin_slice(origin))
+    }
+
+    /// Encode in the given format. Fails when the palette exceeds the
+    /// format's limits (65,535 colors in `.aco`, 65,534-unit names in `.ase`).
+    pub fn to_bytes(&self, format: PaletteFormat) -> Result<Vec<u8>, PaletteFileError> {
+        match format {
+            PaletteFormat::Gpl => Ok(write_gpl(self).into_bytes()),
+            PaletteFormat::Ase => write_ase(self),
+            PaletteFormat::Aco => write_aco(self),
+            PaletteFormat::Swatches => Ok(write_swatches(self)),
+        }
+    }
+
+    /// Decode from the given format
+    pub fn from_bytes(format: PaletteFormat, bytes: &[u8]) -> Result<Self, PaletteFileError> {
+        match format {
+            PaletteFormat::Gpl => {
+                read_gpl(std::str::from_utf8(bytes).map_err(|_| malformed("gpl is not UTF-8"))?)
+            }
+            PaletteFormat::Ase => read_ase(bytes),
+            PaletteFormat::Aco => read_aco(bytes),
+            PaletteFormat::Swatches => read_swatches(bytes),
+        }
+    }
+
+    /// Write to `path`, choosing the format from its extension
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), PaletteFileError> {
+        let path = path.as_ref();
+        let format = format_of(path)?;
+        std::fs::write(path, self.to_bytes(format)?)?;
+        Ok(())
+    }
+
+    /// Read from `path`, choosing the format from its extension
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PaletteFileError> {
+        let path = path.as_ref();
+        let format = format_of(path)?;
+        Self::from_bytes(format, &std::fs::read(path)?)
+    }
+}
+
+fn format_of(path: &Path) -> Result<PaletteFormat, PaletteFileError> {
+    PaletteFormat::from_path(path)
+        .ok_or_else(|| PaletteFileError::UnknownFormat(path.display().to_string()))
+}
+
+// ---- GIMP .gpl -------------------------------------------------------------
+
+/// GIMP palette text: header, then one `R G B<TAB>Name` line per color
+pub fn write_gpl(palette: &PaletteFile) -> String {
+    let mut out = String::from("GIMP Palette\n");
+    out.push_str(&format!("Name: {}\n", one_line(&palette.name)));
+    out.push_str("Columns: 0\n#\n");
+    for s in &palette.swatches {
+        out.push_str(&format!(
+            "{:>3} {:>3} {:>3}\t{}\n",
+            s.rgb.r,
+            s.rgb.g,
+            s.rgb.b,
+            one_line(&s.name)
+        ));
+    }
+    out
+}
+
+fn one_line(s: &str) -> String {
+    s.replace(['\r', '\n'], " ")
+}
+
+pub fn read_gpl(text: &str) -> Result<PaletteFile, PaletteFileError> {
+    let mut lines = text.lines();
+    if lines.next().map(str::trim) != Some("GIMP Palette") {
+        return Err(malformed("missing `GIMP Palette` header"));
+    }
+    let mut name = String::new();
+    let mut swatches = Vec::new();
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(n) = line.strip_prefix("Name:") {
+            name = n.trim().to_string();
+            continue;
+        }
+        if line.starts_with("Columns:") {
+            continue;
+        }
+        // three numbers, then the name (which may contain spaces)
+        let mut rest = line;
+        let mut channel = || -> Result<u8, PaletteFileError> {
+            let (num, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            rest = tail.trim_start();
+            num.parse().map_err(|_| malformed(format!("bad color line `{line}`")))
+        };
+        let rgb = Rgb { r: channel()?, g: channel()?, b: channel()? };
+        let rest = rest.trim_end().to_string();
+        let swatch_name = if rest.is_empty() { rgb_to_hex(rgb) } else { rest };
+        swatches.push(Swatch::new(swatch_name, rgb));
+    }
+    Ok(PaletteFile { name, swatches })
+}
+
+// ---- Adobe .ase ------------------------------------------------------------
+
+const ASE_GROUP_START: u16 = 0xC001;
+const ASE_GROUP_END: u16 = 0xC002;
+const ASE_COLOR: u16 = 0x0001;
+
+fn push_utf16_name(out: &mut Vec<u8>, name: &str) -> Result<(), PaletteFileError> {
+    let units: Vec<u16> = name.encode_utf16().chain(std::iter::once(0)).collect();
+    let len = u16::try_from(units.len()).map_err(|_| {
+        PaletteFileError::TooLarge(format!("ASE names hold at most {} UTF-16 units, got {}", u16::MAX - 1, units.len() - 1))
+    })?;
+    out.extend_from_slice(&len.to_be_bytes());
+    for u in units {
+        out.extend_from_slice(&u.to_be_bytes());
+    }
+    Ok(())
+}
+
+fn push_block(out: &mut Vec<u8>, kind: u16, body: &[u8]) {
+    out.extend_from_slice(&kind.to_be_bytes());
+    out.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    out.extend_from_slice(body);
+}
+
+/// Adobe Swatch Exchange: one group named after the palette, RGB global colors
+pub fn write_ase(palette: &PaletteFile) -> Result<Vec<u8>, PaletteFileError> {
+    let mut out = Vec::new();
+    out.extend_from_slice(b"ASEF");
+    out.extend_from_slice(&1u16.to_be_bytes());
+    out.extend_from_slice(&0u16.to_be_bytes());
+    out.extend_from_slice(&(palette.swatches.len() as u32 + 2).to_be_bytes());
+
+    let mut body = Vec::new();
+    push_utf16_name(&mut body, &palette.name)?;
+    push_block(&mut out, ASE_GROUP_START, &body);
+
+    for s in &palette.swatches {
+        body.clear();
+        push_utf16_name(&mut body, &s.name)?;
+        body.extend_from_slice(b"RGB ");
+        for c in [s.rgb.r, s.rgb.g, s.rgb.b] {
+            body.extend_from_slice(&(c as f32 / 255.0).to_be_bytes());
+        }
+        body.extend_from_slice(&2u16.to_be_bytes()); // 0 global, 1 spot, 2 normal
+        push_block(&mut out, ASE_COLOR, &body);
+    }
+
+    push_block(&mut out, ASE_GROUP_END, &[]);
+    Ok(out)
+}
+
+/// Big-endian cursor over a byte slice
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], PaletteFileError> {
+        let end = self.pos.checked_add(n).filter(|&e| e <= self.bytes.len());
+        let end = end.ok_or_else(|| malformed("unexpected end of file"))?;
+        let out = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn u16(&mut self) -> Result<u16, PaletteFileError> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, PaletteFileError> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn f32(&mut self) -> Result<f32, PaletteFileError> {
+        Ok(f32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    /// `len` UTF-16BE code units, the last of which may be a terminating NUL
+    fn utf16(&mut self, len: usize) -> Result<String, PaletteFileError> {
+        let mut units = Vec::with_capacity(len);
+        for _ in 0..len {
+            units.push(self.u16()?);
+        }
+        if units.last() == Some(&0) {
+            units.pop();
+        }
+        String::from_utf16(&units).map_err(|_| malformed("bad UTF-16 name"))
+    }
+}
+
+pub fn read_ase(bytes: &[u8]) -> Result<PaletteFile, PaletteFileError> {
+    let mut r = Reader::new(bytes);
+    if r.take(4)? != b"ASEF" {
+        return Err(malformed("missing ASEF signature"));
+    }
+    r.take(4)?; // version
+    let blocks = r.u32()?;
+
+    let mut name = String::new();
+    let mut swatches = Vec::new();
+    for _ in 0..blocks {
+        let kind = r.u16()?;
+        let len = r.u32()? as usize;
+        let mut b = Reader::new(r.take(len)?);
+        match kind {
+            ASE_GROUP_START => {
+                let n = b.u16()? as usize;
+                if name.is_empty() {
+                    name = b.utf16(n)?;
+                }
+            }
+            ASE_COLOR => {
+                let n = b.u16()? as usize;
+                let swatch_name = b.utf16(n)?;
+                let model = b.take(4)?;
+                let rgb = match model {
+                    b"RGB " => Rgb::from_unit(b.f32()? as f64, b.f32()? as f64, b.f32()? as f64),
+                    b"CMYK" => {
+                        let mut c = [0.0; 4];
+                        for v in &mut c {
+                            *v = b.f32()? as f64 * 100.0;
+                        }
+                        Cmyk::new(c[0], c[1], c[2], c[3]).into()
+                    }
+                    b"LAB " => Lab::new(b.f32()? as f64 * 100.0, b.f32()? as f64, b.f32()? as f64).into(),
+                    b"Gray" => {
+                        let g = b.f32()? as f64;
+                        Rgb::from_unit(g, g, g)
+                    }
+                    other => {
+                        return Err(malformed(format!(
+                            "unknown ASE color model `{}`",
+                            String::from_utf8_lossy(other)
+                        )));
+                    }
+                };
+                let swatch_name = if swatch_name.is_empty() { rgb_to_hex(rgb) } else { swatch_name };
+                swatches.push(Swatch::new(swatch_name, rgb));
+            }
+            _ => {} // group end and unknown blocks
+        }
+    }
+    Ok(PaletteFile { name, swatches })
+}
+
+// ---- Photoshop .aco --------------------------------------------------------
+
+const ACO_RGB: u16 = 0;
+const ACO_HSB: u16 = 1;
+const ACO_CMYK: u16 = 2;
+const ACO_GRAY: u16 = 8;
+
+/// Photoshop swatches: a version 1 section (colors only) followed by a
+/// version 2 section (colors with names), as Photoshop itself writes them.
+/// `.aco` has no palette name, and holds at most 65,535 colors.
+pub fn write_aco(palette: &PaletteFile) -> Result<Vec<u8>, PaletteFileError> {
+    let count = u16::try_from(palette.swatches.len()).map_err(|_| {
+        PaletteFileError::TooLarge(format!("ACO holds at most {} colors, got {}", u16::MAX, palette.swatches.len()))
+    })?;
+    let mut out = Vec::new();
+    for version in [1u16, 2] {
+        out.extend_from_slice(&version.to_be_bytes());
+        out.extend_from_slice(&count.to_be_bytes());
+        for s in &palette.swatches {
+            out.extend_from_slice(&ACO_RGB.to_be_bytes());
+            for c in [s.rgb.r, s.rgb.g, s.rgb.b] {
+                out.extend_from_slice(&(c as u16 * 257).to_be_bytes());
+            }
+            out.extend_from_slice(&0u16.to_be_bytes());
+            if version == 2 {
+                let units: Vec<u16> = s.name.encode_utf16().chain(std::iter::once(0)).collect();
+                out.extend_from_slice(&(units.len() as u32).to_be_bytes());
+                for u in units {
+                    out.extend_from_slice(&u.to_be_bytes());
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+pub fn read_aco(bytes: &[u8]) -> Result<PaletteFile, PaletteFileError> {
+    let mut r = Reader::new(bytes);
+    let mut swatches = Vec::new();
+
+    // prefer the named version 2 section when present
+    while !r.is_done() {
+        let version = r.u16()?;
+        if version != 1 && version != 2 {
+            return Err(malformed(format!("unknown ACO version {version}")));
+        }
+        let count = r.u16()? as usize;
+        let mut section = Vec::with_capacity(count);
+        for _ in 0..count {
+            let space = r.u16()?;
+            let w = [r.u16()?, r.u16()?, r.u16()?, r.u16()?];
+            let name = if version == 2 {
+                let len = r.u32()? as usize;
+                r.utf16(len)?
+            } else {
+                String::new()
+            };
+            let unit = |v: u16| v as f64 / 65535.0;
+            let rgb = match space {
+                ACO_RGB => Rgb::from_unit(unit(w[0]), unit(w[1]), unit(w[2])),
+                ACO_HSB => Hsv::new(unit(w[0]) * 360.0, unit(w[1]) * 100.0, unit(w[2]) * 100.0).into(),
+                // 0 = 100% ink
+                ACO_CMYK => Cmyk::new(
+                    100.0 - unit(w[0]) * 100.0,
+                    100.0 - unit(w[1]) * 100.0,
+                    100.0 - unit(w[2]) * 100.0,
+                    100.0 - unit(w[3]) * 100.0,
+                )
+                .into(),
+                ACO_GRAY => {
+                    let g = 1.0 - w[0] as f64 / 10000.0;
+                    Rgb::from_unit(g, g, g)
+                }
+                other => return Err(malformed(format!("unsupported ACO color space {other}"))),
+            };
+            let name = if name.is_empty() { rgb_to_hex(rgb) } else { name };
+            section.push(Swatch::new(name, rgb));
+        }
+        if version == 2 || swatches.is_empty() {
+            swatches = section;
+        }
+    }
+    Ok(PaletteFile { name: String::new(), swatches })
+}
+
+// ---- Procreate .swatches ---------------------------------------------------
+
+const SWATCHES_JSON: &str = "Swatches.json";
+
+/// Most swatches Procreate shows in one palette
+pub const SWATCHES_PER_PALETTE: usize = 30;
+
+/// Procreate swatches: a zip holding `Swatches.json` with HSB colors.
+/// Procreate does not store swatch names, so only the palette name survives.
+/// Past [`SWATCHES_PER_PALETTE`] colors the set is split into several
+/// palettes, named "Name", "Name 2", "Name 3", …
+pub fn write_swatches(palette: &PaletteFile) -> Vec<u8> {
+    let palettes: Vec<serde_json::Value> = palette
+        .swatches
+        .chunks(SWATCHES_PER_PALETTE)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let swatches: Vec<serde_json::Value> = chunk
+                .iter()
+                .map(|s| {
+                    let hsv = Hsv::from(s.rgb);
+                    serde_json::json!({
+                        "hue": hsv.h / 360.0,
+                        "saturation": hsv.s / 100.0,
+                        "brightness": hsv.v / 100.0,
+                        "alpha": 1,
+                        "colorSpace": 0,
+                    })
+                })
+                .collect();
+            let name = if i == 0 { palette.name.clone() } else { format!("{} {}", palette.name, i + 1) };
+            serde_json::json!({ "name": name, "swatches": swatches })
+        })
+        .collect();
+    // an empty set is still one (empty) palette
+    let json = if palettes.is_empty() {
+        serde_json::json!([{ "name": palette.name, "swatches": [] }])
+    } else {
+        serde_json::Value::Array(palettes)
+    };
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    // writing into memory cannot fail
+    zip.start_file(SWATCHES_JSON, options).expect("in-memory zip");
+    zip.write_all(json.to_string().as_bytes()).expect("in-memory zip");
+    zip.finish().expect("in-memory zip").into_inner()
+}
+
+pub fn read_swatches(bytes: &[u8]) -> Result<PaletteFile, PaletteFileError> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes))
+        .map_err(|e| malformed(format!("not a zip: {e}")))?;
+    let mut text = String::new();
+    archive
+        .by_name(SWATCHES_JSON)
+        .map_err(|_| malformed("missing Swatches.json"))?
+        .read_to_string(&mut text)?;
+
+    let json: serde_json::Value =
+        serde_json::from_str(&text).map_err(|e| malformed(format!("bad JSON: {e}")))?;
+    // older exports hold a single object, newer ones an array of palettes;
+    // the palettes of a split set are joined back together under the first name
+    let palettes = match &json {
+        serde_json::Value::Array(items) if items.is_empty() => return Err(malformed("no palettes")),
+        serde_json::Value::Array(items) => items.as_slice(),
+        other => std::slice::from_ref(other),
+    };
+
+    let name = palettes[0]["name"].as_str().unwrap_or_default().to_string();
+    let mut swatches = Vec::new();
+    for palette in palettes {
+        let entries = palette["swatches"]
+            .as_array()
+            .ok_or_else(|| malformed("missing swatches array"))?;
+        for entry in entries.iter().filter(|e| !e.is_null()) {
+            let field = |k: &str| {
+                entry[k]
+                    .as_f64()
+                    .ok_or_else(|| malformed(format!("swatch missing `{k}`")))
+            };
+            let rgb: Rgb =
+                Hsv::new(field("hue")? * 360.0, field("saturation")? * 100.0, field("brightness")? * 100.0).into();
+            swatches.push(Swatch::new(rgb_to_hex(rgb), rgb));
+        }
+    }
+    Ok(PaletteFile { name, swatches })
+}
@@ -0,0 +1,323 @@
+//! Dominant colors of an image.
+//!
+//! Loads a PNG or JPEG (needs the `extract` feature, on by default), reduces
+//! it to `count` colors with k-means in OKLab or with median-cut, and reports
+//! each color's share of the pixels and its nearest catalog name.
+//!
+//! ```
+//! use colorum::extract::{ExtractMethod, ExtractOptions, extract_colors};
+//! use colorum::{Origin, Rgb};
+//!
+//! // three quarters red, one quarter blue
+//! let mut pixels = vec![Rgb { r: 255, g: 0, b: 0 }; 300];
+//! pixels.extend(vec![Rgb { r: 0, g: 0, b: 255 }; 100]);
+//!
+//! let options = ExtractOptions { count: 2, origin: Origin::Css, ..Default::default() };
+//! for method in [ExtractMethod::KMeans, ExtractMethod::MedianCut] {
+//!     let colors = extract_colors(&pixels, &ExtractOptions { method, ..options });
+//!     assert_eq!(colors[0].rgb, Rgb { r: 255, g: 0, b: 0 });
+//!     assert_eq!(colors[0].share, 0.75);
+//!     assert_eq!(colors[0].nearest.unwrap().1.as_str(), "red");
+//! }
+//! ```
+
+use crate::colors_helper::{Origin, nearest_colors};
+use crate::core::color_types::{ColorName, HexCode};
+use crate::core::distance::DistanceMetric;
+use crate::core::rgb::Rgb;
+use crate::core::spaces::Oklab;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+// Lloyd iterations are cut off here even if the centroids still move
+const KMEANS_MAX_ITERATIONS: usize = 24;
+// ...or as soon as no centroid moves further than this (OKLab units)
+const KMEANS_EPSILON: f64 = 1e-4;
+// Pixels with less alpha than this are background, not color
+const MIN_ALPHA: u8 = 128;
+
+/// How to reduce an image to a few colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ExtractMethod {
+    /// Lloyd's k-means in OKLab, seeded with the median-cut colors
+    #[default]
+    KMeans,
+    /// Recursively split the RGB box with the widest channel at its median
+    MedianCut,
+}
+
+impl ExtractMethod {
+    pub const ALL: [ExtractMethod; 2] = [ExtractMethod::KMeans, ExtractMethod::MedianCut];
+}
+
+impl fmt::Display for ExtractMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ExtractMethod::KMeans => "k-means",
+            ExtractMethod::MedianCut => "median cut",
+        })
+    }
+}
+
+/// What to extract and how to name it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtractOptions {
+    /// At most this many colors; fewer when the image has fewer
+    pub count: usize,
+    pub method: ExtractMethod,
+    /// Palette the nearest names come from
+    pub origin: Origin,
+    pub metric: DistanceMetric,
+    /// Larger images are sampled down to about this many pixels
+    pub max_pixels: usize,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        Self {
+            count: 6,
+            method: ExtractMethod::default(),
+            origin: Origin::All,
+            metric: DistanceMetric::default(),
+            max_pixels: 250_000,
+        }
+    }
+}
+
+/// One dominant color
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtractedColor {
+    pub rgb: Rgb,
+    /// Pixels this color stands for
+    pub pixels: usize,
+    /// `pixels` as a fraction of every opaque pixel, 0-1
+    pub share: f64,
+    /// Closest catalog entry and its distance under `ExtractOptions::metric`
+    pub nearest: Option<(HexCode, ColorName, f64)>,
+}
+
+/// An image that could not be read
+#[derive(Debug)]
+pub enum ExtractError {
+    Io(std::io::Error),
+    /// Not a PNG or JPEG
+    UnsupportedFormat(String),
+    Decode(String),
+    /// Every pixel is transparent
+    Empty,
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtractError::Io(e) => write!(f, "{e}"),
+            ExtractError::UnsupportedFormat(what) => write!(f, "unsupported image format `{what}` (PNG or JPEG only)"),
+            ExtractError::Decode(why) => write!(f, "could not decode image: {why}"),
+            ExtractError::Empty => write!(f, "image has no opaque pixels"),
+        }
+    }
+}
+impl std::error::Error for ExtractError {}
+
+impl From<std::io::Error> for ExtractError {
+    fn from(e: std::io::Error) -> Self {
+        ExtractError::Io(e)
+    }
+}
+
+/// Opaque pixels of a PNG or JPEG held in memory, row by row
+pub fn decode_pixels(bytes: &[u8]) -> Result<Vec<Rgb>, ExtractError> {
+    let format = image::guess_format(bytes).map_err(|e| ExtractError::Decode(e.to_string()))?;
+    if !matches!(format, image::ImageFormat::Png | image::ImageFormat::Jpeg) {
+        return Err(ExtractError::UnsupportedFormat(format!("{format:?}").to_lowercase()));
+    }
+    let img = image::load_from_memory_with_format(bytes, format)
+        .map_err(|e| ExtractError::Decode(e.to_string()))?
+        .into_rgba8();
+    let pixels: Vec<Rgb> = img
+        .pixels()
+        .filter(|p| p.0[3] >= MIN_ALPHA)
+        .map(|p| Rgb { r: p.0[0], g: p.0[1], b: p.0[2] })
+        .collect();
+    if pixels.is_empty() {
+        return Err(ExtractError::Empty);
+    }
+    Ok(pixels)
+}
+
+/// Opaque pixels of a PNG or JPEG file
+pub fn load_pixels(path: impl AsRef<Path>) -> Result<Vec<Rgb>, ExtractError> {
+    decode_pixels(&std::fs::read(path)?)
+}
+
+/// The dominant colors of a PNG or JPEG file, largest share first
+pub fn extract_from_file(path: impl AsRef<Path>, options: &ExtractOptions) -> Result<Vec<ExtractedColor>, ExtractError> {
+    Ok(extract_colors(&load_pixels(path)?, options))
+}
+
+/// The dominant colors of `pixels`, largest share first
+pub fn extract_colors(pixels: &[Rgb], options: &ExtractOptions) -> Vec<ExtractedColor> {
+    let colors = histogram(pixels, options.max_pixels);
+    let clusters = match options.method {
+        ExtractMethod::KMeans => kmeans_oklab(&colors, options.count),
+        ExtractMethod::MedianCut => median_cut(&colors, options.count),
+    };
+    let total: usize = clusters.iter().map(|c| c.1).sum();
+    let mut out: Vec<ExtractedColor> = clusters
+        .into_iter()
+        .map(|(rgb, pixels)| ExtractedColor {
+            rgb,
+            pixels,
+            share: pixels as f64 / total.max(1) as f64,
+            nearest: nearest_colors(rgb, 1, options.origin, options.metric).into_iter().next(),
+        })
+        .collect();
+    out.sort_by(|a, b| b.pixels.cmp(&a.pixels).then_with(|| pack(a.rgb).cmp(&pack(b.rgb))));
+    out
+}
+
+fn pack(c: Rgb) -> u32 {
+    u32::from(c.r) << 16 | u32::from(c.g) << 8 | u32::from(c.b)
+}
+
+fn unpack(v: u32) -> Rgb {
+    Rgb { r: (v >> 16) as u8, g: (v >> 8) as u8, b: v as u8 }
+}
+
+// Distinct colors and how many (sampled) pixels have each, in a fixed order.
+// Sampling takes every n-th pixel and scales the counts back up.
+fn histogram(pixels: &[Rgb], max_pixels: usize) -> Vec<(Rgb, usize)> {
+    let step = pixels.len().div_ceil(max_pixels.max(1)).max(1);
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    for &p in pixels.iter().step_by(step) {
+        *counts.entry(pack(p)).or_default() += step;
+    }
+    let mut out: Vec<(u32, usize)> = counts.into_iter().collect();
+    out.sort_unstable();
+    out.into_iter().map(|(c, n)| (unpack(c), n)).collect()
+}
+
+// Pixel-weighted mean of some colors
+fn mean_rgb(colors: &[(Rgb, usize)]) -> Rgb {
+    let (mut r, mut g, mut b, mut n) = (0.0, 0.0, 0.0, 0.0);
+    for &(c, w) in colors {
+        let w = w as f64;
+        r += c.r as f64 * w;
+        g += c.g as f64 * w;
+        b += c.b as f64 * w;
+        n += w;
+    }
+    Rgb::from_unit(r / n / 255.0, g / n / 255.0, b / n / 255.0)
+}
+
+/// Median-cut quantization of pixel-weighted colors into at most `k` (color, pixels) pairs
+pub fn median_cut(colors: &[(Rgb, usize)], k: usize) -> Vec<(Rgb, usize)> {
+    if k == 0 {
+        return Vec::new();
+    }
+    let channel = |c: Rgb, i: usize| [c.r, c.g, c.b][i];
+    // widest channel and its range
+    let widest = |bx: &[(Rgb, usize)]| -> (usize, u8) {
+        (0..3)
+            .map(|i| {
+                let lo = bx.iter().map(|&(c, _)| channel(c, i)).min().unwrap_or(0);
+                let hi = bx.iter().map(|&(c, _)| channel(c, i)).max().unwrap_or(0);
+                (i, hi - lo)
+            })
+            .max_by_key(|&(i, range)| (range, std::cmp::Reverse(i)))
+            .unwrap_or((0, 0))
+    };
+
+    let mut boxes: Vec<Vec<(Rgb, usize)>> = vec![colors.to_vec()];
+    boxes.retain(|b| !b.is_empty());
+    while boxes.len() < k {
+        // split the box that covers the most pixels times its widest range
+        let Some((at, (axis, _))) = boxes
+            .iter()
+            .map(|bx| widest(bx))
+            .enumerate()
+            .filter(|(i, (_, range))| *range > 0 && boxes[*i].len() > 1)
+            .max_by_key(|(i, (_, range))| {
+                let pixels: usize = boxes[*i].iter().map(|c| c.1).sum();
+                (pixels * *range as usize, std::cmp::Reverse(*i))
+            })
+        else {
+            break;
+        };
+        let mut bx = boxes.swap_remove(at);
+        bx.sort_by_key(|&(c, _)| (channel(c, axis), pack(c)));
+        let half: usize = bx.iter().map(|c| c.1).sum::<usize>() / 2;
+        let mut seen = 0;
+        let mut cut = bx
+            .iter()
+            .position(|&(_, w)| {
+                seen += w;
+                seen > half
+            })
+            .unwrap_or(0);
+        // both halves keep at least one color
+        cut = cut.clamp(1, bx.len() - 1);
+        let upper = bx.split_off(cut);
+        boxes.push(bx);
+        boxes.push(upper);
+    }
+
+    boxes
+        .iter()
+        .map(|bx| (mean_rgb(bx), bx.iter().map(|c| c.1).sum()))
+        .collect()
+}
+
+fn oklab_dist2(a: &Oklab, b: &Oklab) -> f64 {
+    (a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)
+}
+
+/// K-means in OKLab over pixel-weighted colors, seeded with [`median_cut`].
+/// Returns at most `k` (color, pixels) pairs; clusters that end up empty are dropped.
+pub fn kmeans_oklab(colors: &[(Rgb, usize)], k: usize) -> Vec<(Rgb, usize)> {
+    if k == 0 {
+        return Vec::new();
+    }
+    let points: Vec<(Oklab, f64)> = colors.iter().map(|&(c, w)| (Oklab::from(c), w as f64)).collect();
+    let mut centroids: Vec<Oklab> = median_cut(colors, k).into_iter().map(|(c, _)| Oklab::from(c)).collect();
+    let mut assignment = vec![0usize; points.len()];
+
+    for _ in 0..KMEANS_MAX_ITERATIONS {
+        for (slot, (p, _)) in assignment.iter_mut().zip(&points) {
+            *slot = (0..centroids.len())
+                .min_by(|&i, &j| oklab_dist2(p, &centroids[i]).total_cmp(&oklab_dist2(p, &centroids[j])))
+                .unwrap_or(0);
+        }
+        let mut sums = vec![(0.0, 0.0, 0.0, 0.0); centroids.len()];
+        for (&i, (p, w)) in assignment.iter().zip(&points) {
+            let s = &mut sums[i];
+            s.0 += p.l * w;
+            s.1 += p.a * w;
+            s.2 += p.b * w;
+            s.3 += w;
+        }
+        let mut moved: f64 = 0.0;
+        for (c, s) in centroids.iter_mut().zip(&sums) {
+            if s.3 > 0.0 {
+                let next = Oklab::new(s.0 / s.3, s.1 / s.3, s.2 / s.3);
+                moved = moved.max(oklab_dist2(c, &next).sqrt());
+                *c = next;
+            }
+        }
+        if moved < KMEANS_EPSILON {
+            break;
+        }
+    }
+
+    let mut pixels = vec![0usize; centroids.len()];
+    for (&i, &(_, w)) in assignment.iter().zip(colors) {
+        pixels[i] += w;
+    }
+    centroids
+        .into_iter()
+        .zip(pixels)
+        .filter(|&(_, n)| n > 0)
+        .map(|(c, n)| (Rgb::from(c), n))
+        .collect()
+}
@@ -0,0 +1,270 @@
+//! Blends and gradients between colors.
+//!
+//! [`interpolate`] mixes two colors and [`gradient`] samples evenly across
+//! any number of stops. Both work in sRGB, linear light, OKLab, OKLCH or HSL;
+//! the polar spaces take a CSS-style [`HuePath`] for which way round the hue
+//! wheel to go. [`with_nearest`] names the results and
+//! [`css_linear_gradient`] writes them out for a stylesheet.
+//!
+//! ```
+//! use colorum::gradient::{BlendSpace, HuePath, css_linear_gradient, gradient, interpolate};
+//! use colorum::Rgb;
+//!
+//! let red = Rgb { r: 255, g: 0, b: 0 };
+//! let blue = Rgb { r: 0, g: 0, b: 255 };
+//! assert_eq!(interpolate(red, blue, 0.5, BlendSpace::Srgb), Rgb { r: 128, g: 0, b: 128 });
+//! assert_eq!(interpolate(red, blue, 0.0, BlendSpace::Oklab), red);
+//!
+//! // the long way round from red to blue passes through green
+//! let steps = gradient(&[red, blue], 5, BlendSpace::Hsl(HuePath::Longer));
+//! assert_eq!(steps[2], Rgb { r: 0, g: 255, b: 0 });
+//!
+//! assert_eq!(
+//!     css_linear_gradient(&[red, blue], 90.0).unwrap(),
+//!     "linear-gradient(90deg, #FF0000 0%, #0000FF 100%)"
+//! );
+//! ```
+
+use crate::colors_helper::{Origin, nearest_colors};
+use crate::core::color_types::{ColorName, HexCode};
+use crate::core::distance::DistanceMetric;
+use crate::core::rgb::{Hsl, Rgb, rgb_to_hex};
+use crate::core::spaces::{Oklab, Oklch};
+use palette::Srgb;
+use std::fmt;
+
+/// Which way round the hue wheel a polar blend goes, as in CSS Color 4
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HuePath {
+    /// The shorter arc (at most 180°)
+    #[default]
+    Shorter,
+    /// The longer arc
+    Longer,
+    /// Always counting up
+    Increasing,
+    /// Always counting down
+    Decreasing,
+}
+
+impl HuePath {
+    pub const ALL: [HuePath; 4] = [HuePath::Shorter, HuePath::Longer, HuePath::Increasing, HuePath::Decreasing];
+
+    // both hues, adjusted so a straight lerp between them follows this path
+    fn arc(self, h1: f64, h2: f64) -> (f64, f64) {
+        let (h1, h2) = (h1.rem_euclid(360.0), h2.rem_euclid(360.0));
+        let d = h2 - h1;
+        match self {
+            HuePath::Shorter if d > 180.0 => (h1 + 360.0, h2),
+            HuePath::Shorter if d < -180.0 => (h1, h2 + 360.0),
+            HuePath::Longer if 0.0 < d && d < 180.0 => (h1 + 360.0, h2),
+            HuePath::Longer if -180.0 < d && d <= 0.0 => (h1, h2 + 360.0),
+            HuePath::Increasing if d < 0.0 => (h1, h2 + 360.0),
+            HuePath::Decreasing if d > 0.0 => (h1 + 360.0, h2),
+            _ => (h1, h2),
+        }
+    }
+}
+
+impl fmt::Display for HuePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HuePath::Shorter => "shorter hue",
+            HuePath::Longer => "longer hue",
+            HuePath::Increasing => "increasing hue",
+            HuePath::Decreasing => "decreasing hue",
+        })
+    }
+}
+
+/// Space a blend is computed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BlendSpace {
+    /// Gamma-encoded sRGB channels, like plain CSS gradients
+    Srgb,
+    /// Linear-light sRGB; physically mixing the lights
+    LinearRgb,
+    /// OKLab; even perceived steps, no hue drift
+    #[default]
+    Oklab,
+    /// OKLCH; keeps chroma up through the middle
+    Oklch(HuePath),
+    /// HSL, the way most color pickers blend
+    Hsl(HuePath),
+}
+
+impl BlendSpace {
+    /// Every space, polar ones with the shorter hue path
+    pub const ALL: [BlendSpace; 5] = [
+        BlendSpace::Srgb,
+        BlendSpace::LinearRgb,
+        BlendSpace::Oklab,
+        BlendSpace::Oklch(HuePath::Shorter),
+        BlendSpace::Hsl(HuePath::Shorter),
+    ];
+
+    /// The hue path of a polar space
+    pub fn hue_path(self) -> Option<HuePath> {
+        match self {
+            BlendSpace::Oklch(path) | BlendSpace::Hsl(path) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// The same space with another hue path; rectangular spaces are unchanged
+    pub fn with_hue_path(self, path: HuePath) -> Self {
+        match self {
+            BlendSpace::Oklch(_) => BlendSpace::Oklch(path),
+            BlendSpace::Hsl(_) => BlendSpace::Hsl(path),
+            other => other,
+        }
+    }
+
+    /// The CSS `<color-interpolation-method>`, e.g. `in oklch longer hue`
+    pub fn css(self) -> String {
+        match self {
+            BlendSpace::Srgb => "in srgb".into(),
+            BlendSpace::LinearRgb => "in srgb-linear".into(),
+            BlendSpace::Oklab => "in oklab".into(),
+            BlendSpace::Oklch(HuePath::Shorter) => "in oklch".into(),
+            BlendSpace::Hsl(HuePath::Shorter) => "in hsl".into(),
+            BlendSpace::Oklch(path) => format!("in oklch {path}"),
+            BlendSpace::Hsl(path) => format!("in hsl {path}"),
+        }
+    }
+}
+
+impl fmt::Display for BlendSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BlendSpace::Srgb => "sRGB",
+            BlendSpace::LinearRgb => "linear RGB",
+            BlendSpace::Oklab => "OKLab",
+            BlendSpace::Oklch(_) => "OKLCH",
+            BlendSpace::Hsl(_) => "HSL",
+        })
+    }
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+// hue of a polar blend; a gray has no hue of its own and takes the other's
+fn lerp_hue(path: HuePath, (h1, gray1): (f64, bool), (h2, gray2): (f64, bool), t: f64) -> f64 {
+    let (h1, h2) = match (gray1, gray2) {
+        (true, false) => (h2, h2),
+        (false, true) => (h1, h1),
+        _ => (h1, h2),
+    };
+    let (h1, h2) = path.arc(h1, h2);
+    lerp(h1, h2, t).rem_euclid(360.0)
+}
+
+/// The color `t` of the way from `a` to `b` (0 = `a`, 1 = `b`) in `space`.
+/// `t` is clamped to 0..=1.
+pub fn interpolate(a: Rgb, b: Rgb, t: f64, space: BlendSpace) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    match space {
+        BlendSpace::Srgb => {
+            let ch = |x: u8, y: u8| lerp(x as f64, y as f64, t).round() as u8;
+            Rgb { r: ch(a.r, b.r), g: ch(a.g, b.g), b: ch(a.b, b.b) }
+        }
+        BlendSpace::LinearRgb => {
+            let lin = |c: Rgb| Srgb::new(c.r, c.g, c.b).into_format::<f64>().into_linear();
+            let (x, y) = (lin(a), lin(b));
+            let mixed = Srgb::from_linear(palette::LinSrgb::new(
+                lerp(x.red, y.red, t),
+                lerp(x.green, y.green, t),
+                lerp(x.blue, y.blue, t),
+            ));
+            Rgb::from_unit(mixed.red, mixed.green, mixed.blue)
+        }
+        BlendSpace::Oklab => {
+            let (x, y) = (Oklab::from(a), Oklab::from(b));
+            Oklab::new(lerp(x.l, y.l, t), lerp(x.a, y.a, t), lerp(x.b, y.b, t)).into()
+        }
+        BlendSpace::Oklch(path) => {
+            let (x, y) = (Oklch::from(a), Oklch::from(b));
+            let h = lerp_hue(path, (x.h, x.c < 1e-4), (y.h, y.c < 1e-4), t);
+            Oklch::new(lerp(x.l, y.l, t), lerp(x.c, y.c, t), h).to_gamut().into()
+        }
+        BlendSpace::Hsl(path) => {
+            let (x, y) = (Hsl::from(a), Hsl::from(b));
+            let h = lerp_hue(path, (x.h as f64, x.s < 1e-3), (y.h as f64, y.s < 1e-3), t);
+            let t = t as f32;
+            Hsl { h: h as f32, s: x.s + (y.s - x.s) * t, l: x.l + (y.l - x.l) * t }.into()
+        }
+    }
+}
+
+/// `n` colors evenly spread over `stops`, first and last stop included.
+/// The stops themselves are spaced evenly too, so with three stops the middle
+/// one lands halfway. Empty when `stops` is empty or `n` is 0.
+pub fn gradient(stops: &[Rgb], n: usize, space: BlendSpace) -> Vec<Rgb> {
+    match (stops, n) {
+        ([], _) | (_, 0) => Vec::new(),
+        ([only], _) => vec![*only; n],
+        (_, 1) => vec![stops[0]],
+        _ => {
+            let segments = (stops.len() - 1) as f64;
+            (0..n)
+                .map(|i| {
+                    let pos = i as f64 / (n - 1) as f64 * segments;
+                    // the last sample falls exactly on the last stop
+                    let seg = (pos.floor() as usize).min(stops.len() - 2);
+                    interpolate(stops[seg], stops[seg + 1], pos - seg as f64, space)
+                })
+                .collect()
+        }
+    }
+}
+
+/// A blended color and the catalog entry closest to it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NamedColor {
+    pub rgb: Rgb,
+    pub nearest: Option<(HexCode, ColorName, f64)>,
+}
+
+/// Each of `colors` with its nearest name in `origin`
+pub fn with_nearest(colors: &[Rgb], origin: Origin, metric: DistanceMetric) -> Vec<NamedColor> {
+    colors
+        .iter()
+        .map(|&rgb| NamedColor { rgb, nearest: nearest_colors(rgb, 1, origin, metric).into_iter().next() })
+        .collect()
+}
+
+// "#RRGGBB 0%, #RRGGBB 50%, ..." with positions rounded to hundredths. CSS
+// needs two stops, so a lone color is given twice; None when there are none.
+fn css_stops(colors: &[Rgb]) -> Option<String> {
+    let colors = match colors {
+        [] => return None,
+        [only] => &[*only, *only][..],
+        many => many,
+    };
+    let last = (colors.len() - 1) as f64;
+    let stops: Vec<String> = colors
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| format!("{} {}%", rgb_to_hex(c), (i as f64 / last * 10_000.0).round() / 100.0))
+        .collect();
+    Some(stops.join(", "))
+}
+
+/// A CSS `linear-gradient()` through `colors` at evenly spaced positions.
+/// Browsers blend these in sRGB, so sample with [`gradient`] first to bake
+/// another space in: the more samples, the closer the match.
+///
+/// A single color gives a solid fill (the color at 0% and 100%); no colors
+/// give `None`, as there is no valid gradient to write.
+pub fn css_linear_gradient(colors: &[Rgb], angle_deg: f64) -> Option<String> {
+    Some(format!("linear-gradient({angle_deg}deg, {})", css_stops(colors)?))
+}
+
+/// A CSS Color 4 `linear-gradient()` that asks the browser to blend `stops`
+/// in `space` itself, e.g. `linear-gradient(90deg in oklch longer hue, ...)`.
+/// Fewer than two stops are handled as in [`css_linear_gradient`].
+pub fn css_linear_gradient_in(stops: &[Rgb], angle_deg: f64, space: BlendSpace) -> Option<String> {
+    Some(format!("linear-gradient({angle_deg}deg {}, {})", space.css(), css_stops(stops)?))
+}
@@ -0,0 +1,159 @@
+//! Harmonious color sets.
+//!
+//! Rotates a base color's hue by the angles of a classic scheme
+//! (complementary, triadic, analogous, split-complementary, tetradic) in
+//! OKLCH or HSL, and snaps every result to its nearest named color.
+//!
+//! ```
+//! use colorum::harmony::{HarmonyOptions, HarmonyScheme, HarmonySpace, harmony};
+//! use colorum::{Origin, Rgb};
+//!
+//! let options = HarmonyOptions { space: HarmonySpace::Hsl, origin: Origin::Css, ..Default::default() };
+//! let set = harmony(Rgb { r: 255, g: 0, b: 0 }, HarmonyScheme::Triadic, &options);
+//!
+//! let rgbs: Vec<Rgb> = set.iter().map(|c| c.rgb).collect();
+//! assert_eq!(rgbs, [Rgb { r: 255, g: 0, b: 0 }, Rgb { r: 0, g: 255, b: 0 }, Rgb { r: 0, g: 0, b: 255 }]);
+//!
+//! let names: Vec<&str> = set.iter().map(|c| c.nearest.unwrap().1.as_str()).collect();
+//! assert_eq!(names, ["red", "lime", "blue"]);
+//! ```
+
+use crate::colors_helper::{Origin, nearest_colors};
+use crate::core::color_types::{ColorName, HexCode};
+use crate::core::distance::DistanceMetric;
+use crate::core::rgb::{Hsl, Rgb, hex_to_rgb};
+use crate::core::spaces::Oklch;
+use std::fmt;
+
+/// Which hues go together
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HarmonyScheme {
+    /// The opposite hue
+    #[default]
+    Complementary,
+    /// Three hues 120° apart
+    Triadic,
+    /// The neighbours 30° either side
+    Analogous,
+    /// The two hues 30° either side of the complement
+    SplitComplementary,
+    /// Four hues 90° apart
+    Tetradic,
+}
+
+impl HarmonyScheme {
+    pub const ALL: [HarmonyScheme; 5] = [
+        HarmonyScheme::Complementary,
+        HarmonyScheme::Triadic,
+        HarmonyScheme::Analogous,
+        HarmonyScheme::SplitComplementary,
+        HarmonyScheme::Tetradic,
+    ];
+
+    /// Hue offsets in degrees, starting with the base color's 0
+    pub fn offsets(self) -> &'static [f64] {
+        match self {
+            HarmonyScheme::Complementary => &[0.0, 180.0],
+            HarmonyScheme::Triadic => &[0.0, 120.0, 240.0],
+            HarmonyScheme::Analogous => &[0.0, -30.0, 30.0],
+            HarmonyScheme::SplitComplementary => &[0.0, 150.0, 210.0],
+            HarmonyScheme::Tetradic => &[0.0, 90.0, 180.0, 270.0],
+        }
+    }
+}
+
+impl fmt::Display for HarmonyScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HarmonyScheme::Complementary => "complementary",
+            HarmonyScheme::Triadic => "triadic",
+            HarmonyScheme::Analogous => "analogous",
+            HarmonyScheme::SplitComplementary => "split complementary",
+            HarmonyScheme::Tetradic => "tetradic",
+        })
+    }
+}
+
+/// Where hues are rotated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HarmonySpace {
+    /// Keeps perceived lightness; chroma is lowered when a hue can't hold it in sRGB
+    #[default]
+    Oklch,
+    /// Keeps saturation and lightness, the way most color pickers do it
+    Hsl,
+}
+
+impl HarmonySpace {
+    pub const ALL: [HarmonySpace; 2] = [HarmonySpace::Oklch, HarmonySpace::Hsl];
+}
+
+impl fmt::Display for HarmonySpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HarmonySpace::Oklch => "OKLCH",
+            HarmonySpace::Hsl => "HSL",
+        })
+    }
+}
+
+/// Settings for [`harmony`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HarmonyOptions {
+    pub space: HarmonySpace,
+    /// Catalog the colors are snapped to
+    pub origin: Origin,
+    pub metric: DistanceMetric,
+}
+
+impl Default for HarmonyOptions {
+    fn default() -> Self {
+        Self { space: HarmonySpace::default(), origin: Origin::All, metric: DistanceMetric::default() }
+    }
+}
+
+/// One color of a harmony
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HarmonyColor {
+    /// Hue offset from the base color, in degrees
+    pub offset: f64,
+    /// The rotated color
+    pub rgb: Rgb,
+    /// Closest catalog entry and its distance, if `origin` has any colors
+    pub nearest: Option<(HexCode, ColorName, f64)>,
+}
+
+impl HarmonyColor {
+    /// The named color this one snaps to, or the rotated color when there is none
+    pub fn snapped(&self) -> Rgb {
+        self.nearest.and_then(|(hex, _, _)| hex_to_rgb(hex.as_str())).unwrap_or(self.rgb)
+    }
+}
+
+/// `rgb` with its hue turned by `degrees` in `space`
+pub fn rotate_hue(rgb: Rgb, degrees: f64, space: HarmonySpace) -> Rgb {
+    match space {
+        HarmonySpace::Oklch => {
+            let lch = Oklch::from(rgb);
+            Oklch { h: (lch.h + degrees).rem_euclid(360.0), ..lch }.to_gamut().into()
+        }
+        HarmonySpace::Hsl => {
+            let hsl = Hsl::from(rgb);
+            Hsl { h: (hsl.h + degrees as f32).rem_euclid(360.0), ..hsl }.into()
+        }
+    }
+}
+
+/// The colors of `scheme` around `base`, base first, each with its nearest name
+pub fn harmony(base: Rgb, scheme: HarmonyScheme, options: &HarmonyOptions) -> Vec<HarmonyColor> {
+    scheme
+        .offsets()
+        .iter()
+        .map(|&offset| {
+            // the base is kept as is rather than round-tripped
+            let rgb = if offset == 0.0 { base } else { rotate_hue(base, offset, options.space) };
+            let nearest = nearest_colors(rgb, 1, options.origin, options.metric).into_iter().next();
+            HarmonyColor { offset, rgb, nearest }
+        })
+        .collect()
+}
@@ -0,0 +1,271 @@
+//! Load user palettes and register them next to the built-in origins.
+//!
+//! Reads GIMP `.gpl`, Adobe `.ase`, CSV and JSON into a [`PaletteFile`], then
+//! [`PaletteFile::register`] gives it a new `PaletteId` that works
+//! with `origin_slice`, `search_in_origin`, `nearest_colors` and the origin picker.
+//! Imported palettes are not part of `Origin::All`.
+//!
+//! CSV rows hold a name and a color in either order; a header row naming
+//! `name` and `hex`/`color` columns is optional. JSON may be an array of
+//! `{"name", "hex"}` objects or bare color strings, an object mapping names to
+//! colors, or `{"name": …, "colors": [...]}`. Colors are anything
+//! [`parse_color`] accepts.
+//!
+//! ```
+//! use colorum::colors_helper::{TokenMode, search_in_origin};
+//! use colorum::import::read_csv;
+//!
+//! let palette = read_csv("name,hex\nHarbour Fog,#A7B8C2\nHarbour Night,#1C2B39\n").unwrap();
+//! let origin = palette.register();
+//! assert_eq!(search_in_origin(origin, "harbour fog", TokenMode::All).len(), 1);
+//! ```
+
+use crate::colors_helper::{Origin, register_palette};
+use crate::core::parse::parse_color;
+use crate::core::rgb::{Rgb, rgb_to_hex};
+use crate::export::{PaletteFile, PaletteFileError, Swatch, read_ase, read_gpl};
+use serde_json::Value;
+use std::fmt;
+use std::path::Path;
+
+/// Palette formats that can be imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImportFormat {
+    /// GIMP / Inkscape palette (text)
+    Gpl,
+    /// Adobe Swatch Exchange
+    Ase,
+    /// Comma-separated name and color columns
+    Csv,
+    /// JSON list or map of named colors
+    Json,
+}
+
+impl ImportFormat {
+    pub const ALL: [ImportFormat; 4] =
+        [ImportFormat::Gpl, ImportFormat::Ase, ImportFormat::Csv, ImportFormat::Json];
+
+    /// File extension without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImportFormat::Gpl => "gpl",
+            ImportFormat::Ase => "ase",
+            ImportFormat::Csv => "csv",
+            ImportFormat::Json => "json",
+        }
+    }
+
+    /// Match an extension, ignoring case and a leading dot
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        let ext = ext.trim_start_matches('.');
+        Self::ALL.into_iter().find(|f| f.extension().eq_ignore_ascii_case(ext))
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension().and_then(|e| e.to_str()).and_then(Self::from_extension)
+    }
+}
+
+impl fmt::Display for ImportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ".{}", self.extension())
+    }
+}
+
+fn malformed(why: impl Into<String>) -> PaletteFileError {
+    PaletteFileError::Malformed(why.into())
+}
+
+fn utf8(bytes: &[u8], format: ImportFormat) -> Result<&str, PaletteFileError> {
+    std::str::from_utf8(bytes).map_err(|_| malformed(format!("{format} file is not UTF-8")))
+}
+
+/// Decode a palette in the given format
+pub fn read_palette(format: ImportFormat, bytes: &[u8]) -> Result<PaletteFile, PaletteFileError> {
+    match format {
+        ImportFormat::Gpl => read_gpl(utf8(bytes, format)?),
+        ImportFormat::Ase => read_ase(bytes),
+        ImportFormat::Csv => read_csv(utf8(bytes, format)?),
+        ImportFormat::Json => read_json(utf8(bytes, format)?),
+    }
+}
+
+/// Read a palette from `path`, choosing the format from its extension.
+/// An unnamed palette is named after the file.
+pub fn load_palette(path: impl AsRef<Path>) -> Result<PaletteFile, PaletteFileError> {
+    let path = path.as_ref();
+    let format = ImportFormat::from_path(path)
+        .ok_or_else(|| PaletteFileError::UnknownFormat(path.display().to_string()))?;
+    let mut palette = read_palette(format, &std::fs::read(path)?)?;
+    if palette.name.trim().is_empty() {
+        palette.name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+    }
+    Ok(palette)
+}
+
+/// Load `path` and register it; returns the new origin
+pub fn import_palette(path: impl AsRef<Path>) -> Result<Origin, PaletteFileError> {
+    Ok(load_palette(path)?.register())
+}
+
+impl PaletteFile {
+    /// Register this palette as a new origin for the rest of the program
+    pub fn register(self) -> Origin {
+        register_palette(&self.name, self.swatches.into_iter().map(|s| (s.name, s.rgb)))
+    }
+}
+
+fn color_of(value: &str) -> Option<Rgb> {
+    parse_color(value).ok().map(|c| c.rgb())
+}
+
+// ---- CSV -------------------------------------------------------------------
+
+// Split one CSV line, honouring double quotes ("" inside quotes is a literal quote)
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_string());
+    fields
+}
+
+// Only hex and function syntax count as "the color column" when guessing,
+// so a name like "Tomato" is never mistaken for one.
+fn looks_like_color(field: &str) -> bool {
+    let f = field.trim_start_matches('#');
+    (field.contains('(') || (matches!(f.len(), 3 | 4 | 6 | 8) && f.chars().all(|c| c.is_ascii_hexdigit())))
+        && color_of(field).is_some()
+}
+
+// blank, or a `#` line that isn't a `#hex,...` row
+fn is_csv_comment(line: &str) -> bool {
+    line.is_empty() || (line.starts_with('#') && !looks_like_color(&csv_fields(line)[0]))
+}
+
+/// CSV with a name and a color per row. Blank lines and `#` comments are skipped.
+pub fn read_csv(text: &str) -> Result<PaletteFile, PaletteFileError> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut rows = text
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !is_csv_comment(l.trim()))
+        .map(|(i, l)| (i + 1, csv_fields(l)))
+        .peekable();
+
+    // (name column, color column) from a header row, if there is one
+    let mut columns: Option<(Option<usize>, usize)> = None;
+    if let Some((_, first)) = rows.peek()
+        && !first.iter().any(|f| looks_like_color(f))
+    {
+        let find = |keys: &[&str]| {
+            first.iter().position(|f| keys.iter().any(|k| f.eq_ignore_ascii_case(k)))
+        };
+        let color = find(&["hex", "color", "colour", "value", "rgb"])
+            .ok_or_else(|| malformed("CSV header has no hex/color column"))?;
+        columns = Some((find(&["name", "label", "title"]), color));
+        rows.next();
+    }
+
+    let mut swatches = Vec::new();
+    for (line_no, fields) in rows {
+        let (name, rgb) = match columns {
+            Some((name_col, color_col)) => {
+                let value = fields.get(color_col).map(String::as_str).unwrap_or("");
+                let rgb = color_of(value)
+                    .ok_or_else(|| malformed(format!("line {line_no}: bad color `{value}`")))?;
+                let name = name_col.and_then(|c| fields.get(c)).cloned().unwrap_or_default();
+                (name, rgb)
+            }
+            None => {
+                let color_col = fields
+                    .iter()
+                    .position(|f| looks_like_color(f))
+                    .ok_or_else(|| malformed(format!("line {line_no}: no color")))?;
+                let rgb = color_of(&fields[color_col]).expect("checked by looks_like_color");
+                let name = fields
+                    .iter()
+                    .enumerate()
+                    .find(|(i, f)| *i != color_col && !f.is_empty())
+                    .map(|(_, f)| f.clone())
+                    .unwrap_or_default();
+                (name, rgb)
+            }
+        };
+        let name = if name.is_empty() { rgb_to_hex(rgb) } else { name };
+        swatches.push(Swatch::new(name, rgb));
+    }
+    Ok(PaletteFile { name: String::new(), swatches })
+}
+
+// ---- JSON ------------------------------------------------------------------
+
+fn json_swatch(entry: &Value) -> Result<Swatch, PaletteFileError> {
+    let (name, value) = match entry {
+        Value::String(s) => (String::new(), s.as_str()),
+        Value::Object(obj) => {
+            let value = ["hex", "color", "colour", "value"]
+                .iter()
+                .find_map(|k| obj.get(*k).and_then(Value::as_str))
+                .ok_or_else(|| malformed(format!("swatch without a hex/color field: {entry}")))?;
+            let name = obj.get("name").and_then(Value::as_str).unwrap_or("").to_string();
+            (name, value)
+        }
+        _ => return Err(malformed(format!("unexpected swatch {entry}"))),
+    };
+    let rgb = color_of(value).ok_or_else(|| malformed(format!("bad color `{value}`")))?;
+    let name = if name.trim().is_empty() { rgb_to_hex(rgb) } else { name };
+    Ok(Swatch::new(name, rgb))
+}
+
+/// JSON palette in one of the shapes described in the module docs
+pub fn read_json(text: &str) -> Result<PaletteFile, PaletteFileError> {
+    let root: Value = serde_json::from_str(text).map_err(|e| malformed(format!("bad JSON: {e}")))?;
+
+    let (name, entries) = match &root {
+        Value::Array(items) => (String::new(), items),
+        Value::Object(obj) => {
+            match ["colors", "swatches"].iter().find_map(|k| obj.get(*k)) {
+                Some(Value::Array(items)) => {
+                    let name = obj.get("name").and_then(Value::as_str).unwrap_or("").to_string();
+                    (name, items)
+                }
+                Some(_) => return Err(malformed("`colors` must be an array")),
+                // { "Name": "#hex", ... }
+                None => {
+                    let swatches = obj
+                        .iter()
+                        .map(|(name, value)| {
+                            let value = value
+                                .as_str()
+                                .ok_or_else(|| malformed(format!("`{name}` is not a color string")))?;
+                            let rgb = color_of(value)
+                                .ok_or_else(|| malformed(format!("bad color `{value}`")))?;
+                            Ok(Swatch::new(name.clone(), rgb))
+                        })
+                        .collect::<Result<_, PaletteFileError>>()?;
+                    return Ok(PaletteFile { name: String::new(), swatches });
+                }
+            }
+        }
+        _ => return Err(malformed("expected a JSON array or object")),
+    };
+
+    let swatches = entries.iter().map(json_swatch).collect::<Result<_, _>>()?;
+    Ok(PaletteFile { name, swatches })
+}
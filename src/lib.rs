@@ -1,6 +1,9 @@
 //! Library entry for `colorum`
 //! - Re-exports core modules so call sites can `use colorum::*`.
 //! - Provides `run_app()` that `main.rs` can call to start the Iced app_gui.
+//! - `ui`, `brand` and the `Msg`/`Channel` re-exports need the default `gui`
+//!   feature; everything else builds without iced.
+//! - `extract` (image palettes) needs the `extract` feature, which `gui` turns on.
 
 #![forbid(unsafe_code)]
 extern crate core as std_core;
@@ -8,12 +11,24 @@ extern crate core as std_core;
 pub mod colors; // src/colors/
 pub mod colors_helper; // src/colors_helper/
 pub mod core; // src/core/ - Core types and utilities
+pub mod contrast; // src/contrast.rs - WCAG 2.1 ratios and APCA Lc
+pub mod cvd; // src/cvd.rs - color vision deficiency simulation and confusable pairs
+pub mod export; // src/export.rs - palette files (.gpl, .ase, .aco, .swatches)
+pub mod gradient; // src/gradient.rs - interpolation, gradients and CSS linear-gradient()
+pub mod harmony; // src/harmony.rs - complementary/triadic/... sets snapped to named colors
+pub mod import; // src/import.rs - user palettes (.gpl, .ase, .csv, .json) registered at runtime
+pub mod scale; // src/scale.rs - tints/shades/tones and 50-950 ramps with CSS/Tailwind export
+#[cfg(feature = "extract")]
+pub mod extract; // src/extract.rs - dominant colors of PNG/JPEG images
+#[cfg(feature = "gui")]
 pub mod ui; // src/ui/ - User interface components
 
 // Re-export core types for convenience
-pub use core::{color_types, hex, rgb};
+pub use core::{color_types, distance, hex, parse, rgb, spaces};
+#[cfg(feature = "gui")]
 pub use ui::{messages, widgets};
 
+#[cfg(feature = "gui")]
 pub mod brand;
 
 // ---- Re-exports for ergonomics ---------------------------------------------
@@ -29,6 +44,7 @@ pub use colors::{
 };
 
 pub use colors::brand_colors::COLORS_BRANDS;
+pub use colors::ral_colors::COLORS_RAL_CLASSIC;
 #[cfg(feature = "github-colors")]
 pub use colors::github_colors::COLORS_GITHUB;
 
@@ -46,20 +62,30 @@ pub use colors::github_colors::COLORS_GITHUB;
 // UI module already declared above
 // Re-exports (updated)
 pub use core::hex::{
-    HexError, combine_hex, hex_for_name, name_for_hex, normalize_hex, sanitize_hex2, split_hex,
+    HexError, combine_hex, hex_for_name, name_for_hex, normalize_hex, normalize_hex_alpha,
+    sanitize_hex2, split_hex,
 };
-pub use core::rgb::{Rgb, dist2, hex_to_rgb, rgb_to_hex};
+pub use core::rgb::{Rgb, Rgba, dist2, hex_to_rgb, hex_to_rgba, rgb_to_hex, rgba_to_hex};
+pub use core::distance::DistanceMetric;
+pub use core::parse::{ParseError, parse_color};
+pub use core::spaces::{Cmyk, Hwb, Hsv, Lab, Lch, Oklab, Oklch, Xyz};
 
 // If you want these at the root:
 pub use colors_helper::{
     COMBINED_COLORS,
+    closest_in,
+    color_index,
     find_closest_color_name,
+    find_closest_color_name_with,
+    nearest_colors,
+    nearest_in,
     search_in_origin,
     origin_slice,
     lookup_by_name,
     lookup_by_name_ci,
     TokenMode,
 };
+#[cfg(feature = "gui")]
 pub use ui::messages::{Channel, Msg};
 
 // ---- Convenience functions --------------------------------------------------
@@ -120,7 +146,8 @@ mod tests {
         assert_eq!(normalize_hex("#33aaff").unwrap(), "#33AAFF");
         assert_eq!(normalize_hex("#33AAFFCC").unwrap(), "#33AAFF"); // strips alpha
         assert!(normalize_hex("33AAFF").is_err()); // must start with '#'
-        assert!(normalize_hex("#33AA").is_err()); // #RGBA not supported
+        assert_eq!(normalize_hex("#33AA").unwrap(), "#3333AA"); // #RGBA, alpha dropped
+        assert!(normalize_hex("#33AAF").is_err()); // 5 digits not supported
     }
 
     #[test]
@@ -190,9 +217,9 @@ mod tests {
             println!("  {}: {} - {}", i + 1, name.as_str(), hex.as_str());
         }
 
-        // Manual check of colors containing 'a'
+        // Manual check of colors containing 'a' (accents folded, so "Erreà" counts)
         let matching_a_count = slice.iter()
-            .filter(|(_, name)| name.as_str().to_lowercase().contains("a"))
+            .filter(|(_, name)| crate::colors_helper::fold_text(name.as_str()).contains("a"))
             .count();
         let matching_a_sample: Vec<_> = slice.iter()
             .filter(|(_, name)| name.as_str().to_lowercase().contains("a"))
@@ -391,6 +418,263 @@ mod tests {
         assert_eq!(dist_ab, dist_ba);
     }
 
+    #[test]
+    fn test_ciede2000_reference_pairs() {
+        use crate::core::distance::{Lab, delta_e_2000};
+
+        // Sharma, Wu & Dalal (2005) test data: (L1, a1, b1), (L2, a2, b2), ΔE00
+        let pairs: [((f64, f64, f64), (f64, f64, f64), f64); 34] = [
+            ((50.0000, 2.6772, -79.7751), (50.0000, 0.0000, -82.7485), 2.0425),
+            ((50.0000, 3.1571, -77.2803), (50.0000, 0.0000, -82.7485), 2.8615),
+            ((50.0000, 2.8361, -74.0200), (50.0000, 0.0000, -82.7485), 3.4412),
+            ((50.0000, -1.3802, -84.2814), (50.0000, 0.0000, -82.7485), 1.0000),
+            ((50.0000, -1.1848, -84.8006), (50.0000, 0.0000, -82.7485), 1.0000),
+            ((50.0000, -0.9009, -85.5211), (50.0000, 0.0000, -82.7485), 1.0000),
+            ((50.0000, 0.0000, 0.0000), (50.0000, -1.0000, 2.0000), 2.3669),
+            ((50.0000, -1.0000, 2.0000), (50.0000, 0.0000, 0.0000), 2.3669),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0009), 7.1792),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0010), 7.1792),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0011), 7.2195),
+            ((50.0000, 2.4900, -0.0010), (50.0000, -2.4900, 0.0012), 7.2195),
+            ((50.0000, -0.0010, 2.4900), (50.0000, 0.0009, -2.4900), 4.8045),
+            ((50.0000, -0.0010, 2.4900), (50.0000, 0.0010, -2.4900), 4.8045),
+            ((50.0000, -0.0010, 2.4900), (50.0000, 0.0011, -2.4900), 4.7461),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 0.0000, -2.5000), 4.3065),
+            ((50.0000, 2.5000, 0.0000), (73.0000, 25.0000, -18.0000), 27.1492),
+            ((50.0000, 2.5000, 0.0000), (61.0000, -5.0000, 29.0000), 22.8977),
+            ((50.0000, 2.5000, 0.0000), (56.0000, -27.0000, -3.0000), 31.9030),
+            ((50.0000, 2.5000, 0.0000), (58.0000, 24.0000, 15.0000), 19.4535),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 3.1736, 0.5854), 1.0000),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 3.2972, 0.0000), 1.0000),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 1.8634, 0.5757), 1.0000),
+            ((50.0000, 2.5000, 0.0000), (50.0000, 3.2592, 0.3350), 1.0000),
+            ((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644),
+            ((63.0109, -31.0961, -5.8663), (62.8187, -29.7946, -4.0864), 1.2630),
+            ((61.2901, 3.7196, -5.3901), (61.4292, 2.2480, -4.9620), 1.8731),
+            ((35.0831, -44.1164, 3.7933), (35.0232, -40.0716, 1.5901), 1.8645),
+            ((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), 2.0373),
+            ((36.4612, 47.8580, 18.3852), (36.2715, 50.5065, 21.2231), 1.4146),
+            ((90.8027, -2.0831, 1.4410), (91.1528, -1.6435, 0.0447), 1.4441),
+            ((90.9257, -0.5406, -0.9208), (88.6381, -0.8985, -0.7239), 1.5381),
+            ((6.7747, -0.2908, -2.4247), (5.8714, -0.0985, -2.2286), 0.6377),
+            ((2.0776, 0.0795, -1.1350), (0.9033, -0.0636, -0.5514), 0.9082),
+        ];
+
+        for (i, ((l1, a1, b1), (l2, a2, b2), expected)) in pairs.iter().enumerate() {
+            let x = Lab::new(*l1, *a1, *b1);
+            let y = Lab::new(*l2, *a2, *b2);
+            let d = delta_e_2000(x, y);
+            assert!((d - expected).abs() < 1e-4, "pair {}: got {:.4}, expected {:.4}", i + 1, d, expected);
+            // ΔE00 is symmetric
+            assert!((delta_e_2000(y, x) - expected).abs() < 1e-4, "pair {} reversed", i + 1);
+        }
+    }
+
+    #[test]
+    fn test_distance_metrics() {
+        let tomato = Rgb { r: 255, g: 99, b: 71 };
+        let near = Rgb { r: 250, g: 100, b: 75 };
+        let far = Rgb { r: 0, g: 128, b: 255 };
+
+        for metric in DistanceMetric::ALL {
+            assert_eq!(metric.distance(tomato, tomato), 0.0, "{} of a color to itself", metric);
+            assert!(
+                metric.distance(tomato, near) < metric.distance(tomato, far),
+                "{} should rank the similar color closer", metric
+            );
+        }
+
+        // White vs black is ΔE76 = 100 in CIELAB
+        let white = Rgb { r: 255, g: 255, b: 255 };
+        let black = Rgb { r: 0, g: 0, b: 0 };
+        assert!((DistanceMetric::Cie76.distance(white, black) - 100.0).abs() < 0.01);
+        assert!((DistanceMetric::Oklab.distance(white, black) - 1.0).abs() < 0.01);
+
+        // Exact catalog colors resolve to themselves under every metric
+        for metric in DistanceMetric::ALL {
+            let hit = closest_in(COLORS_CSS, tomato, metric).unwrap();
+            assert_eq!(hit.1.as_str(), "tomato");
+            assert_eq!(hit.2, 0.0);
+        }
+        assert_eq!(DistanceMetric::default(), DistanceMetric::Ciede2000);
+    }
+
+    #[test]
+    fn test_nearest_colors() {
+        let tomato = Rgb { r: 255, g: 99, b: 71 };
+
+        let top = nearest_colors(tomato, 5, Origin::Css, DistanceMetric::Ciede2000);
+        assert_eq!(top.len(), 5);
+        assert_eq!(top[0].1.as_str(), "tomato");
+        assert_eq!(top[0].2, 0.0);
+        assert!(top.windows(2).all(|w| w[0].2 <= w[1].2), "results should be nearest first");
+        assert!(top.iter().all(|(hex, name, _)| {
+            origin_slice(Origin::Css).iter().any(|(h, n)| h == hex && n == name)
+        }));
+
+        // Restricting the origin never finds anything closer than the full catalog
+        let pantone = nearest_colors(tomato, 3, Origin::Pantone, DistanceMetric::Oklab);
+        let all = nearest_colors(tomato, 3, Origin::All, DistanceMetric::Oklab);
+        assert_eq!(pantone.len(), 3);
+        assert!(all[0].2 <= pantone[0].2);
+
+        // Edge cases
+        assert!(nearest_colors(tomato, 0, Origin::Css, DistanceMetric::Cie76).is_empty());
+        let everything = nearest_colors(tomato, usize::MAX, Origin::Persian, DistanceMetric::Cie76);
+        assert_eq!(everything.len(), origin_slice(Origin::Persian).len());
+    }
+
+    #[test]
+    fn test_color_space_round_trips() {
+        use crate::core::rgb::Hsl;
+
+        // Every space returns the original color on Rgb -> space -> Rgb
+        for r in (0..=255u16).step_by(15) {
+            for g in (0..=255u16).step_by(15) {
+                for b in (0..=255u16).step_by(15) {
+                    let rgb = Rgb { r: r as u8, g: g as u8, b: b as u8 };
+                    assert_eq!(Rgb::from(Lab::from(rgb)), rgb, "Lab {rgb:?}");
+                    assert_eq!(Rgb::from(Lch::from(rgb)), rgb, "Lch {rgb:?}");
+                    assert_eq!(Rgb::from(Oklab::from(rgb)), rgb, "Oklab {rgb:?}");
+                    assert_eq!(Rgb::from(Oklch::from(rgb)), rgb, "Oklch {rgb:?}");
+                    assert_eq!(Rgb::from(Hsl::from(rgb)), rgb, "Hsl {rgb:?}");
+                    assert_eq!(Rgb::from(Hsv::from(rgb)), rgb, "Hsv {rgb:?}");
+                    assert_eq!(Rgb::from(Hwb::from(rgb)), rgb, "Hwb {rgb:?}");
+                    assert_eq!(Rgb::from(Xyz::from(rgb)), rgb, "Xyz {rgb:?}");
+                    assert_eq!(Rgb::from(Cmyk::from(rgb)), rgb, "Cmyk {rgb:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_space_reference_values() {
+        let close = |a: f64, b: f64, eps: f64| (a - b).abs() < eps;
+        let red = Rgb { r: 255, g: 0, b: 0 };
+
+        let lab = Lab::from(red);
+        assert!(close(lab.l, 53.2408, 1e-3) && close(lab.a, 80.0925, 1e-3) && close(lab.b, 67.2032, 1e-3));
+
+        let lch = Lch::from(red);
+        assert!(close(lch.c, 104.5518, 1e-3) && close(lch.h, 39.9990, 1e-3));
+
+        let ok = Oklab::from(red);
+        assert!(close(ok.l, 0.62796, 1e-4) && close(ok.a, 0.22486, 1e-4) && close(ok.b, 0.12585, 1e-4));
+
+        let oklch = Oklch::from(red);
+        assert!(close(oklch.c, 0.25768, 1e-4) && close(oklch.h, 29.2339, 1e-2));
+
+        let xyz = Xyz::from(Rgb { r: 255, g: 255, b: 255 });
+        assert!(close(xyz.x, 0.95047, 1e-3) && close(xyz.y, 1.0, 1e-6) && close(xyz.z, 1.08883, 1e-3));
+
+        let hsv = Hsv::from(Rgb { r: 255, g: 87, b: 51 });
+        assert!(close(hsv.h, 10.588, 1e-3) && close(hsv.s, 80.0, 1e-9) && close(hsv.v, 100.0, 1e-9));
+
+        let hwb = Hwb::from(Rgb { r: 128, g: 128, b: 128 });
+        assert!(close(hwb.w, 50.196, 1e-3) && close(hwb.b, 49.804, 1e-3));
+        // whiteness + blackness over 100% normalizes to gray
+        assert_eq!(Rgb::from(Hwb::new(0.0, 80.0, 80.0)), Rgb { r: 128, g: 128, b: 128 });
+
+        let cmyk = Cmyk::from(Rgb { r: 255, g: 87, b: 51 });
+        assert!(close(cmyk.c, 0.0, 1e-9) && close(cmyk.m, 65.882, 1e-3) && close(cmyk.y, 80.0, 1e-9) && close(cmyk.k, 0.0, 1e-9));
+        assert_eq!(Cmyk::from(Rgb { r: 0, g: 0, b: 0 }), Cmyk::new(0.0, 0.0, 0.0, 100.0));
+
+        // Out-of-gamut values clamp instead of wrapping
+        assert_eq!(Rgb::from(Oklch::new(0.7, 0.4, 150.0)).r, 0);
+        assert_eq!(Rgb::from(Lab::new(120.0, 0.0, 0.0)), Rgb { r: 255, g: 255, b: 255 });
+    }
+
+    #[test]
+    fn test_spatial_index_matches_linear_scan() {
+        // Walk a coarse RGB grid and compare the k-d tree against a brute-force scan
+        let grid = (0..=255u16).step_by(85).map(|v| v as u8);
+        let mut targets = Vec::new();
+        for r in grid.clone() {
+            for g in grid.clone() {
+                for b in grid.clone() {
+                    targets.push(Rgb { r, g, b });
+                }
+            }
+        }
+
+        for origin in [Origin::All, Origin::Css, Origin::XKCD] {
+            let colors = origin_slice(origin);
+            assert_eq!(color_index(origin, DistanceMetric::Cie76).len(), colors.len());
+
+            for &target in &targets {
+                // Euclidean metrics: the tree is exact, distances must agree
+                for metric in [DistanceMetric::RgbEuclid, DistanceMetric::Cie76, DistanceMetric::Oklab] {
+                    let fast = nearest_colors(target, 5, origin, metric);
+                    let slow = nearest_in(colors, target, 5, metric);
+                    let fd: Vec<f64> = fast.iter().map(|c| c.2).collect();
+                    let sd: Vec<f64> = slow.iter().map(|c| c.2).collect();
+                    assert_eq!(fd, sd, "{origin} {metric} {target:?}");
+                }
+
+                // Non-Euclidean metrics: the best match must be as good as brute force
+                for metric in [DistanceMetric::Cie94, DistanceMetric::Ciede2000] {
+                    let fast = nearest_colors(target, 1, origin, metric);
+                    let slow = nearest_in(colors, target, 1, metric);
+                    assert!((fast[0].2 - slow[0].2).abs() < 1e-9, "{origin} {metric} {target:?}");
+                }
+            }
+        }
+
+        // Random targets, several neighbours, every metric: the k distances match a linear scan
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for (origin, targets) in [(Origin::All, 30), (Origin::Css, 200), (Origin::Pantone, 100)] {
+            let colors = origin_slice(origin);
+            for _ in 0..targets {
+                let x = next();
+                let target = Rgb { r: x as u8, g: (x >> 8) as u8, b: (x >> 16) as u8 };
+                let k = 2 + (x >> 24) as usize % 9;
+                for metric in DistanceMetric::ALL {
+                    let fast: Vec<f64> = nearest_colors(target, k, origin, metric).iter().map(|c| c.2).collect();
+                    let slow: Vec<f64> = nearest_in(colors, target, k, metric).iter().map(|c| c.2).collect();
+                    assert_eq!(fast.len(), k);
+                    assert!(fast.iter().zip(&slow).all(|(f, s)| (f - s).abs() < 1e-9), "{origin} {metric} k={k} {target:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ciede2000_index_every_palette() {
+        // The CIEDE2000 re-query radius is measured, not proven: check k > 1 on
+        // every palette, half the targets in the saturated blues where ΔE76 / ΔE00 peaks
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let metric = DistanceMetric::Ciede2000;
+        for origin in crate::colors_helper::PaletteId::all() {
+            let colors = origin_slice(origin);
+            let targets = if origin == Origin::All { 20 } else { 60 };
+            for i in 0..targets {
+                let x = next();
+                let target = if i % 2 == 0 {
+                    Rgb { r: x as u8, g: (x >> 8) as u8, b: (x >> 16) as u8 }
+                } else {
+                    Rgb { r: x as u8 % 96, g: (x >> 8) as u8 % 96, b: 160 + (x >> 16) as u8 % 96 }
+                };
+                let k = (2 + (x >> 24) as usize % 9).min(colors.len());
+                let fast: Vec<f64> = nearest_colors(target, k, origin, metric).iter().map(|c| c.2).collect();
+                let slow: Vec<f64> = nearest_in(colors, target, k, metric).iter().map(|c| c.2).collect();
+                assert_eq!(fast.len(), k, "{origin}");
+                assert!(fast.iter().zip(&slow).all(|(f, s)| (f - s).abs() < 1e-9), "{origin} k={k} {target:?}");
+            }
+        }
+    }
+
     #[test]
     fn test_hex_utilities_comprehensive() {
         // Test normalize_hex with various formats
@@ -401,7 +685,7 @@ mod tests {
 
         // Test error cases
         assert!(normalize_hex("FF0000").is_err()); // missing #
-        assert!(normalize_hex("#FF00").is_err()); // invalid length
+        assert!(normalize_hex("#FF000").is_err()); // invalid length
         assert!(normalize_hex("#GG0000").is_err()); // invalid hex chars
 
         // Test split and combine
@@ -444,6 +728,17 @@ mod tests {
         if red_ci.is_some() && red_lower.is_some() {
             assert_eq!(red_ci, red_lower, "Case-insensitive lookup should return same result");
         }
+
+        // a name in several palettes resolves to the first in registry order, CSS first
+        assert_eq!(lookup_by_name("tomato"), hex_for_name("tomato"));
+        assert_eq!(lookup_by_name_ci("TOMATO"), Some("#FF6347"));
+
+        // ...or within one origin, with the catalog's spelling of the name
+        use crate::colors_helper::lookup_in_origin_ci;
+        let (hex, name) = lookup_in_origin_ci(Origin::XKCD, "TOMATO").unwrap();
+        assert_eq!((hex.as_str().to_uppercase().as_str(), name.as_str()), ("#EF4026", "tomato"));
+        assert_eq!(lookup_in_origin_ci(Origin::All, "Tomato").map(|(h, _)| h.as_str()), lookup_by_name_ci("tomato"));
+        assert!(lookup_in_origin_ci(Origin::Css, "puke green").is_none());
     }
 
     #[test]
@@ -514,14 +809,18 @@ mod tests {
     fn test_copy_format_enum() {
         // Test format cycling
         assert_eq!(CopyFormat::Hex.next(), CopyFormat::Rgb);
-        assert_eq!(CopyFormat::Rgb.next(), CopyFormat::Hsl);
-        assert_eq!(CopyFormat::Hsl.next(), CopyFormat::RgbValues);
+        assert_eq!(CopyFormat::Rgb.next(), CopyFormat::Rgba);
+        assert_eq!(CopyFormat::Rgba.next(), CopyFormat::Hsl);
+        assert_eq!(CopyFormat::Hsl.next(), CopyFormat::Hsla);
+        assert_eq!(CopyFormat::Hsla.next(), CopyFormat::RgbValues);
         assert_eq!(CopyFormat::RgbValues.next(), CopyFormat::Hex);
 
         // Test display names
         assert_eq!(CopyFormat::Hex.display_name(), "HEX");
         assert_eq!(CopyFormat::Rgb.display_name(), "RGB");
+        assert_eq!(CopyFormat::Rgba.display_name(), "RGBA");
         assert_eq!(CopyFormat::Hsl.display_name(), "HSL");
+        assert_eq!(CopyFormat::Hsla.display_name(), "HSLA");
         assert_eq!(CopyFormat::RgbValues.display_name(), "RGB Values");
 
         // Test default
@@ -556,12 +855,1006 @@ mod tests {
         assert_eq!(hsl_output, expected_hsl_str);
     }
 
+    #[test]
+    fn test_alpha_round_trip() {
+        use crate::core::rgb::format_rgba;
+
+        // Alpha-preserving normalization
+        assert_eq!(normalize_hex_alpha("#f00").unwrap(), "#FF0000");
+        assert_eq!(normalize_hex_alpha("#f008").unwrap(), "#FF000088");
+        assert_eq!(normalize_hex_alpha("#33aaffcc").unwrap(), "#33AAFFCC");
+        assert_eq!(normalize_hex_alpha("#33aaff").unwrap(), "#33AAFF");
+        assert_eq!(normalize_hex_alpha("#33AAF"), Err(HexError::UnsupportedLength));
+        assert_eq!(normalize_hex_alpha("33AAFF"), Err(HexError::BadFormat));
+
+        // Rgba <-> hex
+        let tomato = Rgba::new(255, 99, 71, 128);
+        assert_eq!(hex_to_rgba("#FF634780"), Some(tomato));
+        assert_eq!(hex_to_rgba("#FF6347"), Some(tomato.with_alpha(255)));
+        assert_eq!(rgba_to_hex(tomato), "#FF634780");
+        assert_eq!(rgba_to_hex(tomato.with_alpha(255)), "#FF6347");
+        assert!(hex_to_rgba("#FF63478").is_none());
+        assert_eq!(hex_to_rgba(&normalize_hex_alpha("#F638").unwrap()), Some(Rgba::new(255, 102, 51, 136)));
+        assert_eq!(Rgb::from(tomato), Rgb { r: 255, g: 99, b: 71 });
+
+        // CSS output keeps alpha
+        assert_eq!(format_rgba(tomato, CopyFormat::Hex), "#FF634780");
+        assert_eq!(format_rgba(tomato, CopyFormat::Rgba), "rgba(255, 99, 71, 0.502)");
+        assert_eq!(format_rgba(tomato, CopyFormat::Hsla), "hsla(9, 100%, 64%, 0.502)");
+        assert_eq!(format_rgba(tomato, CopyFormat::Rgb), "rgb(255, 99, 71)");
+        assert_eq!(format_rgba(tomato.with_alpha(0), CopyFormat::Rgba), "rgba(255, 99, 71, 0)");
+        assert_eq!(format_rgb(tomato.rgb(), CopyFormat::Rgba), "rgba(255, 99, 71, 1)");
+    }
+
+    #[test]
+    fn test_parse_color_css4() {
+        let rgba = |r, g, b, a| Rgba::new(r, g, b, a);
+        let ok = |s: &str| parse_color(s).unwrap_or_else(|e| panic!("{s}: {e}"));
+
+        // hex, with and without '#', with alpha
+        assert_eq!(ok("#f63"), rgba(255, 102, 51, 255));
+        assert_eq!(ok("#FF634780"), rgba(255, 99, 71, 128));
+        assert_eq!(ok("ff6347"), rgba(255, 99, 71, 255));
+
+        // rgb(): legacy commas, modern spaces, percentages, slash alpha
+        assert_eq!(ok("rgb(12, 34, 56)"), rgba(12, 34, 56, 255));
+        assert_eq!(ok("rgba(12, 34, 56, 0.5)"), rgba(12, 34, 56, 128));
+        assert_eq!(ok("rgb(12 34 56 / 50%)"), rgba(12, 34, 56, 128));
+        assert_eq!(ok("RGB(100% 0% 50%)"), rgba(255, 0, 128, 255));
+        assert_eq!(ok("rgb(300 -5 none)"), rgba(255, 0, 0, 255)); // clamped
+
+        // hue-based
+        assert_eq!(ok("hsl(120, 100%, 25%)"), rgba(0, 128, 0, 255));
+        assert_eq!(ok("hsl(0.5turn 100% 50% / 0.25)"), rgba(0, 255, 255, 64));
+        assert_eq!(ok("hsla(240deg, 100%, 50%, 1)"), rgba(0, 0, 255, 255));
+        assert_eq!(ok("hwb(0 0% 0%)"), rgba(255, 0, 0, 255));
+        assert_eq!(ok("hwb(90 50% 50%)"), rgba(128, 128, 128, 255));
+
+        // perceptual spaces; CSS lab()/lch() are D50, so sRGB red is not the D65 (53.24 80.09 67.20)
+        assert_eq!(ok("lab(54.2905 80.8049 69.891)"), rgba(255, 0, 0, 255));
+        assert_eq!(ok("lch(54.2905 106.8372 40.8526)"), rgba(255, 0, 0, 255));
+        // the CSS Color 4 example, written in all three perceptual forms
+        assert_eq!(ok("lab(29.2345% 39.3825 20.0664)"), rgba(0x7D, 0x23, 0x29, 255));
+        assert_eq!(ok("lch(29.2345% 44.2 27)"), rgba(0x7D, 0x23, 0x29, 255));
+        assert_eq!(ok("oklch(40.101% 0.12332 21.555)"), rgba(0x7D, 0x23, 0x29, 255));
+        assert_eq!(ok("oklab(62.796% 0.22486 0.12585)"), rgba(255, 0, 0, 255));
+        assert_eq!(ok("oklch(0.62796 0.25768 29.2339 / 0)"), rgba(255, 0, 0, 0));
+        assert_eq!(ok("oklch(100% 0 0)"), rgba(255, 255, 255, 255));
+
+        // color()
+        assert_eq!(ok("color(srgb 1 0.5 0)"), rgba(255, 128, 0, 255));
+        assert_eq!(ok("color(srgb 100% 0% 0% / 0.5)"), rgba(255, 0, 0, 128));
+
+        // names and keywords
+        assert_eq!(ok("tomato"), rgba(255, 99, 71, 255));
+        assert_eq!(ok("  Tomato "), rgba(255, 99, 71, 255));
+        assert_eq!(ok("transparent"), rgba(0, 0, 0, 0));
+        assert_eq!("rgb(1 2 3)".parse::<Rgba>(), Ok(rgba(1, 2, 3, 255)));
+
+        // errors
+        assert_eq!(parse_color("   "), Err(ParseError::Empty));
+        assert_eq!(parse_color("#12345"), Err(ParseError::Hex(HexError::UnsupportedLength)));
+        assert_eq!(parse_color("foo(1 2 3)"), Err(ParseError::UnknownFunction("foo".into())));
+        // catalog names with parentheses aren't mistaken for functions
+        assert_eq!(parse_color("Benelli (firearms) red").unwrap().rgb(), Rgb { r: 0xE1, g: 0x06, b: 0x00 });
+        assert_eq!(parse_color("Cu(I) Copper(I) Blue-green").unwrap().rgb(), Rgb { r: 0x0D, g: 0x98, b: 0xBA });
+        assert_eq!(parse_color("benelli (FIREARMS) red").unwrap().rgb(), Rgb { r: 0xE1, g: 0x06, b: 0x00 });
+        assert!(parse_color("Benelli (rifles) red").is_err());
+        assert_eq!(
+            parse_color("color(display-p3 1 0 0)"),
+            Err(ParseError::UnsupportedColorSpace("display-p3".into()))
+        );
+        assert_eq!(
+            parse_color("rgb(1 2)"),
+            Err(ParseError::WrongArgumentCount { function: "rgb".into(), expected: 3, found: 2 })
+        );
+        assert_eq!(parse_color("rgb(1 2 x)"), Err(ParseError::BadValue("x".into())));
+        assert_eq!(parse_color("hsl(10% 50% 50%)"), Err(ParseError::BadValue("10%".into())));
+        assert_eq!(parse_color("rgb(1 2 3"), Err(ParseError::BadValue("rgb(1 2 3".into())));
+        assert_eq!(parse_color("not a color"), Err(ParseError::UnknownColor("not a color".into())));
+    }
+
+    #[test]
+    fn test_palette_export_round_trip() {
+        use crate::export::{PaletteFile, PaletteFileError, PaletteFormat, Swatch};
+
+        // an origin, plus a hand-picked set with non-ASCII names
+        let css = PaletteFile::from_origin(Origin::Css);
+        let picked: Vec<_> = origin_slice(Origin::Persian).iter().take(5).copied().collect();
+        let mut persian = PaletteFile::from_colors("Picked Persian", &picked);
+        persian.swatches.push(Swatch::new("Bleu de Sèvres – 青", Rgb { r: 2, g: 3, b: 254 }));
+        assert_eq!(css.swatches.len(), origin_slice(Origin::Css).len());
+
+        for palette in [&css, &persian] {
+            for format in PaletteFormat::ALL {
+                let bytes = palette.to_bytes(format).unwrap();
+                let back = PaletteFile::from_bytes(format, &bytes)
+                    .unwrap_or_else(|e| panic!("{format} {}: {e}", palette.name));
+
+                let colors: Vec<Rgb> = palette.swatches.iter().map(|s| s.rgb).collect();
+                let back_colors: Vec<Rgb> = back.swatches.iter().map(|s| s.rgb).collect();
+                assert_eq!(back_colors, colors, "{format} colors");
+
+                match format {
+                    // .swatches stores no names; readers fall back to the hex
+                    PaletteFormat::Swatches => {
+                        assert_eq!(back.name, palette.name);
+                        assert!(back.swatches.iter().all(|s| s.name == rgb_to_hex(s.rgb)));
+                    }
+                    // .aco has no palette name
+                    PaletteFormat::Aco => assert_eq!(back.swatches, palette.swatches),
+                    _ => assert_eq!(&back, palette, "{format}"),
+                }
+            }
+        }
+
+        // save/load picks the format from the extension
+        let dir = std::env::temp_dir().join(format!("colorum-export-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for format in PaletteFormat::ALL {
+            let path = dir.join(format!("persian.{}", format.extension()));
+            persian.save(&path).unwrap();
+            assert_eq!(PaletteFile::load(&path).unwrap().swatches.len(), persian.swatches.len());
+        }
+        assert!(matches!(persian.save(dir.join("persian.txt")), Err(PaletteFileError::UnknownFormat(_))));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // the GIMP text is what other tools expect
+        let gpl = String::from_utf8(persian.to_bytes(PaletteFormat::Gpl).unwrap()).unwrap();
+        assert!(gpl.starts_with("GIMP Palette\nName: Picked Persian\nColumns: 0\n#\n"));
+        assert!(gpl.ends_with("  2   3 254\tBleu de Sèvres – 青\n"));
+
+        // malformed input is an error, not a panic
+        for format in PaletteFormat::ALL {
+            assert!(PaletteFile::from_bytes(format, b"garbage").is_err(), "{format}");
+        }
+        let truncated = &persian.to_bytes(PaletteFormat::Ase).unwrap()[..40];
+        assert!(matches!(PaletteFile::from_bytes(PaletteFormat::Ase, truncated), Err(PaletteFileError::Malformed(_))));
+
+        // .swatches: Procreate's 30 per palette, split and joined back
+        let json = |bytes: Vec<u8>| {
+            let mut zip = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+            let mut text = String::new();
+            std::io::Read::read_to_string(&mut zip.by_name("Swatches.json").unwrap(), &mut text).unwrap();
+            serde_json::from_str::<serde_json::Value>(&text).unwrap()
+        };
+        let split = json(css.to_bytes(PaletteFormat::Swatches).unwrap());
+        let sizes: Vec<usize> = split.as_array().unwrap().iter().map(|p| p["swatches"].as_array().unwrap().len()).collect();
+        assert_eq!(sizes, [30, 30, 30, 30, 28]);
+        assert_eq!((split[0]["name"].as_str(), split[4]["name"].as_str()), (Some("CSS"), Some("CSS 5")));
+        let thirty = PaletteFile::from_colors("Thirty", &origin_slice(Origin::Css)[..30]);
+        assert_eq!(json(thirty.to_bytes(PaletteFormat::Swatches).unwrap()).as_array().unwrap().len(), 1);
+        let empty = PaletteFile { name: "Empty".into(), swatches: Vec::new() };
+        assert!(PaletteFile::from_bytes(PaletteFormat::Swatches, &empty.to_bytes(PaletteFormat::Swatches).unwrap()).unwrap().swatches.is_empty());
+
+        // .aco counts and .ase name lengths are 16-bit: too much is an error, not a corrupt file
+        let gray = Swatch::new("gray", Rgb { r: 128, g: 128, b: 128 });
+        let mut big = PaletteFile { name: "Big".into(), swatches: vec![gray.clone(); u16::MAX as usize] };
+        assert_eq!(PaletteFile::from_bytes(PaletteFormat::Aco, &big.to_bytes(PaletteFormat::Aco).unwrap()).unwrap().swatches.len(), u16::MAX as usize);
+        big.swatches.push(gray);
+        assert!(matches!(big.to_bytes(PaletteFormat::Aco), Err(PaletteFileError::TooLarge(_))));
+        let long = PaletteFile { name: "x".repeat(u16::MAX as usize), swatches: Vec::new() };
+        assert!(matches!(long.to_bytes(PaletteFormat::Ase), Err(PaletteFileError::TooLarge(_))));
+        let longest = PaletteFile { name: "x".repeat(u16::MAX as usize - 1), swatches: Vec::new() };
+        assert_eq!(PaletteFile::from_bytes(PaletteFormat::Ase, &longest.to_bytes(PaletteFormat::Ase).unwrap()).unwrap(), longest);
+        // nothing is written when encoding fails
+        let path = std::env::temp_dir().join(format!("colorum-long-{}.ase", std::process::id()));
+        assert!(matches!(long.save(&path), Err(PaletteFileError::TooLarge(_))));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_palette_import_registers_origin() {
+        use crate::colors_helper::{imported_origins, origin_names, origin_rank};
+        use crate::export::{PaletteFile, PaletteFileError, PaletteFormat};
+        use crate::import::{ImportFormat, import_palette, read_csv, read_json};
+
+        // CSV: header in any column order, quoted names, rgb() values, #hex rows without a header
+        let csv = read_csv("# my swatches\nhex,name\n#112233,\"Dock, Wet\"\nrgb(255 0 0),Signal\n").unwrap();
+        assert_eq!(csv.swatches[0].name, "Dock, Wet");
+        assert_eq!(csv.swatches[1].rgb, Rgb { r: 255, g: 0, b: 0 });
+        let bare = read_csv("Tomato,#F64\n#0A0B0C,Ink\n\n").unwrap();
+        assert_eq!(bare.swatches[0].rgb, Rgb { r: 0xFF, g: 0x66, b: 0x44 });
+        assert_eq!(bare.swatches[1].name, "Ink");
+        assert!(matches!(read_csv("name,hex\nBroken,#12"), Err(PaletteFileError::Malformed(_))));
+        assert!(read_csv("a,b\n").is_err());
+
+        // JSON: named list, bare array, and name → color map
+        let json = read_json(r##"{"name": "Harbour", "colors": [{"name": "Harbour Fog", "hex": "#A7B8C2"}, "#1C2B39"]}"##).unwrap();
+        assert_eq!(json.name, "Harbour");
+        assert_eq!(json.swatches[1].name, "#1C2B39");
+        assert_eq!(read_json(r#"[{"name": "Moss", "color": "hsl(90 40% 40%)"}]"#).unwrap().swatches.len(), 1);
+        // name -> color objects keep the file's order, not the keys' sorted order
+        let flat = read_json(r##"{"Moss": "#556B2F", "Clay": "#B66A50", "Aqua": "#00FFFF"}"##).unwrap();
+        let names: Vec<&str> = flat.swatches.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Moss", "Clay", "Aqua"]);
+        assert!(read_json(r#"[{"name": "No Color"}]"#).is_err());
+        assert!(read_json("{").is_err());
+
+        // files: the format comes from the extension, the name from the file if unnamed
+        let dir = std::env::temp_dir().join(format!("colorum-import-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let gpl_path = dir.join("tidepool.gpl");
+        PaletteFile::from_colors("", &origin_slice(Origin::Css)[..4]).save(&gpl_path).unwrap();
+        let json_path = dir.join("harbour.json");
+        std::fs::write(&json_path, r##"[{"name": "Harbour Fog", "hex": "#A7B8C2"}, {"name": "Harbour Night", "hex": "#1C2B39"}]"##).unwrap();
+        let ase_path = dir.join("picked.ase");
+        std::fs::write(&ase_path, PaletteFile::from_origin(Origin::Persian).to_bytes(PaletteFormat::Ase).unwrap()).unwrap();
+        assert_eq!(ImportFormat::from_path(&ase_path), Some(ImportFormat::Ase));
+
+        let tidepool = import_palette(&gpl_path).unwrap();
+        let harbour = import_palette(&json_path).unwrap();
+        let persian = import_palette(&ase_path).unwrap();
+        assert!(matches!(import_palette(dir.join("nope.txt")), Err(PaletteFileError::UnknownFormat(_))));
+        assert!(matches!(import_palette(dir.join("missing.csv")), Err(PaletteFileError::Io(_))));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // each import is its own origin, listed for the picker
+        assert_ne!(tidepool, harbour);
+        assert!(imported_origins().contains(&harbour));
+        assert_eq!(tidepool.to_string(), "tidepool");
+        assert_eq!(persian.to_string(), "Persian");
+        assert_eq!(origin_slice(tidepool).len(), 4);
+        assert_eq!(origin_slice(persian).len(), origin_slice(Origin::Persian).len());
+        assert!(origin_slice(harbour).iter().all(|(_, n)| n.origin() == harbour));
+
+        // searchable, sorted, ranked and indexed like a built-in origin
+        let hits = search_in_origin(harbour, "harbour night", TokenMode::All);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0.as_str(), "#1C2B39");
+        assert_eq!(search_in_origin(harbour, "ha", TokenMode::Any).len(), 2);
+        assert_eq!(search_in_origin(harbour, "", TokenMode::Any).len(), 2);
+        assert_eq!(origin_names(harbour), ["Harbour Fog", "Harbour Night"]);
+        assert_eq!(origin_rank(harbour)["Harbour Night"], 1);
+        let near = nearest_colors(Rgb { r: 30, g: 40, b: 60 }, 1, harbour, DistanceMetric::Ciede2000);
+        assert_eq!(near[0].1.as_str(), "Harbour Night");
+
+        // imports stay out of the built-in catalog
+        assert!(search_in_origin(Origin::All, "harbour night", TokenMode::All).is_empty());
+    }
+
+    #[test]
+    fn test_palette_ids_derive_from_registry() {
+        use crate::colors_helper::{PaletteId, REGISTRY, origin_names, origin_rank};
+
+        // the picker lists All, then the registry in order
+        let all = PaletteId::all();
+        assert_eq!(all[0], Origin::All);
+        assert_eq!(&all[1..=REGISTRY.len()], REGISTRY.iter().map(|c| c.origin).collect::<Vec<_>>());
+
+        for c in REGISTRY {
+            let id = c.origin;
+            assert_eq!(id.to_string(), c.name);
+            assert!(!id.is_imported());
+            assert_eq!(origin_slice(id), (c.data)(), "{id}");
+            assert_eq!(origin_names(id).len(), origin_slice(id).len(), "{id}");
+            let (_, first) = origin_slice(id)[0];
+            assert!(origin_rank(id).contains_key(first.as_str()), "{id}");
+
+            // every palette gets its own token index
+            let hits = search_in_origin(id, first.as_str(), TokenMode::All);
+            assert!(hits.iter().any(|(_, n)| *n == first), "{id}: {first}");
+            assert!(hits.iter().all(|h| origin_slice(id).contains(h)), "{id}");
+        }
+
+        // Seasons and Canadian Provinces used to borrow the ALL index
+        let winter = search_in_origin(Origin::Seasons, "winter frost", TokenMode::All);
+        assert_eq!(winter.len(), 1);
+        assert_eq!(winter[0].1.as_str(), "january winter depths frost white");
+        assert_eq!(Origin::CanadianProvinces.to_string(), "Canadian Provinces");
+        assert_eq!(Origin::default(), Origin::All);
+        assert_eq!(Origin::All.to_string(), "ALL");
+    }
+
+    mod test_palette {
+        crate::define_palette!(Tidepool, crate::colors_helper::PaletteId::custom(500), "Tidepool", [
+            ("#2F6F73", "zostera tidepool green"),
+            ("#C9B28A", "zostera tidepool sand"),
+        ]);
+        // an id a built-in palette already owns is ignored
+        pub mod clash {
+            crate::define_palette!(Clash, crate::Origin::Css, "Not CSS", [("#010203", "zostera clash")]);
+        }
+    }
+
+    #[test]
+    fn test_define_palette_feeds_catalog() {
+        use crate::colors_helper::palette_registry::{COMBINED_COLORS_AUTO, palette_data, palette_name};
+        use crate::colors_helper::{PaletteId, catalogs};
+
+        let id = PaletteId::custom(500);
+        assert_eq!(test_palette::DATA.len(), 2);
+        assert!(test_palette::DATA.iter().all(|(_, n)| n.origin() == id));
+
+        // the registry side
+        assert_eq!(palette_name(id), "Tidepool");
+        assert_eq!(palette_data(id), test_palette::DATA);
+        assert!(COMBINED_COLORS_AUTO.iter().any(|(_, n)| n.as_str() == "zostera tidepool sand"));
+
+        // ...and everything derived from the catalog
+        assert!(catalogs().iter().any(|c| c.origin == id));
+        assert!(PaletteId::all().contains(&id));
+        assert_eq!(id.to_string(), "Tidepool");
+        assert_eq!(origin_slice(id), test_palette::DATA);
+        assert_eq!(search_in_origin(id, "tidepool sand", TokenMode::All).len(), 1);
+        let all_hits = search_in_origin(Origin::All, "zostera tidepool", TokenMode::All);
+        assert_eq!(all_hits.len(), 2);
+        let near = nearest_colors(Rgb { r: 0x2F, g: 0x6F, b: 0x74 }, 1, Origin::All, DistanceMetric::Cie76);
+        assert_eq!(near[0].1.as_str(), "zostera tidepool green");
+
+        assert_eq!(Origin::Css.to_string(), "CSS");
+        let (_, clash) = test_palette::clash::DATA[0];
+        assert!(!COMBINED_COLORS.iter().any(|(_, n)| n.as_str() == clash.as_str()));
+        assert_eq!(catalogs().iter().filter(|c| c.origin == Origin::Css).count(), 1);
+    }
+
+    #[test]
+    fn test_ral_classic_palette() {
+        use crate::colors_helper::{lookup_ral, origin_names};
+        use crate::core::color_types::{ColorName, Ordering};
+
+        let ral = origin_slice(Origin::RalClassic);
+        assert_eq!(ral, crate::COLORS_RAL_CLASSIC);
+        assert_eq!(ral.len(), 216);
+        assert_eq!(Origin::RalClassic.to_string(), "RAL Classic");
+        assert!(ral.iter().all(|(_, n)| n.ordering() == Ordering::Integer && n.origin() == Origin::RalClassic));
+        assert!(ral.iter().all(|(h, _)| normalize_hex(h.as_str()).is_ok()));
+        assert!(!ral.iter().any(|(_, n)| n.as_str().contains("Yelloenw")));
+
+        // ordered by RAL number, each code once
+        let codes: Vec<u32> = ral.iter().map(|(_, n)| n.as_str()[4..8].parse().unwrap()).collect();
+        assert!(codes.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!((codes[0], codes[codes.len() - 1]), (1000, 9023));
+
+        // Ordering::Integer sorts by the code, not the name
+        let mut shuffled: Vec<ColorName> = ral.iter().rev().map(|(_, n)| *n).collect();
+        shuffled.sort();
+        assert_eq!(shuffled[0].as_str(), "RAL 1000 Green Beige");
+        assert_eq!(shuffled[2].as_str(), "RAL 1002 Sand Yellow");
+
+        // lookups by code
+        let (hex, name) = lookup_ral("RAL 3020").unwrap();
+        assert_eq!((hex.as_str(), name.as_str()), ("#BB1E10", "RAL 3020 Traffic Red"));
+        for q in ["ral3020", "RAL-3020", "  Ral 3020 "] {
+            assert_eq!(lookup_ral(q).unwrap().1, name, "{q}");
+        }
+        assert_eq!(lookup_ral("RAL 1021").unwrap().1.as_str(), "RAL 1021 Rape Yellow");
+        for q in ["RAL 3019", "RAL 302", "3020", "RAL 30200", "RALLY"] {
+            assert!(lookup_ral(q).is_none(), "{q}");
+        }
+
+        // search, the color parser and the name caches all know the codes
+        assert_eq!(search_in_origin(Origin::All, "RAL 3020", TokenMode::Any), vec![(hex, name)]);
+        assert_eq!(search_in_origin(Origin::RalClassic, "3020", TokenMode::Any), vec![(hex, name)]);
+        assert!(search_in_origin(Origin::RalClassic, "traffic", TokenMode::Any).len() >= 10);
+        assert_eq!(parse_color("RAL 3020").unwrap(), Rgba::new(0xBB, 0x1E, 0x10, 255));
+        assert_eq!(origin_names(Origin::RalClassic).len(), 216);
+    }
+
+    #[test]
+    fn test_palette_id_from_str() {
+        use crate::colors_helper::{PaletteId, UnknownPalette};
+
+        assert_eq!("ral-classic".parse::<PaletteId>(), Ok(Origin::RalClassic));
+        assert_eq!("Canadian_Provinces".parse::<PaletteId>(), Ok(Origin::CanadianProvinces));
+        assert_eq!("css".parse::<PaletteId>(), Ok(Origin::Css));
+        assert_eq!("all".parse::<PaletteId>(), Ok(Origin::All));
+        for id in PaletteId::all() {
+            assert_eq!(id.to_string().parse::<PaletteId>(), Ok(id));
+        }
+        assert_eq!("nope".parse::<PaletteId>(), Err(UnknownPalette("nope".into())));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trips() {
+        use crate::core::color_types::{ColorName, Entity, HexCode, Ordering, OwnedColorName, OwnedHexCode};
+        use serde_json::{from_str, json, to_value};
+
+        // hex codes are normalized on the way in
+        let hex: HexCode = from_str("\"#3af\"").unwrap();
+        assert_eq!(hex.as_str(), "#33AAFF");
+        assert_eq!(to_value(hex).unwrap(), json!("#33AAFF"));
+        assert!(from_str::<HexCode>("\"33aaff\"").is_err());
+        let owned: OwnedHexCode = from_str("\"#ff634780\"").unwrap();
+        assert_eq!(owned.as_str(), "#FF6347");
+
+        // names resolve back to the catalog entry
+        let (_, tomato) = *origin_slice(Origin::Css).iter().find(|(_, n)| n.as_str() == "tomato").unwrap();
+        let v = to_value(tomato).unwrap();
+        assert_eq!(v, json!({"name": "tomato", "entity": "Color", "origin": "CSS", "ordering": "Name"}));
+        assert_eq!(serde_json::from_value::<ColorName>(v).unwrap(), tomato);
+        let ral: ColorName = from_str(r#"{"name": "ral 3020 traffic red", "origin": "ral-classic"}"#).unwrap();
+        assert_eq!((ral.as_str(), ral.origin(), ral.ordering()), ("RAL 3020 Traffic Red", Origin::RalClassic, Ordering::Integer));
+        assert!(from_str::<ColorName>("\"not a catalog color\"").is_err());
+
+        // owned names keep whatever they were given
+        let mine: OwnedColorName = from_str("\"not a catalog color\"").unwrap();
+        assert_eq!((mine.as_str(), mine.entity(), mine.resolve()), ("not a catalog color", Entity::Color, None));
+        assert_eq!(OwnedColorName::from(tomato).resolve(), Some(tomato));
+
+        // plain data types derive
+        let rgb: Rgb = from_str(r#"{"r": 1, "g": 2, "b": 3}"#).unwrap();
+        assert_eq!(rgb, Rgb { r: 1, g: 2, b: 3 });
+        assert_eq!(to_value(CopyFormat::Hsla).unwrap(), json!("Hsla"));
+        assert_eq!(to_value(Ordering::Custom(3)).unwrap(), json!({"Custom": 3}));
+        assert_eq!(from_str::<Origin>("\"XKCD\"").unwrap(), Origin::XKCD);
+        let hsl = rgb_to_hsl(rgb);
+        assert_eq!(from_str::<crate::core::rgb::Hsl>(&serde_json::to_string(&hsl).unwrap()).unwrap(), hsl);
+    }
+
+    #[test]
+    fn test_fuzzy_search_ranking() {
+        use crate::colors_helper::{MatchKind, score_name, search_scored};
+
+        // typos that the token modes miss
+        assert!(search_in_origin(Origin::Css, "turquise", TokenMode::Any).is_empty());
+        let hits = search_in_origin(Origin::Css, "turquise", TokenMode::Fuzzy);
+        assert_eq!(hits[0].1.as_str(), "turquoise");
+        let hits = search_in_origin(Origin::All, "fuchia", TokenMode::Fuzzy);
+        assert_eq!(hits[0].1.as_str(), "fuchsia");
+
+        // exact > prefix > token > fuzzy, whatever the closeness within a kind
+        let exact = score_name("sky blue", "Sky-Blue").unwrap();
+        let prefix = score_name("sky", "skyblue").unwrap();
+        let token = score_name("blue", "dark sky blue").unwrap();
+        let fuzzy = score_name("bleu", "blue").unwrap();
+        assert_eq!(
+            [exact.0, prefix.0, token.0, fuzzy.0],
+            [MatchKind::Exact, MatchKind::Prefix, MatchKind::Token, MatchKind::Fuzzy]
+        );
+        assert!(exact.1 > prefix.1 && prefix.1 > token.1 && token.1 > fuzzy.1);
+        assert_eq!(score_name("red", "blue"), None);
+        // short words get no typo allowance
+        assert_eq!(score_name("rad", "red"), None);
+
+        // results come back best first, not in origin buckets
+        let ranked = search_scored(Origin::All, "red");
+        assert!(ranked.windows(2).all(|w| w[0].score >= w[1].score));
+        assert_eq!(ranked[0].kind, MatchKind::Exact);
+        assert!(ranked.iter().all(|m| m.kind != MatchKind::Exact || m.name.as_str().eq_ignore_ascii_case("red")));
+
+        // the entity prefix still applies
+        let brands = search_scored(Origin::All, "Entity:Brand blu");
+        assert!(!brands.is_empty());
+        assert!(brands.iter().all(|m| m.name.entity() == crate::core::color_types::Entity::Brand));
+    }
+
+    #[test]
+    fn test_query_language() {
+        use crate::colors_helper::{Filter, parse_query};
+        use crate::core::color_types::Entity;
+
+        let q = parse_query(r#"origin:pantone|xkcd Entity:Brand hue:340..20 light:>60 near:#3366ff~10 "navy blue" -dark"#).unwrap();
+        assert_eq!(q.text, ["navy", "blue"]);
+        assert_eq!(q.clauses[0].filter, Filter::Origin(vec![Origin::Pantone, Origin::XKCD]));
+        assert_eq!(q.clauses[1].filter, Filter::Entity(vec![Entity::Brand]));
+        assert!(matches!(q.clauses[4].filter, Filter::Near { tolerance: 10.0, .. }));
+        assert_eq!(q.clauses[5].filter, Filter::Phrase("navy blue".into()));
+        assert!(q.clauses[6].negated);
+
+        // every error names its clause
+        for (query, clause) in [
+            ("blue hue:abc", "hue:abc"),
+            ("light:>160 red", "light:>160"),
+            ("origin:nowhere", "origin:nowhere"),
+            ("-entity:robot", "-entity:robot"),
+            ("near:notacolor", "near:notacolor"),
+            ("near:#fff~far", "near:#fff~far"),
+            ("shape:round", "shape:round"),
+            (r#"red "navy"#, r#""navy"#),
+        ] {
+            assert_eq!(parse_query(query).unwrap_err().clause, clause, "{query}");
+        }
+        // a trailing colon is part of a name, not a clause
+        assert_eq!(parse_query("Red: carmine").unwrap().text, ["red:", "carmine"]);
+
+        // origin: narrows All; hue and lightness filter on the color itself
+        let hits = search_in_origin(Origin::All, "origin:css hue:200..240 light:>60", TokenMode::Any);
+        assert!(!hits.is_empty());
+        for (hex, name) in &hits {
+            assert_eq!(name.origin(), Origin::Css);
+            let hsl = rgb_to_hsl(hex_to_rgb(hex.as_str()).unwrap());
+            assert!((200.0..=240.0).contains(&hsl.h) && hsl.l > 60.0, "{name}");
+        }
+        assert!(hits.iter().any(|(_, n)| n.as_str() == "lightskyblue"));
+
+        // origin: goes by palette membership, also for palettes whose names don't record their origin
+        for (origin, clause) in [(Origin::Seasons, "origin:seasons"), (Origin::MetalFlames, r#"origin:"metal flames""#)] {
+            let picked = origin_slice(origin);
+            let hits = search_in_origin(Origin::All, clause, TokenMode::Any);
+            assert_eq!(hits.len(), picked.len(), "{clause}");
+            assert!(hits.iter().all(|e| picked.contains(e)));
+            let rest = search_in_origin(Origin::All, &format!("-{clause} red"), TokenMode::Any);
+            assert!(!rest.is_empty() && rest.iter().all(|e| !picked.contains(e)), "-{clause}");
+            // a negated origin: drops the palette even when it is searched directly
+            assert!(search_in_origin(origin, &format!("-{clause} light:>0"), TokenMode::Any).is_empty());
+        }
+        let provinces = search_in_origin(Origin::All, r#"origin:"canadian provinces""#, TokenMode::Any);
+        assert_eq!(provinces.len(), origin_slice(Origin::CanadianProvinces).len());
+
+        // hue ranges wrap through red
+        let reds = search_in_origin(Origin::Css, "hue:350..10 light:40..60", TokenMode::Any);
+        assert!(reds.iter().any(|(_, n)| n.as_str() == "red"));
+
+        // near: alone lists the closest first
+        let near = search_in_origin(Origin::Css, "near:#FF6348~5", TokenMode::Any);
+        assert_eq!(near[0].1.as_str(), "tomato");
+
+        // negation and phrases
+        let blues = search_in_origin(Origin::XKCD, "blue -dark -entity:brand", TokenMode::Any);
+        assert!(!blues.is_empty());
+        assert!(blues.iter().all(|(_, n)| !n.as_str().split(' ').any(|w| w == "dark")));
+        let navy = search_in_origin(Origin::All, r#""navy blue""#, TokenMode::Any);
+        assert!(!navy.is_empty());
+        assert!(navy.iter().all(|(_, n)| n.as_str().to_lowercase().contains("navy blue")));
+
+        // an invalid query finds nothing rather than everything
+        assert!(search_in_origin(Origin::Css, "red hue:abc", TokenMode::Any).is_empty());
+    }
+
+    #[test]
+    fn test_unicode_folding() {
+        use crate::colors_helper::{fold_text, search_scored};
+        use crate::core::color_types::{ColorName, HexCode};
+
+        assert_eq!(fold_text("Crème Brûlée"), "creme brulee");
+        assert_eq!(fold_text("5-Masted Preußen"), "5-masted preussen");
+        assert_eq!(fold_text("À L’Orange"), "a l'orange");
+        assert_eq!(fold_text("ＲＥＤ"), "red");
+        assert_eq!(fold_text("Navy Blue"), "navy blue");
+
+        let names = |v: Vec<(HexCode, ColorName)>| -> Vec<&'static str> {
+            v.into_iter().map(|(_, n)| n.as_str()).collect()
+        };
+        // plain ASCII input finds accented names, through the index and substring paths
+        assert!(names(search_in_origin(Origin::ItalianBrands, "clement red", TokenMode::All)).contains(&"Clément red"));
+        assert!(names(search_in_origin(Origin::All, "ferre gold", TokenMode::Any)).contains(&"Gianfranco Ferré gold"));
+        assert!(names(search_in_origin(Origin::ItalianBrands, "errea", TokenMode::Substring)).contains(&"Erreà yellow"));
+        assert!(names(search_in_origin(Origin::ItalianBrands, "\"elena miro\"", TokenMode::All)).len() >= 3);
+        // and accented input still finds them
+        assert!(names(search_in_origin(Origin::ItalianBrands, "Clément", TokenMode::All)).contains(&"Clément blue"));
+        assert_eq!(search_scored(Origin::ItalianBrands, "noverca blue")[0].name.as_str(), "Nòverca blue");
+        assert!(search_in_origin(Origin::ItalianBrands, "clement -\"clément red\"", TokenMode::All)
+            .iter()
+            .all(|(_, n)| n.as_str() != "Clément red"));
+    }
+
+    #[test]
+    fn test_aliases() {
+        use crate::colors_helper::{aliases_of, matched_alias, search_scored};
+        use crate::core::color_types::AliasKind;
+
+        // the Hindi palette no longer glues "Hindi" onto its names
+        let hindi = origin_slice(Origin::Hindi);
+        assert!(hindi.iter().all(|(_, n)| !n.as_str().starts_with("Hindi")));
+        let sky = hindi.iter().find(|(_, n)| n.as_str() == "Sky Blue").unwrap().1;
+        assert!(sky.aliases().iter().any(|a| a.text == "आसमानी" && a.kind == AliasKind::Translation && a.lang == Some("hi")));
+        let wheat = hindi.iter().find(|(_, n)| n.as_str() == "Wheat").unwrap().1;
+        assert!(wheat.aliases().iter().any(|a| a.text == "gehuan" && a.kind == AliasKind::Transliteration));
+
+        // translations and transliterations are searchable, and say which alias matched
+        for query in ["aasmani", "आसमानी"] {
+            let hits = search_in_origin(Origin::All, query, TokenMode::All);
+            assert!(hits.iter().any(|&(_, n)| n == sky), "{query}");
+            assert_eq!(matched_alias(sky, query).map(|a| a.text), Some(query));
+        }
+        assert_eq!(matched_alias(sky, "sky blue"), None);
+        let firuzeh = search_in_origin(Origin::Persian, "firuzeh", TokenMode::Any);
+        assert_eq!(firuzeh[0].1.as_str(), "Turquoise");
+        assert_eq!(search_in_origin(Origin::Persian, "\"sabz-e irani\"", TokenMode::All)[0].1.as_str(), "Persian green");
+
+        // grey/gray spellings are generated for every palette
+        let slate = origin_slice(Origin::Css).iter().find(|(_, n)| n.as_str() == "darkslategray").unwrap().1;
+        let spellings: Vec<_> = aliases_of(slate).into_iter().filter(|a| a.kind == AliasKind::Spelling).collect();
+        assert_eq!(spellings.iter().map(|a| a.text).collect::<Vec<_>>(), ["darkslategrey"]);
+        let xkcd_gray = search_in_origin(Origin::XKCD, "gray", TokenMode::All);
+        assert!(xkcd_gray.iter().any(|(_, n)| n.as_str().contains("grey")));
+        // only whole words (or the end of a compound) change spelling
+        let spelled = |name: &'static str| aliases_of(crate::core::color_types::ColorName::new(name)).into_iter().map(|a| a.text).collect::<Vec<_>>();
+        assert!(spelled("Colorado sunset").is_empty());
+        assert!(spelled("Greyhound").is_empty());
+        assert!(spelled("grayish blue").is_empty());
+        assert_eq!(spelled("Colorado grey"), ["Colorado gray"]);
+        assert_eq!(spelled("Watercolor"), ["Watercolour"]);
+        assert_eq!(matched_alias(xkcd_gray[0].1, "gray").map(|a| a.kind), xkcd_gray[0].1.as_str().contains("grey").then_some(AliasKind::Spelling));
+
+        // fuzzy search scores aliases too and reports the one it used
+        let top = search_scored(Origin::Hindi, "asmani")[0];
+        assert_eq!((top.name, top.alias.map(|a| a.text)), (sky, Some("aasmani")));
+        assert_eq!(search_scored(Origin::Hindi, "sky blue")[0].alias, None);
+    }
+
+    #[cfg(feature = "extract")]
+    #[test]
+    fn test_extract_palette() {
+        use crate::extract::{ExtractError, ExtractMethod, ExtractOptions, decode_pixels, extract_from_file, load_pixels};
+
+        // 60% navy, 30% gold, 10% transparent (ignored), in a 10×10 PNG
+        let mut img = image::RgbaImage::new(10, 10);
+        for (i, px) in img.pixels_mut().enumerate() {
+            *px = match i {
+                0..60 => image::Rgba([0, 0, 128, 255]),
+                60..90 => image::Rgba([255, 215, 0, 255]),
+                _ => image::Rgba([255, 255, 255, 0]),
+            };
+        }
+        let dir = std::env::temp_dir().join(format!("colorum-extract-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let png = dir.join("flag.png");
+        img.save(&png).unwrap();
+        assert_eq!(load_pixels(&png).unwrap().len(), 90);
+
+        for method in ExtractMethod::ALL {
+            let options = ExtractOptions { count: 4, method, origin: Origin::Css, ..Default::default() };
+            let colors = extract_from_file(&png, &options).unwrap();
+            assert_eq!(colors.len(), 2, "{method}");
+            let named: Vec<(&str, f64)> = colors
+                .iter()
+                .map(|c| (c.nearest.unwrap().1.as_str(), (c.share * 100.0).round()))
+                .collect();
+            assert_eq!(named, [("navy", 67.0), ("gold", 33.0)], "{method}");
+        }
+
+        // a noisy gradient still comes back as `count` colors whose shares add up to 1
+        let mut noisy = image::RgbImage::new(64, 64);
+        for (x, y, px) in noisy.enumerate_pixels_mut() {
+            *px = image::Rgb([(x * 4) as u8, (y * 4) as u8, ((x + y) * 2) as u8]);
+        }
+        let jpg = dir.join("noisy.jpg");
+        noisy.save(&jpg).unwrap();
+        for method in ExtractMethod::ALL {
+            let colors = extract_from_file(&jpg, &ExtractOptions { count: 5, method, ..Default::default() }).unwrap();
+            assert_eq!(colors.len(), 5, "{method}");
+            assert!((colors.iter().map(|c| c.share).sum::<f64>() - 1.0).abs() < 1e-9);
+            assert!(colors.windows(2).all(|w| w[0].pixels >= w[1].pixels));
+            assert!(colors.iter().all(|c| c.nearest.is_some()));
+        }
+
+        assert!(matches!(decode_pixels(b"GIF89a\x01\x00\x01\x00"), Err(ExtractError::UnsupportedFormat(_))));
+        assert!(matches!(decode_pixels(b"not an image"), Err(ExtractError::Decode(_))));
+        assert!(matches!(load_pixels(dir.join("missing.png")), Err(ExtractError::Io(_))));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_harmony() {
+        use crate::core::spaces::Oklch;
+        use crate::harmony::{HarmonyOptions, HarmonyScheme, HarmonySpace, harmony, rotate_hue};
+
+        let base = Rgb { r: 0x33, g: 0x66, b: 0xCC };
+        for scheme in HarmonyScheme::ALL {
+            for space in HarmonySpace::ALL {
+                let options = HarmonyOptions { space, origin: Origin::Css, ..Default::default() };
+                let set = harmony(base, scheme, &options);
+                assert_eq!(set.len(), scheme.offsets().len(), "{scheme} in {space}");
+                assert_eq!(set[0].rgb, base);
+                // every color is snapped to a CSS name, and snapped() is that name's color
+                for c in &set {
+                    let (hex, _, _) = c.nearest.expect("css has colors");
+                    assert_eq!(rgb_to_hex(c.snapped()), hex.as_str().to_uppercase());
+                }
+            }
+        }
+
+        // HSL keeps saturation and lightness; the complement of pure red is cyan
+        let red = Rgb { r: 255, g: 0, b: 0 };
+        assert_eq!(rotate_hue(red, 180.0, HarmonySpace::Hsl), Rgb { r: 0, g: 255, b: 255 });
+        assert_eq!(rotate_hue(red, -120.0, HarmonySpace::Hsl), Rgb { r: 0, g: 0, b: 255 });
+
+        // OKLCH keeps lightness and lands on the requested hue, lowering chroma to stay in sRGB
+        let before = Oklch::from(red);
+        let after = Oklch::from(rotate_hue(red, 180.0, HarmonySpace::Oklch));
+        assert!((after.l - before.l).abs() < 0.01, "{before:?} -> {after:?}");
+        let hue_diff = (after.h - (before.h + 180.0)).rem_euclid(360.0);
+        assert!(hue_diff.min(360.0 - hue_diff) < 2.0, "{before:?} -> {after:?}");
+        assert!(after.c < before.c);
+
+        // a gray has no hue to turn
+        let gray = Rgb { r: 128, g: 128, b: 128 };
+        assert_eq!(rotate_hue(gray, 90.0, HarmonySpace::Oklch), gray);
+        assert!(!Oklch::new(0.6, 0.4, 150.0).in_gamut());
+        assert!(Oklch::new(0.6, 0.4, 150.0).to_gamut().in_gamut());
+    }
+
+    #[test]
+    fn test_contrast() {
+        use crate::contrast::{TextSize, WcagLevel, apca_lc, check, contrast_ratio, relative_luminance};
+
+        let black = Rgb { r: 0, g: 0, b: 0 };
+        let white = Rgb { r: 255, g: 255, b: 255 };
+        let gray = Rgb { r: 0x88, g: 0x88, b: 0x88 };
+
+        assert_eq!(relative_luminance(black), 0.0);
+        assert!((relative_luminance(white) - 1.0).abs() < 1e-12);
+        assert_eq!(contrast_ratio(white, black), 21.0);
+        assert_eq!(contrast_ratio(white, white), 1.0);
+        assert_eq!(contrast_ratio(gray, white), contrast_ratio(white, gray));
+
+        // thresholds sit exactly on the WCAG numbers
+        let orange = check(Rgb { r: 0xFF, g: 0x63, b: 0x47 }, white);
+        assert!((orange.ratio - 2.94).abs() < 0.01, "{}", orange.ratio);
+        assert!(!orange.passes(WcagLevel::Aa, TextSize::Large));
+        let gray_on_white = check(Rgb { r: 0x59, g: 0x59, b: 0x59 }, white);
+        assert!(gray_on_white.ratio >= 7.0 && gray_on_white.ratio < 7.1, "{}", gray_on_white.ratio);
+        for level in [WcagLevel::Aa, WcagLevel::Aaa] {
+            for size in [TextSize::Normal, TextSize::Large] {
+                assert!(gray_on_white.passes(level, size), "{level} {size:?}");
+            }
+        }
+
+        // APCA reference values, and polarity: light text on dark is negative
+        assert!((apca_lc(black, white) - 106.04).abs() < 0.01);
+        assert!((apca_lc(white, black) + 107.88).abs() < 0.01);
+        assert!((apca_lc(gray, white) - 63.06).abs() < 0.01);
+        assert!((apca_lc(white, gray) + 68.54).abs() < 0.01);
+        assert_eq!(apca_lc(gray, gray), 0.0);
+    }
+
+    #[test]
+    fn test_suggest_foreground() {
+        use crate::contrast::{contrast_ratio, suggest_foreground};
+
+        let white = Rgb { r: 255, g: 255, b: 255 };
+        let navy = Rgb { r: 0x10, g: 0x18, b: 0x40 };
+        let tomato = Rgb { r: 255, g: 99, b: 71 };
+        let teal = Rgb { r: 0x30, g: 0x80, b: 0x80 };
+
+        // a passing pair is left alone
+        let black = Rgb { r: 0, g: 0, b: 0 };
+        assert_eq!(suggest_foreground(black, white, 4.5, None).unwrap().rgb, black);
+
+        for (fg, bg, target) in [(tomato, white, 4.5), (tomato, white, 7.0), (teal, navy, 4.5), (tomato, navy, 7.0)] {
+            let fix = suggest_foreground(fg, bg, target, None).unwrap();
+            assert!(fix.ratio >= target, "{fg:?} on {bg:?}: {}", fix.ratio);
+            assert_eq!(fix.ratio, contrast_ratio(fix.rgb, bg));
+
+            // the hue survives the lightness change
+            let (before, after) = (Oklch::from(fg), Oklch::from(fix.rgb));
+            let dh = (after.h - before.h).rem_euclid(360.0);
+            assert!(dh.min(360.0 - dh) < 5.0, "{before:?} -> {after:?}");
+
+            // darker on a light background, lighter on a dark one
+            let went_darker = after.l < before.l;
+            assert_eq!(went_darker, bg == white, "{fg:?} on {bg:?}");
+
+            // snapped results are named colors that still pass
+            let snap = Some((Origin::Css, DistanceMetric::default()));
+            let named = suggest_foreground(fg, bg, target, snap).unwrap();
+            let (hex, _) = named.name.unwrap();
+            assert_eq!(hex_to_rgb(hex.as_str()), Some(named.rgb));
+            assert!(named.ratio >= target);
+        }
+
+        // nothing but black or white reaches 21:1, and nothing reaches more
+        assert!(suggest_foreground(tomato, Rgb { r: 250, g: 250, b: 250 }, 21.0, None).is_none());
+        assert!(suggest_foreground(tomato, white, 22.0, None).is_none());
+    }
+
+    #[test]
+    fn test_cvd() {
+        use crate::core::color_types::{ColorName, HexCode};
+        use crate::cvd::{ConfusionOptions, CvdModel, Deficiency, Simulation, confusable_pairs, simulate};
+
+        let white = Rgb { r: 255, g: 255, b: 255 };
+        let black = Rgb { r: 0, g: 0, b: 0 };
+        let red = Rgb { r: 255, g: 0, b: 0 };
+        let green = Rgb { r: 0, g: 160, b: 0 };
+        let blue = Rgb { r: 0, g: 0, b: 255 };
+
+        for model in CvdModel::ALL {
+            for deficiency in Deficiency::ALL {
+                // neutrals are untouched by every simulation
+                for gray in [white, black, Rgb { r: 90, g: 90, b: 90 }] {
+                    assert_eq!(simulate(gray, deficiency, model), gray, "{deficiency} {model}");
+                }
+            }
+            // red and green move much closer without L or M cones
+            let normal = DistanceMetric::Ciede2000.distance(red, green);
+            for deficiency in [Deficiency::Protanopia, Deficiency::Deuteranopia] {
+                let sim = Simulation::new(deficiency, model);
+                let seen = DistanceMetric::Ciede2000.distance(sim.apply(red), sim.apply(green));
+                assert!(seen < normal / 2.0, "{deficiency} {model}: {normal} -> {seen}");
+            }
+        }
+
+        // Viénot projects onto a plane where red and green channels match, so
+        // simulating twice changes (almost) nothing more
+        for deficiency in [Deficiency::Protanopia, Deficiency::Deuteranopia] {
+            let c = simulate(Rgb { r: 200, g: 40, b: 90 }, deficiency, CvdModel::BrettelVienot);
+            assert_eq!(c.r, c.g);
+            for c in [red, green, blue] {
+                let once = simulate(c, deficiency, CvdModel::BrettelVienot);
+                let twice = simulate(once, deficiency, CvdModel::BrettelVienot);
+                assert!(DistanceMetric::Ciede2000.distance(once, twice) < 1.0, "{deficiency}: {once:?} {twice:?}");
+            }
+        }
+        // achromatopsia leaves only lightness
+        let gray = simulate(Rgb { r: 30, g: 140, b: 200 }, Deficiency::Achromatopsia, CvdModel::Machado);
+        assert!(gray.r == gray.g && gray.g == gray.b);
+
+        // a color and its own simulation are told apart, but not with the deficiency
+        let sim = Simulation::new(Deficiency::Deuteranopia, CvdModel::BrettelVienot);
+        let palette: Vec<(HexCode, ColorName)> = [(red, "red"), (sim.apply(red), "red as seen"), (blue, "blue")]
+            .into_iter()
+            .map(|(c, n)| (HexCode::new(rgb_to_hex(c).leak()), ColorName::new(n)))
+            .collect();
+        let pairs = confusable_pairs(&palette, sim.deficiency, &ConfusionOptions { model: sim.model, ..Default::default() });
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].a.1.as_str(), pairs[0].b.1.as_str()), ("red", "red as seen"));
+        assert!(pairs[0].before >= 5.0 && pairs[0].after < 5.0);
+        assert!(confusable_pairs(&palette, Deficiency::Tritanopia, &ConfusionOptions::default()).is_empty());
+
+        assert_eq!(ConfusionOptions::with_metric(DistanceMetric::Oklab).threshold, 0.05);
+
+        // the indexed search finds exactly the pairs an all-pairs scan does
+        let css = origin_slice(Origin::Css);
+        for metric in DistanceMetric::ALL {
+            for deficiency in [Deficiency::Deuteranopia, Deficiency::Tritanopia] {
+                let options = ConfusionOptions::with_metric(metric);
+                let mut expected = Vec::new();
+                for (i, &(a_hex, a_name)) in css.iter().enumerate() {
+                    for &(b_hex, b_name) in &css[i + 1..] {
+                        let (a, b) = (hex_to_rgb(a_hex.as_str()).unwrap(), hex_to_rgb(b_hex.as_str()).unwrap());
+                        let before = metric.distance(a, b);
+                        let after = metric.distance(simulate(a, deficiency, options.model), simulate(b, deficiency, options.model));
+                        if before >= options.threshold && after < options.threshold {
+                            expected.push((a_name.as_str(), b_name.as_str()));
+                        }
+                    }
+                }
+                let found = confusable_pairs(css, deficiency, &options);
+                let mut names: Vec<_> = found.iter().map(|p| (p.a.1.as_str(), p.b.1.as_str())).collect();
+                names.sort();
+                expected.sort();
+                assert_eq!(names, expected, "{metric:?} {deficiency}");
+                assert!(found.windows(2).all(|w| w[0].after <= w[1].after));
+
+                // a limit keeps the hardest pairs
+                let top = confusable_pairs(css, deficiency, &ConfusionOptions { limit: Some(5), ..options });
+                assert_eq!(top.len(), found.len().min(5));
+                assert!(top.iter().zip(&found).all(|(x, y)| x.after == y.after));
+            }
+        }
+        assert!(confusable_pairs(css, Deficiency::Protanopia, &ConfusionOptions { limit: Some(0), ..Default::default() }).is_empty());
+    }
+
+    #[test]
+    fn test_gradient() {
+        use crate::gradient::{BlendSpace, HuePath, css_linear_gradient, css_linear_gradient_in, gradient, interpolate, with_nearest};
+
+        let red = Rgb { r: 255, g: 0, b: 0 };
+        let blue = Rgb { r: 0, g: 0, b: 255 };
+        let black = Rgb { r: 0, g: 0, b: 0 };
+        let white = Rgb { r: 255, g: 255, b: 255 };
+
+        // ends come back exactly in every space, and t is clamped
+        for space in BlendSpace::ALL {
+            assert_eq!(interpolate(red, blue, 0.0, space), red, "{space}");
+            assert_eq!(interpolate(red, blue, 1.0, space), blue, "{space}");
+            assert_eq!(interpolate(red, blue, 2.0, space), blue, "{space}");
+        }
+
+        assert_eq!(interpolate(black, white, 0.5, BlendSpace::Srgb), Rgb { r: 128, g: 128, b: 128 });
+        // half the light is brighter than half the code value
+        assert_eq!(interpolate(black, white, 0.5, BlendSpace::LinearRgb), Rgb { r: 188, g: 188, b: 188 });
+        let mid = interpolate(black, white, 0.5, BlendSpace::Oklab);
+        assert!(mid.r == mid.g && mid.g == mid.b && mid.r > 90 && mid.r < 110);
+
+        // hue paths: red (0°) to blue (240°) in HSL
+        assert_eq!(interpolate(red, blue, 0.5, BlendSpace::Hsl(HuePath::Shorter)), Rgb { r: 255, g: 0, b: 255 });
+        assert_eq!(interpolate(red, blue, 0.5, BlendSpace::Hsl(HuePath::Longer)), Rgb { r: 0, g: 255, b: 0 });
+        assert_eq!(interpolate(red, blue, 0.5, BlendSpace::Hsl(HuePath::Increasing)), Rgb { r: 0, g: 255, b: 0 });
+        assert_eq!(interpolate(red, blue, 0.5, BlendSpace::Hsl(HuePath::Decreasing)), Rgb { r: 255, g: 0, b: 255 });
+        // a gray end has no hue and borrows the other one
+        let light_blue = interpolate(blue, white, 0.5, BlendSpace::Hsl(HuePath::Shorter));
+        assert!(light_blue.r == light_blue.g && light_blue.b > light_blue.r);
+
+        // OKLCH stays in gamut and keeps more chroma than OKLab through the middle
+        let lch = crate::Oklch::from(interpolate(red, blue, 0.5, BlendSpace::Oklch(HuePath::Shorter)));
+        let lab = crate::Oklch::from(interpolate(red, blue, 0.5, BlendSpace::Oklab));
+        assert!(lch.c > lab.c);
+
+        // gradient lengths and stop placement
+        assert!(gradient(&[], 5, BlendSpace::Oklab).is_empty());
+        assert!(gradient(&[red, blue], 0, BlendSpace::Oklab).is_empty());
+        assert_eq!(gradient(&[red], 3, BlendSpace::Oklab), vec![red; 3]);
+        assert_eq!(gradient(&[red, blue], 1, BlendSpace::Oklab), vec![red]);
+        let three = gradient(&[red, white, blue], 5, BlendSpace::Srgb);
+        assert_eq!(three.len(), 5);
+        assert_eq!((three[0], three[2], three[4]), (red, white, blue));
+        assert_eq!(three[1], Rgb { r: 255, g: 128, b: 128 });
+
+        // CSS
+        assert_eq!(
+            css_linear_gradient(&three, 180.0).unwrap(),
+            "linear-gradient(180deg, #FF0000 0%, #FF8080 25%, #FFFFFF 50%, #8080FF 75%, #0000FF 100%)"
+        );
+        assert_eq!(
+            css_linear_gradient(&gradient(&[red, blue], 4, BlendSpace::Srgb), 90.0).unwrap(),
+            "linear-gradient(90deg, #FF0000 0%, #AA0055 33.33%, #5500AA 66.67%, #0000FF 100%)"
+        );
+        assert_eq!(
+            css_linear_gradient_in(&[red, blue], 45.0, BlendSpace::Oklch(HuePath::Longer)).unwrap(),
+            "linear-gradient(45deg in oklch longer hue, #FF0000 0%, #0000FF 100%)"
+        );
+        // a lone color is a solid fill; no colors, no gradient
+        assert_eq!(css_linear_gradient(&[red], 90.0).unwrap(), "linear-gradient(90deg, #FF0000 0%, #FF0000 100%)");
+        assert_eq!(
+            css_linear_gradient_in(&[blue], 0.0, BlendSpace::Oklab).unwrap(),
+            "linear-gradient(0deg in oklab, #0000FF 0%, #0000FF 100%)"
+        );
+        assert_eq!(css_linear_gradient(&[], 90.0), None);
+        assert_eq!(css_linear_gradient_in(&[], 90.0, BlendSpace::Srgb), None);
+        assert_eq!(BlendSpace::Hsl(HuePath::Shorter).css(), "in hsl");
+        assert_eq!(BlendSpace::LinearRgb.css(), "in srgb-linear");
+
+        let named = with_nearest(&[red, blue], crate::Origin::Css, crate::DistanceMetric::default());
+        let names: Vec<&str> = named.iter().map(|c| c.nearest.unwrap().1.as_str()).collect();
+        assert_eq!(names, ["red", "blue"]);
+    }
+
+    #[test]
+    fn test_scale() {
+        use crate::gradient::BlendSpace;
+        use crate::scale::{STEPS, anchor_step, css_custom_properties, scale, shades, tailwind_colors, tints, tones};
+
+        let white = Rgb { r: 255, g: 255, b: 255 };
+        let black = Rgb { r: 0, g: 0, b: 0 };
+        let red = Rgb { r: 255, g: 0, b: 0 };
+
+        // tints/shades/tones start at the base and stop short of the target
+        let t = tints(red, 4, BlendSpace::Srgb);
+        assert_eq!(t, [red, Rgb { r: 255, g: 64, b: 64 }, Rgb { r: 255, g: 128, b: 128 }, Rgb { r: 255, g: 191, b: 191 }]);
+        let s = shades(red, 2, BlendSpace::Srgb);
+        assert_eq!(s, [red, Rgb { r: 128, g: 0, b: 0 }]);
+        assert!(tints(red, 0, BlendSpace::Oklab).is_empty());
+        // tones keep lightness and lose chroma
+        let toned = tones(red, 5, BlendSpace::Oklab);
+        assert_eq!(toned[0], red);
+        let (first, last) = (crate::Oklch::from(toned[1]), crate::Oklch::from(toned[4]));
+        assert!(last.c < first.c);
+        assert!((last.l - crate::Oklch::from(red).l).abs() < 0.01);
+
+        // every color gets a full ramp, lightest first, base kept on its anchor step
+        for base in [red, Rgb { r: 0x3B, g: 0x82, b: 0xF6 }, Rgb { r: 0x22, g: 0xC5, b: 0x5E }, white, black, Rgb { r: 128, g: 128, b: 128 }] {
+            let ramp = scale(base);
+            assert_eq!(ramp.iter().map(|s| s.step).collect::<Vec<_>>(), STEPS);
+            assert_eq!(ramp.iter().filter(|s| s.base).count(), 1);
+            let anchor = ramp.iter().find(|s| s.base).unwrap();
+            assert_eq!((anchor.step, anchor.rgb), (anchor_step(base), base));
+            let ls: Vec<f64> = ramp.iter().map(|s| crate::Oklch::from(s.rgb).l).collect();
+            assert!(ls.windows(2).all(|w| w[0] > w[1]), "{base:?} {ls:?}");
+            // chroma never exceeds the base's
+            let base_c = crate::Oklch::from(base).c;
+            assert!(ramp.iter().all(|s| crate::Oklch::from(s.rgb).c <= base_c + 0.01));
+        }
+        assert_eq!(anchor_step(white), 50);
+        assert_eq!(anchor_step(black), 950);
+
+        // a gray ramp stays gray
+        assert!(scale(Rgb { r: 128, g: 128, b: 128 }).iter().all(|s| s.rgb.r == s.rgb.g && s.rgb.g == s.rgb.b));
+
+        let ramp = scale(Rgb { r: 0x3B, g: 0x82, b: 0xF6 });
+        let css = css_custom_properties("Brand Blue!", &ramp);
+        assert!(css.starts_with(":root {\n  --color-brand-blue-50: #"));
+        assert!(css.contains("  --color-brand-blue-500: #3B82F6;\n"));
+        assert!(css.ends_with(";\n}\n"));
+        assert_eq!(css.lines().count(), STEPS.len() + 2);
+
+        let tw = tailwind_colors("Brand Blue!", &ramp);
+        assert!(tw.starts_with("{\n  'brand-blue': {\n    50: '#"));
+        assert!(tw.contains("    500: '#3B82F6',\n"));
+        assert!(tw.ends_with("  },\n}\n"));
+        assert!(tailwind_colors("", &ramp).contains("'color': {"));
+    }
+
     #[test]
     fn test_hex_error_types() {
         // Test different error conditions
         assert_eq!(normalize_hex("FF0000"), Err(HexError::BadFormat)); // No #
         assert_eq!(normalize_hex("#FF"), Err(HexError::UnsupportedLength)); // Too short
-        assert_eq!(normalize_hex("#FF00"), Err(HexError::UnsupportedLength)); // Invalid length
+        assert_eq!(normalize_hex("#FF000"), Err(HexError::UnsupportedLength)); // Invalid length
         assert_eq!(normalize_hex("#FF0000G"), Err(HexError::BadFormat)); // Invalid hex char
         assert_eq!(normalize_hex("#"), Err(HexError::UnsupportedLength)); // Only #
 
@@ -570,7 +1863,7 @@ mod tests {
         let unsupported = HexError::UnsupportedLength;
 
         assert_eq!(bad_format.to_string(), "invalid hex format");
-        assert_eq!(unsupported.to_string(), "supported: #RGB, #RRGGBB, #RRGGBBAA");
+        assert_eq!(unsupported.to_string(), "supported: #RGB, #RGBA, #RRGGBB, #RRGGBBAA");
 
         // Test error equality
         assert_eq!(HexError::BadFormat, HexError::BadFormat);
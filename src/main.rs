@@ -2,6 +2,14 @@
 #![allow(dead_code)] // Allow dead code for unused features and utilities
 mod colors_helper;
 mod core;
+mod contrast;
+mod cvd;
+mod export;
+mod gradient;
+mod extract;
+mod harmony;
+mod import;
+mod scale;
 mod ui;
 
 pub mod colors;
@@ -13,6 +21,13 @@ use iced::{Theme, application};
 fn main() -> iced::Result {
     colorum::init_profiling();
 
+    // palette files given on the command line show up as extra origins
+    for path in std::env::args_os().skip(1) {
+        if let Err(e) = import::import_palette(&path) {
+            eprintln!("could not import {}: {e}", path.to_string_lossy());
+        }
+    }
+
     application(App::title, App::update, App::view)
         .theme(|_| Theme::Light)
         .subscription(App::subscription)
@@ -0,0 +1,139 @@
+//! Tints, shades, tones and design-system lightness ramps.
+//!
+//! [`tints`], [`shades`] and [`tones`] step a color towards white, black or
+//! the gray of its own lightness. [`scale`] builds a Tailwind-style ramp of
+//! eleven steps (50, 100, … 900, 950) in OKLCH: the base color lands on the
+//! step nearest its lightness, the others keep its hue and follow a fixed
+//! lightness curve, with chroma tapering towards both ends and lowered
+//! wherever sRGB can't hold it. [`css_custom_properties`] and
+//! [`tailwind_colors`] write a ramp out for a stylesheet or a Tailwind config.
+//!
+//! ```
+//! use colorum::scale::{STEPS, css_custom_properties, scale};
+//! use colorum::{Oklch, Rgb};
+//!
+//! let brand = Rgb { r: 0x3B, g: 0x82, b: 0xF6 };
+//! let ramp = scale(brand);
+//! assert_eq!(ramp.len(), STEPS.len());
+//! assert_eq!(ramp.iter().find(|s| s.base).unwrap().rgb, brand);
+//!
+//! // lightest first, darkest last
+//! assert!(ramp.windows(2).all(|w| Oklch::from(w[0].rgb).l > Oklch::from(w[1].rgb).l));
+//!
+//! let css = css_custom_properties("brand", &ramp);
+//! assert!(css.contains("  --color-brand-500: #3B82F6;\n"));
+//! ```
+
+use crate::core::rgb::{Rgb, rgb_to_hex};
+use crate::core::spaces::{Oklab, Oklch};
+use crate::gradient::{BlendSpace, gradient};
+
+/// Step names of a ramp, lightest first
+pub const STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+// OKLCH lightness of each step, close to Tailwind's own palettes
+const LIGHTNESS: [f64; 11] = [0.975, 0.936, 0.885, 0.808, 0.712, 0.637, 0.563, 0.495, 0.431, 0.379, 0.27];
+
+// chroma of each step relative to the most colorful one; the ends fade out
+const CHROMA: [f64; 11] = [0.06, 0.14, 0.27, 0.48, 0.78, 0.96, 1.0, 0.88, 0.73, 0.59, 0.4];
+
+/// One step of a [`scale`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScaleStep {
+    /// 50, 100, … 950
+    pub step: u16,
+    pub rgb: Rgb,
+    /// Whether this is the color the ramp was built from
+    pub base: bool,
+}
+
+/// Where `base` sits in a ramp: the step whose lightness is nearest its own
+pub fn anchor_step(base: Rgb) -> u16 {
+    STEPS[anchor_index(Oklch::from(base).l)]
+}
+
+fn anchor_index(l: f64) -> usize {
+    (0..LIGHTNESS.len()).min_by(|&i, &j| (LIGHTNESS[i] - l).abs().total_cmp(&(LIGHTNESS[j] - l).abs())).unwrap_or(0)
+}
+
+/// A 50–950 ramp around `base`, lightest first. `base` is kept exactly at
+/// its [`anchor_step`]; every other step takes that step's lightness, the
+/// base hue, and a share of the base chroma that never exceeds it, so a
+/// near-white base gives a pale ramp rather than an invented vivid one.
+pub fn scale(base: Rgb) -> Vec<ScaleStep> {
+    let lch = Oklch::from(base);
+    let anchor = anchor_index(lch.l);
+    STEPS
+        .iter()
+        .enumerate()
+        .map(|(i, &step)| {
+            if i == anchor {
+                return ScaleStep { step, rgb: base, base: true };
+            }
+            let c = lch.c * (CHROMA[i] / CHROMA[anchor]).min(1.0);
+            let rgb = Oklch::new(LIGHTNESS[i], c, lch.h).to_gamut().into();
+            ScaleStep { step, rgb, base: false }
+        })
+        .collect()
+}
+
+// `n` colors from `base` towards `target`, `base` first and `target` left out
+fn towards(base: Rgb, target: Rgb, n: usize, space: BlendSpace) -> Vec<Rgb> {
+    let mut colors = gradient(&[base, target], n + 1, space);
+    colors.truncate(n);
+    colors
+}
+
+/// `n` colors from `base` towards white, `base` first and white left out
+pub fn tints(base: Rgb, n: usize, space: BlendSpace) -> Vec<Rgb> {
+    towards(base, Rgb { r: 255, g: 255, b: 255 }, n, space)
+}
+
+/// `n` colors from `base` towards black, `base` first and black left out
+pub fn shades(base: Rgb, n: usize, space: BlendSpace) -> Vec<Rgb> {
+    towards(base, Rgb { r: 0, g: 0, b: 0 }, n, space)
+}
+
+/// `n` colors from `base` towards the gray of the same OKLab lightness,
+/// `base` first and the gray left out
+pub fn tones(base: Rgb, n: usize, space: BlendSpace) -> Vec<Rgb> {
+    let gray = Oklab::new(Oklab::from(base).l, 0.0, 0.0).into();
+    towards(base, gray, n, space)
+}
+
+// lowercase, with anything but letters and digits turned into single dashes
+fn token(name: &str) -> String {
+    let mut out = String::new();
+    for ch in name.trim().chars() {
+        if ch.is_ascii_alphanumeric() {
+            out.push(ch.to_ascii_lowercase());
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    let out = out.trim_end_matches('-');
+    if out.is_empty() { "color".into() } else { out.into() }
+}
+
+/// The ramp as CSS custom properties on `:root`, one `--color-{name}-{step}`
+/// per step (the names Tailwind v4 reads from `@theme`)
+pub fn css_custom_properties(name: &str, steps: &[ScaleStep]) -> String {
+    let name = token(name);
+    let mut out = String::from(":root {\n");
+    for s in steps {
+        out.push_str(&format!("  --color-{name}-{}: {};\n", s.step, rgb_to_hex(s.rgb)));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// The ramp as a Tailwind `colors` object, ready for `theme.extend.colors`
+/// in a `tailwind.config.js`
+pub fn tailwind_colors(name: &str, steps: &[ScaleStep]) -> String {
+    let mut out = format!("{{\n  '{}': {{\n", token(name));
+    for s in steps {
+        out.push_str(&format!("    {}: '{}',\n", s.step, rgb_to_hex(s.rgb)));
+    }
+    out.push_str("  },\n}\n");
+    out
+}
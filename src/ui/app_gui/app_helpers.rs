@@ -1,6 +1,7 @@
 use crate::colors_helper::{MAX_RESULTS, Origin};
 use crate::ui::app_gui::App;
 use crate::ui::messages::Msg;
+use crate::core::color_types::{ColorName, HexCode};
 use crate::core::rgb::hex_to_rgb;
 use iced::widget::{column, container, mouse_area, scrollable, text};
 use iced::{Alignment, Background, Color, Element, Length, Task, border};
@@ -35,13 +36,33 @@ impl App {
         for (row, &idx) in self.results_idx.iter().enumerate() {
             let (hex, name) = self.base[idx];
             let is_sel = self.sel_pos == Some(row);
+            // show the alias the query found, e.g. "Sky Blue (aasmani)"
+            let shown = match crate::colors_helper::matched_alias(name, &self.query) {
+                Some(alias) => format!("{} ({})", name.as_str(), alias.text),
+                None => name.as_str().to_string(),
+            };
             let label = if is_sel {
-                format!("▶ {}  {}", name.as_str(), hex.as_str())
+                format!("▶ {}  {}", shown, hex.as_str())
             } else {
-                format!("{}  {}", name.as_str(), hex.as_str())
+                format!("{}  {}", shown, hex.as_str())
             };
 
-            let row_body = container(text(label))
+            let rgb = crate::core::rgb::hex_to_rgb(hex.as_str()).map(|c| self.seen(c));
+            let swatch = container(text(""))
+                .width(Length::Fixed(14.0))
+                .height(Length::Fixed(14.0))
+                .style(move |_theme: &iced::Theme| iced::widget::container::Style {
+                    background: rgb.map(|c| Background::Color(Color::from_rgb8(c.r, c.g, c.b))),
+                    border: border::Border {
+                        radius: 3.0.into(),
+                        width: 1.0,
+                        color: Color::from_rgb(0.6, 0.6, 0.6),
+                    },
+                    ..Default::default()
+                });
+
+            let row_content = iced::widget::row![swatch, text(label)].spacing(6).align_y(Alignment::Center);
+            let row_body = container(row_content)
                 .padding([6, 8])
                 .width(Length::Fill)
                 .style(move |_theme: &iced::Theme| {
@@ -75,9 +96,22 @@ impl App {
             .into()
     }
 
+    /// Search mode for the query box: fuzzy when toggled on, otherwise every
+    /// word must match once the query has a space in it.
+    pub(crate) fn token_mode(&self, q: &str) -> crate::colors_helper::TokenMode {
+        use crate::colors_helper::TokenMode;
+        if self.fuzzy_search {
+            TokenMode::Fuzzy
+        } else if q.contains(' ') {
+            TokenMode::All
+        } else {
+            TokenMode::Any
+        }
+    }
+
     pub fn filtered_names(&self) -> Vec<&'static str> {
         use crate::colors_helper::{
-            HEAVY_MIN_QUERY, MAX_RESULTS, TokenMode, is_heavy_origin, origin_names, origin_rank,
+            HEAVY_MIN_QUERY, MAX_RESULTS, is_heavy_origin, origin_names, origin_rank,
             search_in_origin,
         };
 
@@ -94,16 +128,14 @@ impl App {
         }
 
         // normal search flow
-        let mode = if q.contains(' ') {
-            TokenMode::All
-        } else {
-            TokenMode::Any
-        };
-        let hits = search_in_origin(self.selected_origin, q, mode);
+        let hits = search_in_origin(self.selected_origin, q, self.token_mode(q));
 
         let mut names: Vec<&'static str> = hits.into_iter().map(|(_hex, name)| name.as_str()).collect();
-        let rank = origin_rank(self.selected_origin);
-        names.sort_unstable_by_key(|n| rank.get(n).copied().unwrap_or(usize::MAX));
+        // fuzzy hits are already best first
+        if !self.fuzzy_search {
+            let rank = origin_rank(self.selected_origin);
+            names.sort_unstable_by_key(|n| rank.get(n).copied().unwrap_or(usize::MAX));
+        }
 
         if names.len() > MAX_RESULTS {
             names.truncate(MAX_RESULTS);
@@ -205,21 +237,55 @@ impl App {
     }
 
     /// Set rr/gg/bb from a hex string like "#61B3E4" or "61B3E4".
+    /// An 8-digit "#RRGGBBAA" also sets the alpha; 6 digits keep the current alpha.
     pub(crate) fn set_from_hex(&mut self, hex: &str) {
         let clean = hex.trim().trim_start_matches('#');
-        if clean.len() == 6 {
-            if let Ok(r) = u8::from_str_radix(&clean[0..2], 16) {
-                if let Ok(g) = u8::from_str_radix(&clean[2..4], 16) {
-                    if let Ok(b) = u8::from_str_radix(&clean[4..6], 16) {
-                        self.rr = format!("{r:02X}");
-                        self.gg = format!("{g:02X}");
-                        self.bb = format!("{b:02X}");
-                    }
-                }
+        if clean.len() != 6 && clean.len() != 8 {
+            return;
+        }
+        if let Some(rgba) = crate::core::rgb::hex_to_rgba(&format!("#{clean}")) {
+            self.rr = format!("{:02X}", rgba.r);
+            self.gg = format!("{:02X}", rgba.g);
+            self.bb = format!("{:02X}", rgba.b);
+            if clean.len() == 8 {
+                self.aa = format!("{:02X}", rgba.a);
             }
         }
     }
 
+    /// Move the wheel to `rgba` and list the nearest names in the current origin.
+    pub(crate) fn jump_to_color(&mut self, rgba: crate::core::rgb::Rgba) {
+        self.rr = format!("{:02X}", rgba.r);
+        self.gg = format!("{:02X}", rgba.g);
+        self.bb = format!("{:02X}", rgba.b);
+        self.aa = format!("{:02X}", rgba.a);
+
+        let nearest = crate::colors_helper::nearest_colors(
+            rgba.rgb(),
+            50,
+            self.selected_origin,
+            self.distance_metric,
+        );
+        self.results_idx = nearest
+            .iter()
+            .filter_map(|(_hex, name, _d)| self.base_index_by_name.get(name).copied())
+            .collect();
+        // only claim a name on an exact match; otherwise the list just suggests
+        self.selected_name = nearest
+            .first()
+            .filter(|(_hex, _name, d)| *d == 0.0)
+            .map(|(_hex, name, _d)| name.as_str().to_string());
+        self.sel_pos = None;
+        self.dropdown_open = !self.results_idx.is_empty();
+    }
+
+    /// The current wheel color including alpha. Missing alpha digits read as opaque.
+    pub(crate) fn current_rgba(&self) -> crate::core::rgb::Rgba {
+        let ch = |s: &str| u8::from_str_radix(s, 16).unwrap_or(0);
+        let a = if self.aa.is_empty() { 255 } else { ch(&self.aa) };
+        crate::core::rgb::Rgba::new(ch(&self.rr), ch(&self.gg), ch(&self.bb), a)
+    }
+
     /// Apply the color at `results_idx[row]` to the wheel + selection.
     pub(crate) fn select_row(&mut self, row: usize) {
         if row < self.results_idx.len() {
@@ -276,15 +342,16 @@ impl App {
         let current_hex = format!("#{:02X}{:02X}{:02X}", r, g, b);
 
         // Convert to other color spaces
-        let hsl = rgb_to_hsl(r, g, b);
-        let cmyk = rgb_to_cmyk(r, g, b);
+        let rgb = crate::core::rgb::Rgb { r, g, b };
+        let hsl = crate::core::rgb::Hsl::from(rgb);
+        let cmyk = crate::core::spaces::Cmyk::from(rgb);
 
-        // Find closest color
-        let closest_color = self.get_closest_color_name();
+        // Find closest colors in the selected origin
+        let closest_colors = self.closest_colors(5);
 
         // Calculate contrast ratios
-        let white_contrast = contrast_ratio((r, g, b), (255, 255, 255));
-        let black_contrast = contrast_ratio((r, g, b), (0, 0, 0));
+        let white_contrast = crate::contrast::contrast_ratio(rgb, crate::core::rgb::Rgb { r: 255, g: 255, b: 255 });
+        let black_contrast = crate::contrast::contrast_ratio(rgb, crate::core::rgb::Rgb { r: 0, g: 0, b: 0 });
 
         let mut analytics_column = column![]
             .spacing(2)
@@ -297,27 +364,39 @@ impl App {
             .push(iced::widget::Space::with_height(Length::Fixed(2.0)))
             .push(text(format!("Window: {}×{}", self.window_width as u32, self.window_height as u32)).size(10).color(iced::Color::from_rgb(0.5, 0.5, 0.5)));
 
-        // Closest color section FIRST (clickable)
-        if let Some(closest) = closest_color {
-            let closest_button = button(text(format!("{}", closest)).size(11))
-                .on_press(Msg::CopyHex(closest.to_string()))
-                .style(|_theme, _status| iced::widget::button::Style {
-                    background: None,
-                    text_color: Color::from_rgb(0.2, 0.4, 0.8),
-                    border: iced::border::Border::default(),
-                    shadow: iced::Shadow::default(),
-                })
-                .padding([1, 3]);
-
+        // Closest colors section FIRST (clickable)
+        if !closest_colors.is_empty() {
             analytics_column = analytics_column
                 .push(iced::widget::Space::with_height(Length::Fixed(4.0)))
-                .push(text("Closest Color").size(13).font(iced::Font {
+                .push(text(format!("Closest in {}", self.selected_origin)).size(13).font(iced::Font {
                     weight: iced::font::Weight::Bold,
                     ..Default::default()
-                }))
-                .push(closest_button);
+                }));
+
+            for (_hex, name, distance) in closest_colors {
+                let closest_button = button(text(format!("{}  ({:.2})", name.as_str(), distance)).size(11))
+                    .on_press(Msg::CopyHex(name.as_str().to_string()))
+                    .style(|_theme, _status| iced::widget::button::Style {
+                        background: None,
+                        text_color: Color::from_rgb(0.2, 0.4, 0.8),
+                        border: iced::border::Border::default(),
+                        shadow: iced::Shadow::default(),
+                    })
+                    .padding([1, 3]);
+                analytics_column = analytics_column.push(closest_button);
+            }
         }
 
+        // Metric used for the closest-color match
+        let metric_dd = iced::widget::pick_list(
+            crate::core::distance::DistanceMetric::ALL,
+            Some(self.distance_metric),
+            Msg::MetricPicked,
+        )
+        .text_size(11)
+        .padding([1, 3]);
+        analytics_column = analytics_column.push(metric_dd);
+
         // HEX section (clickable)
         let hex_button = button(text(format!("{}", current_hex)).size(11))
             .on_press(Msg::CopyHex(current_hex.clone()))
@@ -353,7 +432,7 @@ impl App {
                 weight: iced::font::Weight::Bold,
                 ..Default::default()
             }))
-            .push(text(format!("{}°, {}%, {}%", hsl.0, hsl.1, hsl.2)).size(11));
+            .push(text(format!("{:.0}°, {:.0}%, {:.0}%", hsl.h, hsl.s, hsl.l)).size(11));
 
         // CMYK section
         analytics_column = analytics_column
@@ -362,7 +441,7 @@ impl App {
                 weight: iced::font::Weight::Bold,
                 ..Default::default()
             }))
-            .push(text(format!("{}%, {}%, {}%, {}%", cmyk.0, cmyk.1, cmyk.2, cmyk.3)).size(11));
+            .push(text(format!("{:.0}%, {:.0}%, {:.0}%, {:.0}%", cmyk.c, cmyk.m, cmyk.y, cmyk.k)).size(11));
 
         // Contrast ratios section - more compact
         analytics_column = analytics_column
@@ -371,22 +450,40 @@ impl App {
                 weight: iced::font::Weight::Bold,
                 ..Default::default()
             }))
-            .push(text(format!("White: {:.1} | Black: {:.1}", white_contrast, black_contrast)).size(11));
+            .push(text(format!("White: {:.1} | Black: {:.1}", white_contrast, black_contrast)).size(11))
+            .push(
+                iced::widget::checkbox("Text on background", self.contrast_mode)
+                    .on_toggle(Msg::ContrastModeToggled)
+                    .size(12)
+                    .text_size(11),
+            );
+
+        if self.contrast_mode {
+            analytics_column = analytics_column.push(self.view_contrast_pair());
+        } else {
+            // WCAG compliance section - more compact
+            let wcag_aa = white_contrast >= 4.5 || black_contrast >= 4.5;
+            let wcag_aaa = white_contrast >= 7.0 || black_contrast >= 7.0;
 
-        // WCAG compliance section - more compact
-        let wcag_aa = white_contrast >= 4.5 || black_contrast >= 4.5;
-        let wcag_aaa = white_contrast >= 7.0 || black_contrast >= 7.0;
+            analytics_column = analytics_column
+                .push(iced::widget::Space::with_height(Length::Fixed(2.0)))
+                .push(text("WCAG").size(13).font(iced::Font {
+                    weight: iced::font::Weight::Bold,
+                    ..Default::default()
+                }))
+                .push(text(format!("AA: {} | AAA: {}",
+                    if wcag_aa { "✓" } else { "✗" },
+                    if wcag_aaa { "✓" } else { "✗" }
+                )).size(11));
+        }
 
         analytics_column = analytics_column
-            .push(iced::widget::Space::with_height(Length::Fixed(2.0)))
-            .push(text("WCAG").size(13).font(iced::Font {
-                weight: iced::font::Weight::Bold,
-                ..Default::default()
-            }))
-            .push(text(format!("AA: {} | AAA: {}",
-                if wcag_aa { "✓" } else { "✗" },
-                if wcag_aaa { "✓" } else { "✗" }
-            )).size(11));
+            .push(iced::widget::Space::with_height(Length::Fixed(4.0)))
+            .push(self.view_harmony())
+            .push(iced::widget::Space::with_height(Length::Fixed(4.0)))
+            .push(self.view_cvd())
+            .push(iced::widget::Space::with_height(Length::Fixed(4.0)))
+            .push(self.view_image_palette());
 
         container(analytics_column)
             .style(|_theme| iced::widget::container::Style {
@@ -411,141 +508,522 @@ impl App {
             .into()
     }
 
-    /// Get the closest color name from the current color palette
-    fn get_closest_color_name(&self) -> Option<&'static str> {
-        let r = u8::from_str_radix(&self.rr, 16).unwrap_or(0);
-        let g = u8::from_str_radix(&self.gg, 16).unwrap_or(0);
-        let b = u8::from_str_radix(&self.bb, 16).unwrap_or(0);
+    /// Foreground and background of the contrast pair. An empty or unparsable
+    /// field stands for the wheel color.
+    pub fn contrast_colors(&self) -> (crate::core::rgb::Rgb, crate::core::rgb::Rgb) {
+        let wheel = crate::core::rgb::Rgb::from(self.current_rgba());
+        let pick = |s: &str| crate::core::parse::parse_color(s.trim()).map_or(wheel, |c| c.rgb());
+        (pick(&self.contrast_fg), pick(&self.contrast_bg))
+    }
+
+    fn view_contrast_pair(&self) -> Element<'_, Msg> {
+        use crate::contrast::{TextSize, WcagLevel};
+        use iced::widget::{button, column, container, row, text, text_input};
+        use iced::{Color, Length};
+
+        let (fg, bg) = self.contrast_colors();
+        let report = crate::contrast::check(fg, bg);
+        // the preview is shown as seen; the verdicts are for the true colors
+        let (fg, bg) = (self.seen(fg), self.seen(bg));
+
+        let side = |label: &'static str, value: &str, on_input: fn(String) -> Msg, from_wheel: Msg| {
+            row![]
+                .push(text(label).size(11).width(Length::Fixed(22.0)))
+                .push(text_input("wheel", value).on_input(on_input).size(11).padding(3))
+                .push(button(text("◉").size(11)).on_press(from_wheel).padding([2, 5]))
+                .spacing(4)
+                .align_y(Alignment::Center)
+        };
+
+        let fg_c = Color::from_rgb8(fg.r, fg.g, fg.b);
+        let preview = container(
+            column![]
+                .push(text("Body text 16px").size(16).color(fg_c))
+                .push(text("Large 24px").size(24).color(fg_c))
+                .spacing(2),
+        )
+        .padding(8)
+        .width(Length::Fill)
+        .style(move |_theme| iced::widget::container::Style {
+            background: Some(Background::Color(Color::from_rgb8(bg.r, bg.g, bg.b))),
+            border: border::Border {
+                radius: 4.0.into(),
+                width: 1.0,
+                color: Color::from_rgb(0.6, 0.6, 0.6),
+            },
+            ..Default::default()
+        });
+
+        let mark = |level, size| if report.passes(level, size) { "✓" } else { "✗" };
+
+        // lighten or darken the foreground until it passes
+        let fix_button = |level: WcagLevel| {
+            button(text(format!("Fix for {level}")).size(11))
+                .on_press(Msg::ContrastSuggest(level.min_ratio(TextSize::Normal)))
+                .padding([1, 6])
+        };
+        let fix_row = row![]
+            .push(fix_button(WcagLevel::Aa))
+            .push(fix_button(WcagLevel::Aaa))
+            .push(
+                iced::widget::checkbox(format!("Snap to {}", self.selected_origin), self.contrast_snap)
+                    .on_toggle(Msg::ContrastSnapToggled)
+                    .size(12)
+                    .text_size(11),
+            )
+            .spacing(4)
+            .align_y(Alignment::Center);
+
+        let mut col = column![]
+            .spacing(3)
+            .push(side("Fg", &self.contrast_fg, Msg::ContrastFgChanged, Msg::ContrastFgFromWheel))
+            .push(side("Bg", &self.contrast_bg, Msg::ContrastBgChanged, Msg::ContrastBgFromWheel))
+            .push(button(text("Swap").size(11)).on_press(Msg::ContrastSwap).padding([1, 6]))
+            .push(preview)
+            .push(text(format!("{:.2}:1  APCA Lc {:.1}", report.ratio, report.apca)).size(11))
+            .push(
+                text(format!(
+                    "AA {} large {} | AAA {} large {}",
+                    mark(WcagLevel::Aa, TextSize::Normal),
+                    mark(WcagLevel::Aa, TextSize::Large),
+                    mark(WcagLevel::Aaa, TextSize::Normal),
+                    mark(WcagLevel::Aaa, TextSize::Large),
+                ))
+                .size(11),
+            )
+            .push(fix_row);
+
+        if let Some(note) = &self.contrast_note {
+            col = col.push(text(note.clone()).size(11).color(Color::from_rgb(0.4, 0.4, 0.4)));
+        }
+        col.into()
+    }
+
+    /// The active color vision simulation, if any
+    pub fn simulation(&self) -> Option<crate::cvd::Simulation> {
+        self.cvd.map(|d| crate::cvd::Simulation::new(d, self.cvd_model))
+    }
+
+    /// `rgb` as it is painted: through the simulation when one is on
+    pub fn seen(&self, rgb: crate::core::rgb::Rgb) -> crate::core::rgb::Rgb {
+        self.simulation().map_or(rgb, |s| s.apply(rgb))
+    }
 
-        let colors = crate::colors_helper::origin_slice(self.selected_origin);
-        let mut closest_name = None;
-        let mut min_distance = f64::MAX;
+    fn view_cvd(&self) -> Element<'_, Msg> {
+        use iced::widget::{button, column, container, row, text};
+        use iced::{Color, Length};
 
-        for (hex, name) in colors {
-            if let Some(rgb) = crate::core::rgb::hex_to_rgb(hex.as_str()) {
-                let distance = color_distance((r, g, b), (rgb.r, rgb.g, rgb.b));
-                if distance < min_distance {
-                    min_distance = distance;
-                    closest_name = Some(name.as_str());
+        let deficiency_dd = iced::widget::pick_list(crate::cvd::Deficiency::ALL, self.cvd, Msg::CvdPicked)
+            .placeholder("Normal vision")
+            .text_size(11)
+            .padding([1, 3]);
+        let model_dd = iced::widget::pick_list(crate::cvd::CvdModel::ALL, Some(self.cvd_model), Msg::CvdModelPicked)
+            .text_size(11)
+            .padding([1, 3]);
+
+        let mut controls = row![].push(deficiency_dd).push(model_dd).spacing(4).align_y(Alignment::Center);
+        if self.cvd.is_some() {
+            controls = controls.push(button(text("Off").size(11)).on_press(Msg::CvdOff).padding([1, 6]));
+        }
+
+        let mut col = column![]
+            .spacing(2)
+            .push(text("Color Vision").size(13).font(iced::Font {
+                weight: iced::font::Weight::Bold,
+                ..Default::default()
+            }))
+            .push(controls);
+
+        let Some(deficiency) = self.cvd else {
+            return col.into();
+        };
+
+        let size = crate::colors_helper::origin_slice(self.selected_origin).len();
+        let too_big = size > super::CVD_MAX_COLORS;
+        let check = button(text(format!("Check {}", self.selected_origin)).size(11)).padding([1, 6]);
+        col = col.push(if self.cvd_busy || too_big { check } else { check.on_press(Msg::CvdCheck) });
+        if too_big {
+            col = col.push(
+                text(format!("{size} colors; pick a palette of at most {} to check", super::CVD_MAX_COLORS))
+                    .size(11)
+                    .color(Color::from_rgb(0.4, 0.4, 0.4)),
+            );
+        }
+
+        let swatch = |rgb: crate::core::rgb::Rgb| {
+            container(text(""))
+                .width(Length::Fixed(14.0))
+                .height(Length::Fixed(14.0))
+                .style(move |_theme| iced::widget::container::Style {
+                    background: Some(Background::Color(Color::from_rgb8(rgb.r, rgb.g, rgb.b))),
+                    border: border::Border {
+                        radius: 3.0.into(),
+                        width: 1.0,
+                        color: Color::from_rgb(0.6, 0.6, 0.6),
+                    },
+                    ..Default::default()
+                })
+        };
+
+        if self.cvd_busy {
+            col = col.push(text("Comparing colors…").size(11));
+        } else if let Some(pairs) = &self.cvd_pairs {
+            let summary = if pairs.len() < super::CVD_SHOWN_PAIRS {
+                format!("{} pairs look alike with {deficiency}", pairs.len())
+            } else {
+                format!("The {} pairs hardest to tell apart with {deficiency}", pairs.len())
+            };
+            col = col.push(
+                text(summary)
+                    .size(11)
+                    .color(Color::from_rgb(0.4, 0.4, 0.4)),
+            );
+            for pair in pairs {
+                let mut line = row![].spacing(4).align_y(Alignment::Center);
+                // true colors side by side, then how both are seen
+                for (hex, _) in [pair.a, pair.b] {
+                    if let Some(rgb) = crate::core::rgb::hex_to_rgb(hex.as_str()) {
+                        line = line.push(swatch(rgb));
+                    }
+                }
+                line = line.push(text("→").size(11));
+                for (hex, _) in [pair.a, pair.b] {
+                    if let Some(rgb) = crate::core::rgb::hex_to_rgb(hex.as_str()) {
+                        line = line.push(swatch(self.seen(rgb)));
+                    }
                 }
+                let label = format!("{} / {}", pair.a.1.as_str(), pair.b.1.as_str());
+                col = col.push(line.push(text(label).size(11)));
             }
         }
 
-        closest_name
+        col.into()
     }
 
+    /// 50-950 ramp of the wheel color with its exports; sized to sit under the wheel
+    pub fn view_scale_strip(&self, width: f32) -> Element<'_, Msg> {
+        use crate::gradient::with_nearest;
+        use crate::scale::{css_custom_properties, scale, tailwind_colors};
+        use iced::widget::{button, row, tooltip};
+
+        let toggle = iced::widget::checkbox("50-950 scale", self.scale_shown)
+            .on_toggle(Msg::ScaleToggled)
+            .size(12)
+            .text_size(11);
+        if !self.scale_shown {
+            return toggle.into();
+        }
 
-}
+        let ramp = scale(crate::core::rgb::Rgb::from(self.current_rgba()));
+        let rgbs: Vec<_> = ramp.iter().map(|s| s.rgb).collect();
+        let named = with_nearest(&rgbs, self.selected_origin, self.distance_metric);
+        let cell = (width / ramp.len() as f32).floor();
+
+        let mut strip = row![];
+        for (step, near) in ramp.iter().zip(&named) {
+            let shown = self.seen(step.rgb);
+            // the label takes whichever of black or white reads better on the step
+            let label_color = if crate::contrast::relative_luminance(shown) > 0.18 { Color::BLACK } else { Color::WHITE };
+            let is_base = step.base;
+            let cell_body = container(text(step.step.to_string()).size(9).color(label_color))
+                .width(Length::Fixed(cell))
+                .height(Length::Fixed(22.0))
+                .center_x(Length::Fixed(cell))
+                .center_y(Length::Fixed(22.0))
+                .style(move |_theme| iced::widget::container::Style {
+                    background: Some(Background::Color(Color::from_rgb8(shown.r, shown.g, shown.b))),
+                    border: border::Border {
+                        color: label_color,
+                        width: if is_base { 2.0 } else { 0.0 },
+                        radius: 0.0.into(),
+                    },
+                    ..Default::default()
+                });
+            let swatch = button(cell_body)
+                .on_press(Msg::UseColor(step.rgb))
+                .padding(0)
+                .style(|_theme, _status| iced::widget::button::Style::default());
+            let name = near.nearest.map_or("?", |(_hex, name, _d)| name.as_str());
+            let tip = container(text(format!("{}  {name}  {}", step.step, crate::core::rgb::rgb_to_hex(step.rgb))).size(11))
+                .padding(4)
+                .style(iced::widget::container::rounded_box);
+            strip = strip.push(tooltip(swatch, tip, tooltip::Position::Top));
+        }
 
-// Helper functions for color analytics
-fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (u16, u8, u8) {
-    let r = r as f32 / 255.0;
-    let g = g as f32 / 255.0;
-    let b = b as f32 / 255.0;
+        // tokens are named after the base color's nearest name
+        let token = named
+            .iter()
+            .zip(&ramp)
+            .find(|(_, s)| s.base)
+            .and_then(|(n, _)| n.nearest)
+            .map_or("brand", |(_hex, name, _d)| name.as_str());
+        let link = |label: &'static str, payload: String| {
+            button(text(label).size(11))
+                .on_press(Msg::CopyHex(payload))
+                .style(|_theme, _status| iced::widget::button::Style {
+                    background: None,
+                    text_color: Color::from_rgb(0.2, 0.4, 0.8),
+                    border: border::Border::default(),
+                    shadow: iced::Shadow::default(),
+                })
+                .padding([1, 3])
+        };
+        let controls = row![
+            toggle,
+            link("Copy CSS vars", css_custom_properties(token, &ramp)),
+            link("Copy Tailwind", tailwind_colors(token, &ramp)),
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center);
+
+        column![controls, strip].spacing(4).align_x(Alignment::Center).into()
+    }
 
-    let max = r.max(g.max(b));
-    let min = r.min(g.min(b));
-    let delta = max - min;
+    /// Gradient from the wheel color to the pinned one, with controls; sized
+    /// to sit under the wheel
+    pub fn view_gradient_strip(&self, width: f32) -> Element<'_, Msg> {
+        use crate::gradient::{BlendSpace, HuePath, css_linear_gradient, gradient, with_nearest};
+        use iced::widget::{button, column, container, row, text, tooltip};
+        use iced::{Color, Length};
 
-    let lightness = (max + min) / 2.0;
+        // Colors in the strip, ends included
+        const STEPS: usize = 11;
 
-    if delta == 0.0 {
-        return (0, 0, (lightness * 100.0) as u8);
-    }
+        // pick_list matches by value, so list the spaces with the current hue path
+        let path = self.gradient_space.hue_path().unwrap_or_default();
+        let spaces: Vec<BlendSpace> = BlendSpace::ALL.iter().map(|s| s.with_hue_path(path)).collect();
+        let space_dd = iced::widget::pick_list(spaces, Some(self.gradient_space), Msg::GradientSpacePicked)
+            .text_size(11)
+            .padding([1, 3]);
 
-    let saturation = if lightness < 0.5 {
-        delta / (max + min)
-    } else {
-        delta / (2.0 - max - min)
-    };
+        let mut controls = row![].spacing(4).align_y(Alignment::Center);
+        controls = match self.gradient_pin {
+            None => controls.push(button(text("Pin for gradient").size(11)).on_press(Msg::GradientPin).padding([1, 6])),
+            Some(_) => controls
+                .push(button(text("Re-pin").size(11)).on_press(Msg::GradientPin).padding([1, 6]))
+                .push(button(text("Unpin").size(11)).on_press(Msg::GradientUnpin).padding([1, 6])),
+        };
+        controls = controls.push(space_dd);
+        if let Some(path) = self.gradient_space.hue_path() {
+            controls = controls.push(
+                iced::widget::pick_list(HuePath::ALL, Some(path), Msg::GradientHuePicked)
+                    .text_size(11)
+                    .padding([1, 3]),
+            );
+        }
 
-    let hue = if max == r {
-        (g - b) / delta + if g < b { 6.0 } else { 0.0 }
-    } else if max == g {
-        (b - r) / delta + 2.0
-    } else {
-        (r - g) / delta + 4.0
-    };
-
-    (
-        ((hue * 60.0) as u16) % 360,
-        (saturation * 100.0) as u8,
-        (lightness * 100.0) as u8,
-    )
-}
+        let Some(pinned) = self.gradient_pin else {
+            return controls.into();
+        };
 
-fn rgb_to_cmyk(r: u8, g: u8, b: u8) -> (u8, u8, u8, u8) {
-    let r = r as f32 / 255.0;
-    let g = g as f32 / 255.0;
-    let b = b as f32 / 255.0;
+        let from = crate::core::rgb::Rgb::from(self.current_rgba());
+        let steps = gradient(&[from, pinned], STEPS, self.gradient_space);
+        let cell = (width / STEPS as f32).floor();
+
+        let mut strip = row![];
+        for step in with_nearest(&steps, self.selected_origin, self.distance_metric) {
+            let shown = self.seen(step.rgb);
+            let cell_body = container(text(""))
+                .width(Length::Fixed(cell))
+                .height(Length::Fixed(22.0))
+                .style(move |_theme| iced::widget::container::Style {
+                    background: Some(Background::Color(Color::from_rgb8(shown.r, shown.g, shown.b))),
+                    ..Default::default()
+                });
+            let swatch = button(cell_body)
+                .on_press(Msg::UseColor(step.rgb))
+                .padding(0)
+                .style(|_theme, _status| iced::widget::button::Style::default());
+            let name = step.nearest.map_or("?", |(_hex, name, _d)| name.as_str());
+            let tip = container(text(format!("{name}  {}", crate::core::rgb::rgb_to_hex(step.rgb))).size(11))
+                .padding(4)
+                .style(iced::widget::container::rounded_box);
+            strip = strip.push(tooltip(swatch, tip, tooltip::Position::Top));
+        }
+
+        // sampled stops, so any space renders the same in every browser
+        let css = css_linear_gradient(&steps, 90.0);
+        let copy = button(text("Copy CSS").size(11))
+            .on_press_maybe(css.map(Msg::CopyHex))
+            .style(|_theme, _status| iced::widget::button::Style {
+                background: None,
+                text_color: Color::from_rgb(0.2, 0.4, 0.8),
+                border: iced::border::Border::default(),
+                shadow: iced::Shadow::default(),
+            })
+            .padding([1, 3]);
 
-    let k = 1.0 - r.max(g.max(b));
-    if k == 1.0 {
-        return (0, 0, 0, 100);
+        column![controls.push(copy), strip].spacing(4).align_x(Alignment::Center).into()
     }
 
-    let c = (1.0 - r - k) / (1.0 - k);
-    let m = (1.0 - g - k) / (1.0 - k);
-    let y = (1.0 - b - k) / (1.0 - k);
+    /// The harmony set around the wheel color, snapped to the selected origin;
+    /// empty when no scheme is picked
+    pub fn harmony_colors(&self) -> Vec<crate::harmony::HarmonyColor> {
+        let Some(scheme) = self.harmony_scheme else {
+            return Vec::new();
+        };
+        let r = u8::from_str_radix(&self.rr, 16).unwrap_or(0);
+        let g = u8::from_str_radix(&self.gg, 16).unwrap_or(0);
+        let b = u8::from_str_radix(&self.bb, 16).unwrap_or(0);
+        let options = crate::harmony::HarmonyOptions {
+            space: self.harmony_space,
+            origin: self.selected_origin,
+            metric: self.distance_metric,
+        };
+        crate::harmony::harmony(crate::core::rgb::Rgb { r, g, b }, scheme, &options)
+    }
 
-    (
-        (c * 100.0) as u8,
-        (m * 100.0) as u8,
-        (y * 100.0) as u8,
-        (k * 100.0) as u8,
-    )
-}
+    fn view_harmony(&self) -> Element<'_, Msg> {
+        use iced::widget::{button, column, container, row, text};
+        use iced::{Color, Length};
 
-fn contrast_ratio(color1: (u8, u8, u8), color2: (u8, u8, u8)) -> f64 {
-    let l1 = relative_luminance(color1);
-    let l2 = relative_luminance(color2);
+        let scheme_dd = iced::widget::pick_list(
+            crate::harmony::HarmonyScheme::ALL,
+            self.harmony_scheme,
+            Msg::HarmonyPicked,
+        )
+        .placeholder("Scheme")
+        .text_size(11)
+        .padding([1, 3]);
+
+        let space_dd = iced::widget::pick_list(
+            crate::harmony::HarmonySpace::ALL,
+            Some(self.harmony_space),
+            Msg::HarmonySpacePicked,
+        )
+        .text_size(11)
+        .padding([1, 3]);
 
-    let lighter = l1.max(l2);
-    let darker = l1.min(l2);
+        let mut controls = row![].push(scheme_dd).push(space_dd).spacing(4).align_y(Alignment::Center);
+        if self.harmony_scheme.is_some() {
+            controls = controls.push(button(text("Off").size(11)).on_press(Msg::HarmonyOff).padding([1, 6]));
+        }
 
-    (lighter + 0.05) / (darker + 0.05)
-}
+        let mut col = column![]
+            .spacing(2)
+            .push(text("Harmony").size(13).font(iced::Font {
+                weight: iced::font::Weight::Bold,
+                ..Default::default()
+            }))
+            .push(controls);
+
+        // the base color is already on the wheel; list the rest
+        for color in self.harmony_colors().into_iter().skip(1) {
+            let rgb = color.snapped();
+            let shown = self.seen(rgb);
+            let swatch = container(text(""))
+                .width(Length::Fixed(14.0))
+                .height(Length::Fixed(14.0))
+                .style(move |_theme| iced::widget::container::Style {
+                    background: Some(Background::Color(Color::from_rgb8(shown.r, shown.g, shown.b))),
+                    border: border::Border {
+                        radius: 3.0.into(),
+                        width: 1.0,
+                        color: Color::from_rgb(0.6, 0.6, 0.6),
+                    },
+                    ..Default::default()
+                });
+            let name = color.nearest.map_or("?", |(_hex, name, _d)| name.as_str());
+            let label = button(text(format!("{name}  {:+.0}°", color.offset)).size(11))
+                .on_press(Msg::UseColor(rgb))
+                .style(|_theme, _status| iced::widget::button::Style {
+                    background: None,
+                    text_color: Color::from_rgb(0.2, 0.4, 0.8),
+                    border: iced::border::Border::default(),
+                    shadow: iced::Shadow::default(),
+                })
+                .padding([1, 3]);
+            col = col.push(row![].push(swatch).push(label).spacing(4).align_y(Alignment::Center));
+        }
 
-fn relative_luminance(color: (u8, u8, u8)) -> f64 {
-    let r = srgb_to_linear(color.0 as f64 / 255.0);
-    let g = srgb_to_linear(color.1 as f64 / 255.0);
-    let b = srgb_to_linear(color.2 as f64 / 255.0);
+        col.into()
+    }
 
-    0.2126 * r + 0.7152 * g + 0.0722 * b
-}
+    /// Dominant colors of an image: path box, method picker, and one clickable
+    /// swatch per color with its share and nearest name
+    fn view_image_palette(&self) -> Element<'_, Msg> {
+        use iced::widget::{button, column, container, row, text, text_input};
+        use iced::{Color, Length};
 
-fn srgb_to_linear(c: f64) -> f64 {
-    if c <= 0.03928 {
-        c / 12.92
-    } else {
-        ((c + 0.055) / 1.055).powf(2.4)
+        let path_row = row![]
+            .push(
+                text_input("PNG/JPEG path, or drop a file", &self.image_path)
+                    .on_input(Msg::ImagePathChanged)
+                    .on_submit(Msg::OpenImage)
+                    .size(11)
+                    .padding(3),
+            )
+            .push(button(text("Open").size(11)).on_press(Msg::OpenImage).padding([2, 6]))
+            .spacing(4)
+            .align_y(Alignment::Center);
+
+        let method_dd = iced::widget::pick_list(
+            crate::extract::ExtractMethod::ALL,
+            Some(self.image_method),
+            Msg::ImageMethodPicked,
+        )
+        .text_size(11)
+        .padding([1, 3]);
+
+        let mut col = column![]
+            .spacing(2)
+            .push(text("Image Palette").size(13).font(iced::Font {
+                weight: iced::font::Weight::Bold,
+                ..Default::default()
+            }))
+            .push(path_row)
+            .push(method_dd);
+
+        if let Some(err) = &self.image_error {
+            col = col.push(text(err.clone()).size(11).color(Color::from_rgb(0.75, 0.1, 0.1)));
+        }
+
+        for color in &self.image_colors {
+            let rgb = color.rgb;
+            let shown = self.seen(rgb);
+            let swatch = container(text(""))
+                .width(Length::Fixed(14.0))
+                .height(Length::Fixed(14.0))
+                .style(move |_theme| iced::widget::container::Style {
+                    background: Some(Background::Color(Color::from_rgb8(shown.r, shown.g, shown.b))),
+                    border: border::Border {
+                        radius: 3.0.into(),
+                        width: 1.0,
+                        color: Color::from_rgb(0.6, 0.6, 0.6),
+                    },
+                    ..Default::default()
+                });
+            let name = color.nearest.map_or("?", |(_hex, name, _d)| name.as_str());
+            let label = button(text(format!("{name}  {:.1}%", color.share * 100.0)).size(11))
+                .on_press(Msg::UseColor(rgb))
+                .style(|_theme, _status| iced::widget::button::Style {
+                    background: None,
+                    text_color: Color::from_rgb(0.2, 0.4, 0.8),
+                    border: iced::border::Border::default(),
+                    shadow: iced::Shadow::default(),
+                })
+                .padding([1, 3]);
+            col = col.push(row![].push(swatch).push(label).spacing(4).align_y(Alignment::Center));
+        }
+
+        col.into()
     }
-}
 
-fn color_distance(color1: (u8, u8, u8), color2: (u8, u8, u8)) -> f64 {
-    let dr = color1.0 as f64 - color2.0 as f64;
-    let dg = color1.1 as f64 - color2.1 as f64;
-    let db = color1.2 as f64 - color2.2 as f64;
+    /// Get the `k` closest colors from the current color palette
+    fn closest_colors(&self, k: usize) -> Vec<(HexCode, ColorName, f64)> {
+        let r = u8::from_str_radix(&self.rr, 16).unwrap_or(0);
+        let g = u8::from_str_radix(&self.gg, 16).unwrap_or(0);
+        let b = u8::from_str_radix(&self.bb, 16).unwrap_or(0);
 
-    (dr * dr + dg * dg + db * db).sqrt()
+        crate::colors_helper::nearest_colors(
+            crate::core::rgb::Rgb { r, g, b },
+            k,
+            self.selected_origin,
+            self.distance_metric,
+        )
+    }
 }
 
 pub fn origins_vec() -> Vec<Origin> {
-    vec![
-        Origin::All,
-        Origin::Css,
-        Origin::XKCD,
-        Origin::Pantone,
-        Origin::Hindi,
-        Origin::Persian,
-        Origin::National,
-        Origin::Brands,
-        Origin::ItalianBrands,
-        Origin::MetalFlames,
-        Origin::KelvinColors,
-        #[cfg(feature = "github-colors")]
-        Origin::GitHub,
-        Origin::Seasons,
-        Origin::CanadianProvinces,
-    ]
-}
\ No newline at end of file
+    Origin::all()
+}
@@ -1,6 +1,7 @@
 use iced::widget::scrollable;
 use std::collections::HashMap;
 use crate::core::color_types::{HexCode, ColorName};
+use crate::core::distance::DistanceMetric;
 use crate::core::rgb::CopyFormat;
 
 pub mod app_helpers;
@@ -8,7 +9,9 @@ pub mod subscription;
 pub mod update;
 pub mod view;
 
-
+// Color vision check: largest palette it runs on, and how many pairs it keeps
+pub(crate) const CVD_MAX_COLORS: usize = 5_000;
+pub(crate) const CVD_SHOWN_PAIRS: usize = 30;
 
 pub struct App {
     // lowercase cache for fast substring search (parallel to `base`)
@@ -17,6 +20,7 @@ pub struct App {
     pub rr: String,
     pub gg: String,
     pub bb: String,
+    pub aa: String, // alpha, "FF" = opaque
 
     pub search: String,
     pub selected_name: Option<String>,
@@ -51,6 +55,45 @@ pub struct App {
     pub copy_format: CopyFormat,
     pub format_feedback: Option<(String, std::time::Instant)>, // (message, timestamp)
 
+    // Metric used for the analytics "closest color" lookup
+    pub distance_metric: DistanceMetric,
+
+    // Typo-tolerant search, ranked by relevance instead of by origin
+    pub fuzzy_search: bool,
+    // Why the current query doesn't parse (shown under the search box)
+    pub query_error: Option<String>,
+
+    // Image palette: file to read, how to reduce it, and the result
+    pub image_path: String,
+    pub image_method: crate::extract::ExtractMethod,
+    pub image_colors: Vec<crate::extract::ExtractedColor>,
+    pub image_error: Option<String>,
+
+    // Harmony set shown on the wheel (None = off) and the space hues turn in
+    pub harmony_scheme: Option<crate::harmony::HarmonyScheme>,
+    pub harmony_space: crate::harmony::HarmonySpace,
+
+    // Two-color contrast check; each side is a CSS color, empty = the wheel color
+    pub contrast_mode: bool,
+    pub contrast_fg: String,
+    pub contrast_bg: String,
+    // Snap suggested foregrounds to the selected origin; outcome of the last suggestion
+    pub contrast_snap: bool,
+    pub contrast_note: Option<String>,
+
+    // Color vision simulation (None = normal vision) and the confusable-pairs
+    // report for the selected origin (None = not run yet)
+    pub cvd: Option<crate::cvd::Deficiency>,
+    pub cvd_model: crate::cvd::CvdModel,
+    pub cvd_pairs: Option<Vec<crate::cvd::ConfusablePair>>,
+    pub cvd_busy: bool,
+
+    // Gradient strip from the wheel color to a pinned one (None = no strip)
+    pub gradient_pin: Option<crate::core::rgb::Rgb>,
+    pub gradient_space: crate::gradient::BlendSpace,
+
+    // 50-950 ramp of the wheel color under the wheel
+    pub scale_shown: bool,
 }
 
 impl Default for App {
@@ -70,6 +113,7 @@ impl Default for App {
             rr: String::new(),
             gg: String::new(),
             bb: String::new(),
+            aa: String::from("FF"),
             search: String::new(),
             selected_name: None,
             selected_origin, // keep your existing value
@@ -103,6 +147,27 @@ impl Default for App {
             copy_format: CopyFormat::default(),
             format_feedback: None,
 
+            distance_metric: DistanceMetric::default(),
+            fuzzy_search: false,
+            query_error: None,
+            image_path: String::new(),
+            image_method: crate::extract::ExtractMethod::default(),
+            image_colors: Vec::new(),
+            image_error: None,
+            harmony_scheme: None,
+            harmony_space: crate::harmony::HarmonySpace::default(),
+            contrast_mode: false,
+            contrast_fg: String::new(),
+            contrast_bg: String::from("#FFFFFF"),
+            contrast_snap: false,
+            contrast_note: None,
+            cvd: None,
+            cvd_model: crate::cvd::CvdModel::default(),
+            cvd_pairs: None,
+            cvd_busy: false,
+            gradient_pin: None,
+            gradient_space: crate::gradient::BlendSpace::default(),
+            scale_shown: false,
         };
         // populate lowercase cache and hex without pound once at startup
         s.base_names_lc = s
@@ -32,12 +32,18 @@ impl App {
             Msg::WindowResized(size.width, size.height)
         });
 
+        // Images dropped on the window go to the image palette
+        let dropped_files = iced::event::listen_with(|event, _status, _window| match event {
+            iced::Event::Window(iced::window::Event::FileDropped(path)) => Some(Msg::ImageDropped(path)),
+            _ => None,
+        });
+
         if self.show_splash {
             let timer = iced::time::every(std::time::Duration::from_millis(100))
                 .map(|_| Msg::Tick);
-            iced::Subscription::batch([keyboard, timer, window_events])
+            iced::Subscription::batch([keyboard, timer, window_events, dropped_files])
         } else {
-            iced::Subscription::batch([keyboard, window_events])
+            iced::Subscription::batch([keyboard, window_events, dropped_files])
         }
     }
 }
@@ -1,7 +1,6 @@
 use crate::ui::app_gui::App;
 use crate::colors_helper::{MAX_RESULTS, Origin, sanitize_hex2};
-use crate::core::hex::combine_hex;
-use crate::core::rgb::{hex_to_rgb, format_rgb};
+use crate::core::rgb::format_rgba;
 use crate::ui::messages::Msg;
 use iced::keyboard::Key;
 use iced::keyboard::key::Named;
@@ -71,6 +70,14 @@ impl App {
                 self.clear_name_if_color_mismatch();
                 Task::none()
             }
+            Msg::AChanged(s) => {
+                self.aa = sanitize_hex2(&s);
+                Task::none()
+            }
+            Msg::AlphaChanged(a) => {
+                self.aa = format!("{a:02X}");
+                Task::none()
+            }
 
             Msg::WheelChanged(ch, v) => {
                 let hh = format!("{v:02X}");
@@ -115,17 +122,8 @@ impl App {
                 // Cycle to the next format
                 self.copy_format = self.copy_format.next();
 
-                // Get the current color as hex first
-                let hex = combine_hex(&self.rr, &self.gg, &self.bb);
-
-
-                // Convert to RGB and then format according to the current copy format
-                let text = if let Some(rgb) = hex_to_rgb(&hex) {
-                    format_rgb(rgb, self.copy_format)
-                } else {
-                    // Fallback to hex if parsing fails
-                    hex
-                };
+                // Format the current color (with alpha) according to the current copy format
+                let text = format_rgba(self.current_rgba(), self.copy_format);
 
                 // Set format feedback message
                 let feedback_msg = format!("Copied as {}: {}", self.copy_format.display_name(), text);
@@ -140,8 +138,10 @@ impl App {
                 self.rr.clear();
                 self.gg.clear();
                 self.bb.clear();
+                self.aa = String::from("FF");
 
                 self.query.clear();
+                self.query_error = None;
                 self.results_idx.clear();
                 self.last_query.clear();
                 self.last_results_idx.clear();
@@ -164,6 +164,7 @@ impl App {
             Msg::QueryChanged(s) => {
                 self.query = s;
                 let q = self.query.trim();
+                self.query_error = None;
                 #[cfg(feature = "github-colors")]
                 // Treat these origins as "heavy"
                 let is_heavy = matches!(self.selected_origin, Origin::All | Origin::GitHub);
@@ -173,7 +174,15 @@ impl App {
                 const HEAVY_MIN: usize = 1;
                 const MAX_RESULTS: usize = 200;
 
-                // If it *looks* like a hex query, run HEX reverse search and bypass gating
+                // A complete color (CSS function or full #hex) jumps the wheel straight to it
+                if let Some(rgba) = pasted_color(q) {
+                    self.last_query = q.to_string();
+                    self.jump_to_color(rgba);
+                    self.last_results_idx = self.results_idx.clone();
+                    return Task::none();
+                }
+
+                // If it *looks* like a partial hex query, run HEX reverse search and bypass gating
                 if let Some(hexq) = normalize_hex_query(q) {
                     self.results_idx.clear();
 
@@ -231,14 +240,21 @@ impl App {
                     return Task::none();
                 }
 
-                // Use Entity-aware search from search_in_origin
-                let mode = if q.contains(' ') {
-                    crate::colors_helper::TokenMode::All
-                } else {
-                    crate::colors_helper::TokenMode::Any
+                // Clauses (origin:, entity:, hue:, near:, …) are checked here so the
+                // box can say which one is wrong instead of just finding nothing
+                let parsed = match crate::colors_helper::parse_query(q) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        self.query_error = Some(e.to_string());
+                        self.results_idx.clear();
+                        self.sel_pos = None;
+                        self.dropdown_open = false;
+                        return Task::none();
+                    }
                 };
+                let mode = self.token_mode(q);
 
-                let hits = crate::colors_helper::search_in_origin(self.selected_origin, q, mode.clone());
+                let hits = crate::colors_helper::search_query(self.selected_origin, &parsed, mode);
 
                 self.results_idx.clear();
                 self.results_idx.reserve(hits.len().min(MAX_RESULTS));
@@ -275,6 +291,9 @@ impl App {
                 let __t0 = std::time::Instant::now();
 
                 self.selected_origin = o;
+                // the confusable-pairs report was for the old origin
+                self.cvd_pairs = None;
+                self.cvd_busy = false;
 
                 let slice = crate::colors_helper::origin_slice(o);
                 self.base = slice.to_vec();
@@ -330,11 +349,7 @@ impl App {
                     self.repopulate_full_results_capped();
                 } else {
                     // Use Entity-aware search for origin changes too
-                    let mode = if q.contains(' ') {
-                        crate::colors_helper::TokenMode::All
-                    } else {
-                        crate::colors_helper::TokenMode::Any
-                    };
+                    let mode = self.token_mode(&q);
 
                     let hits = crate::colors_helper::search_in_origin(self.selected_origin, &q, mode);
 
@@ -514,16 +529,8 @@ impl App {
             }
 
             Msg::CopyCurrentColor => {
-                // Get the current color as hex
-                let hex = combine_hex(&self.rr, &self.gg, &self.bb);
-
-                // Convert to RGB and format according to current copy format
-                let text = if let Some(rgb) = hex_to_rgb(&hex) {
-                    format_rgb(rgb, self.copy_format)
-                } else {
-                    // Fallback to hex if parsing fails
-                    hex
-                };
+                // Format the current color (with alpha) according to current copy format
+                let text = format_rgba(self.current_rgba(), self.copy_format);
 
                 // Set format feedback message
                 let feedback_msg = format!("Copied as {}: {}", self.copy_format.display_name(), text);
@@ -559,12 +566,248 @@ impl App {
                 Task::none()
             }
 
+            Msg::MetricPicked(metric) => {
+                self.distance_metric = metric;
+                Task::none()
+            }
+
+            Msg::FuzzyToggled(on) => {
+                self.fuzzy_search = on;
+                // re-run the current query in the new mode
+                self.update(Msg::QueryChanged(self.query.clone()))
+            }
+
+            Msg::ImagePathChanged(path) => {
+                self.image_path = path;
+                Task::none()
+            }
+
+            Msg::ImageDropped(path) => {
+                self.image_path = path.display().to_string();
+                self.update(Msg::OpenImage)
+            }
+
+            Msg::ImageMethodPicked(method) => {
+                self.image_method = method;
+                if self.image_colors.is_empty() {
+                    return Task::none();
+                }
+                // redo the image that is already open
+                self.update(Msg::OpenImage)
+            }
+
+            Msg::OpenImage => {
+                let path = self.image_path.trim().to_string();
+                if path.is_empty() {
+                    return Task::none();
+                }
+                let options = crate::extract::ExtractOptions {
+                    method: self.image_method,
+                    origin: self.selected_origin,
+                    metric: self.distance_metric,
+                    ..Default::default()
+                };
+                // decoding and clustering can take a moment; keep the UI responsive
+                Task::perform(
+                    async move { crate::extract::extract_from_file(&path, &options).map_err(|e| e.to_string()) },
+                    Msg::ImageExtracted,
+                )
+            }
+
+            Msg::ImageExtracted(result) => {
+                match result {
+                    Ok(colors) => {
+                        self.image_colors = colors;
+                        self.image_error = None;
+                    }
+                    Err(e) => {
+                        self.image_colors.clear();
+                        self.image_error = Some(e);
+                    }
+                }
+                Task::none()
+            }
+
+            Msg::UseColor(rgb) => {
+                self.jump_to_color(crate::core::rgb::Rgba { r: rgb.r, g: rgb.g, b: rgb.b, a: 255 });
+                Task::none()
+            }
+
+            Msg::HarmonyPicked(scheme) => {
+                self.harmony_scheme = Some(scheme);
+                Task::none()
+            }
+
+            Msg::HarmonySpacePicked(space) => {
+                self.harmony_space = space;
+                Task::none()
+            }
+
+            Msg::HarmonyOff => {
+                self.harmony_scheme = None;
+                Task::none()
+            }
+
+            Msg::ContrastModeToggled(on) => {
+                self.contrast_mode = on;
+                Task::none()
+            }
+
+            Msg::ContrastFgChanged(s) => {
+                self.contrast_fg = s;
+                self.contrast_note = None;
+                Task::none()
+            }
+
+            Msg::ContrastBgChanged(s) => {
+                self.contrast_bg = s;
+                self.contrast_note = None;
+                Task::none()
+            }
+
+            Msg::ContrastFgFromWheel => {
+                self.contrast_fg = crate::core::rgb::rgb_to_hex(self.current_rgba().into());
+                Task::none()
+            }
+
+            Msg::ContrastBgFromWheel => {
+                self.contrast_bg = crate::core::rgb::rgb_to_hex(self.current_rgba().into());
+                Task::none()
+            }
+
+            Msg::ContrastSwap => {
+                // pin both sides first so an empty (= wheel) field keeps its color
+                let (fg, bg) = self.contrast_colors();
+                self.contrast_fg = crate::core::rgb::rgb_to_hex(bg);
+                self.contrast_bg = crate::core::rgb::rgb_to_hex(fg);
+                Task::none()
+            }
+
+            Msg::ContrastSnapToggled(on) => {
+                self.contrast_snap = on;
+                Task::none()
+            }
+
+            Msg::ContrastSuggest(target) => {
+                let (fg, bg) = self.contrast_colors();
+                let snap = self.contrast_snap.then_some((self.selected_origin, self.distance_metric));
+                self.contrast_note = Some(match crate::contrast::suggest_foreground(fg, bg, target, snap) {
+                    Some(fix) => {
+                        let hex = crate::core::rgb::rgb_to_hex(fix.rgb);
+                        self.contrast_fg = hex.clone();
+                        match fix.name {
+                            Some((_, name)) => format!("{} {hex}  {:.2}:1", name.as_str(), fix.ratio),
+                            None => format!("{hex}  {:.2}:1", fix.ratio),
+                        }
+                    }
+                    None => format!("Nothing reaches {target}:1 on this background"),
+                });
+                Task::none()
+            }
+
+            Msg::CvdPicked(deficiency) => {
+                if self.cvd != Some(deficiency) {
+                    self.cvd_pairs = None;
+                    self.cvd_busy = false;
+                }
+                self.cvd = Some(deficiency);
+                Task::none()
+            }
+
+            Msg::CvdModelPicked(model) => {
+                if self.cvd_model != model {
+                    self.cvd_pairs = None;
+                    self.cvd_busy = false;
+                }
+                self.cvd_model = model;
+                Task::none()
+            }
+
+            Msg::CvdOff => {
+                self.cvd = None;
+                self.cvd_pairs = None;
+                self.cvd_busy = false;
+                Task::none()
+            }
+
+            Msg::CvdCheck => {
+                let Some(simulation) = self.simulation() else {
+                    return Task::none();
+                };
+                let origin = self.selected_origin;
+                let colors = crate::colors_helper::origin_slice(origin);
+                if colors.len() > super::CVD_MAX_COLORS {
+                    return Task::none();
+                }
+                self.cvd_busy = true;
+                let options = crate::cvd::ConfusionOptions {
+                    model: simulation.model,
+                    limit: Some(super::CVD_SHOWN_PAIRS),
+                    ..crate::cvd::ConfusionOptions::with_metric(self.distance_metric)
+                };
+                Task::perform(
+                    async move { crate::cvd::confusable_pairs(colors, simulation.deficiency, &options) },
+                    move |pairs| Msg::CvdChecked(simulation, origin, pairs),
+                )
+            }
+
+            Msg::CvdChecked(simulation, origin, pairs) => {
+                // a check started before the deficiency, model or origin changed
+                if self.simulation() != Some(simulation) || self.selected_origin != origin {
+                    return Task::none();
+                }
+                self.cvd_busy = false;
+                self.cvd_pairs = Some(pairs);
+                Task::none()
+            }
+
+            Msg::GradientPin => {
+                self.gradient_pin = Some(self.current_rgba().into());
+                Task::none()
+            }
+
+            Msg::GradientUnpin => {
+                self.gradient_pin = None;
+                Task::none()
+            }
+
+            Msg::GradientSpacePicked(space) => {
+                // keep the chosen hue path when moving between OKLCH and HSL
+                let path = self.gradient_space.hue_path().unwrap_or_default();
+                self.gradient_space = space.with_hue_path(path);
+                Task::none()
+            }
+
+            Msg::GradientHuePicked(path) => {
+                self.gradient_space = self.gradient_space.with_hue_path(path);
+                Task::none()
+            }
+
+            Msg::ScaleToggled(on) => {
+                self.scale_shown = on;
+                Task::none()
+            }
 
             _ => Task::none(),
         }
     }
 }
 
+/// Parse `q` as a color only when it is unambiguous: a CSS color function
+/// like `rgb(12 34 56 / 50%)` or a full `#RRGGBB` / `#RRGGBBAA`. Shorter hex
+/// and bare words stay with the prefix and name searches.
+fn pasted_color(q: &str) -> Option<crate::core::rgb::Rgba> {
+    let q = q.trim();
+    let full_hex = q
+        .strip_prefix('#')
+        .is_some_and(|d| matches!(d.len(), 6 | 8));
+    if q.contains('(') || full_hex {
+        crate::core::parse::parse_color(q).ok()
+    } else {
+        None
+    }
+}
+
 #[inline]
 fn normalize_hex_query(q: &str) -> Option<String> {
     // Only treat as hex if it starts with '#' or has at least 2 hex digits
@@ -26,7 +26,12 @@ impl App {
         let g = u8_from_hex2(&self.gg);
         let b = u8_from_hex2(&self.bb);
 
-        let wheel = crate::ui::widgets::color_wheel::ColorWheel::new(r, g, b, Msg::WheelChanged);
+        let alpha = self.current_rgba().a;
+        let harmony = self.harmony_colors();
+        let wheel = crate::ui::widgets::color_wheel::ColorWheel::new(r, g, b, Msg::WheelChanged)
+            .with_alpha(alpha)
+            .with_harmony(harmony.iter().skip(1).map(|c| c.snapped()))
+            .with_simulation(self.simulation());
 
         // Determine responsive sizing based on window dimensions
         let is_small_window = self.window_width < 500.0 || self.window_height < 450.0;
@@ -58,6 +63,23 @@ impl App {
             self.selected_name.as_deref(),
         );
 
+        // Alpha control under the wheel: slider plus the raw AA byte
+        let alpha_row = row![]
+            .push(iced::widget::text("Alpha").size(12))
+            .push(iced::widget::slider(0..=255u8, alpha, Msg::AlphaChanged).width(Length::Fixed(wheel_size * 0.5)))
+            .push(text_input("AA", &self.aa).on_input(Msg::AChanged).size(12).padding(4).width(Length::Fixed(36.0)))
+            .push(iced::widget::text(format!("{:.0}%", alpha as f32 / 2.55)).size(12))
+            .spacing(8)
+            .align_y(Alignment::Center);
+        let wheel_only = column![
+            wheel_only,
+            alpha_row,
+            self.view_gradient_strip(wheel_size * 0.8),
+            self.view_scale_strip(wheel_size * 0.8)
+        ]
+            .spacing(4)
+            .align_x(Alignment::Center);
+
         // Calculate panel width - larger at startup, scales with wheel
         let panel_width = (wheel_size * 0.4).max(200.0).min(300.0);
 
@@ -81,8 +103,14 @@ impl App {
         .placeholder("Origin")
         .width(Length::Shrink);
 
+        let fuzzy_toggle = iced::widget::checkbox("Fuzzy", self.fuzzy_search)
+            .on_toggle(Msg::FuzzyToggled)
+            .size(14)
+            .text_size(12);
+
         let mut origin_row = row![]
             .push(origin_dd)
+            .push(fuzzy_toggle)
             .spacing(8)
             .align_y(Alignment::Center);
 
@@ -100,6 +128,14 @@ impl App {
             .push(search_box)
             .spacing(8);
 
+        if let Some(err) = &self.query_error {
+            search_column = search_column.push(
+                iced::widget::text(err.clone())
+                    .size(12)
+                    .color(iced::Color::from_rgb(0.75, 0.1, 0.1)),
+            );
+        }
+
         if self.dropdown_open && !self.results_idx.is_empty() {
             search_column = search_column.push(self.view_dropdown());
         }
@@ -20,8 +20,10 @@ pub enum Msg {
     RChanged(String),
     GChanged(String),
     BChanged(String),
+    AChanged(String),
     // wheels
     WheelChanged(Channel, u8),
+    AlphaChanged(u8), // alpha slider
     // search/dropdown
     SearchChanged(String),
     //PickedName(String),
@@ -45,4 +47,50 @@ pub enum Msg {
     CopyCurrentColor,   // Ctrl+C - Copy current color
     AdjustColorWheel(Channel, i8), // Arrow keys - Fine color adjustments
 
+    // Analytics
+    MetricPicked(crate::core::distance::DistanceMetric),
+
+    // Search
+    FuzzyToggled(bool),
+
+    // Image palette
+    ImagePathChanged(String),
+    OpenImage,
+    ImageDropped(std::path::PathBuf), // file dropped on the window
+    ImageMethodPicked(crate::extract::ExtractMethod),
+    ImageExtracted(Result<Vec<crate::extract::ExtractedColor>, String>),
+    UseColor(crate::core::rgb::Rgb), // move the wheel to an extracted or harmony color
+
+    // Harmony
+    HarmonyPicked(crate::harmony::HarmonyScheme),
+    HarmonySpacePicked(crate::harmony::HarmonySpace),
+    HarmonyOff,
+
+    // Contrast pair
+    ContrastModeToggled(bool),
+    ContrastFgChanged(String),
+    ContrastBgChanged(String),
+    ContrastFgFromWheel,
+    ContrastBgFromWheel,
+    ContrastSwap,
+    ContrastSnapToggled(bool),
+    ContrastSuggest(f64), // replace the foreground with the nearest one reaching this ratio
+
+    // Color vision simulation
+    CvdPicked(crate::cvd::Deficiency),
+    CvdModelPicked(crate::cvd::CvdModel),
+    CvdOff,
+    CvdCheck, // find pairs in the selected origin that the deficiency merges
+    // pairs, with the simulation and origin they were computed for
+    CvdChecked(crate::cvd::Simulation, crate::colors_helper::Origin, Vec<crate::cvd::ConfusablePair>),
+
+    // Gradient strip
+    GradientPin, // pin the wheel color as the far end
+    GradientUnpin,
+    GradientSpacePicked(crate::gradient::BlendSpace),
+    GradientHuePicked(crate::gradient::HuePath),
+
+    // 50-950 scale strip
+    ScaleToggled(bool),
+
 }
@@ -1,5 +1,6 @@
 use crate::ui::app_gui::App;
-use crate::colors_helper::COMBINED_COLORS;
+use crate::colors_helper::{Origin, nearest_colors};
+use crate::core::distance::DistanceMetric;
 use crate::ui::messages::{Channel, Msg};
 use crate::core::color_types::{HexCode, ColorName};
 use iced::border::Radius;
@@ -12,7 +13,7 @@ use iced::{
     widget::{column, container, text, text_input},
 };
 
-use crate::core::rgb::hex_to_rgb;
+use crate::core::rgb::Rgb;
 
 #[derive(Default)]
 pub struct WheelState {
@@ -26,6 +27,9 @@ where
     r: u8,
     g: u8,
     b: u8,
+    a: u8,
+    harmony: Vec<Rgb>,
+    simulation: Option<crate::cvd::Simulation>,
     on_change: F,
     cache: canvas::Cache,
 }
@@ -39,11 +43,34 @@ where
             r,
             g,
             b,
+            a: 255,
+            harmony: Vec::new(),
+            simulation: None,
             on_change,
             cache: canvas::Cache::new(),
         }
     }
 
+    /// Set the alpha of the center swatch; translucent colors are drawn over a checkerboard.
+    pub fn with_alpha(mut self, a: u8) -> Self {
+        self.a = a;
+        self
+    }
+
+    /// Extra colors (e.g. a harmony set) shown as hollow thumbs on the rings
+    /// and as small swatches in the lower half of the center disc.
+    pub fn with_harmony(mut self, colors: impl IntoIterator<Item = Rgb>) -> Self {
+        self.harmony = colors.into_iter().collect();
+        self
+    }
+
+    /// Paint the center swatch and the harmony colors as seen with a color
+    /// vision deficiency. The rings and thumb positions stay true RGB.
+    pub fn with_simulation(mut self, simulation: Option<crate::cvd::Simulation>) -> Self {
+        self.simulation = simulation;
+        self
+    }
+
     /// Renders the canvas and overlays the 3 hex inputs **inside** the wheel (for large sizes only).
     pub fn view(
         self,
@@ -621,7 +648,12 @@ where
         // Add more space between inner ring and center color
         let inner_radius = (r_inner - ring_thickness * 1.2).max(20.0);
         let circle = Path::circle(center, inner_radius);
-        let combined = Color::from_rgb8(self.r, self.g, self.b);
+        if self.a < 255 {
+            checkerboard(&mut overlay, center, inner_radius);
+        }
+        let seen = |c: Rgb| self.simulation.map_or(c, |s| s.apply(c));
+        let center_rgb = seen(Rgb { r: self.r, g: self.g, b: self.b });
+        let combined = Color::from_rgba8(center_rgb.r, center_rgb.g, center_rgb.b, self.a as f32 / 255.0);
         overlay.fill(&circle, combined);
 
         // Add thin black border around inner circle for contrast
@@ -649,36 +681,64 @@ where
                 },
             );
         };
+        // Harmony thumbs: smaller, filled with their own color, drawn under the main ones
+        for c in &self.harmony {
+            let shown = seen(*c);
+            let fill = Color::from_rgb8(shown.r, shown.g, shown.b);
+            for (radius, value) in [(r_outer, c.r), (r_mid, c.g), (r_inner, c.b)] {
+                let angle = (value as f32 / 255.0) * std::f32::consts::TAU;
+                let circ = Path::circle(polar(center, radius, angle), 4.5);
+                overlay.fill(&circ, fill);
+                overlay.stroke(
+                    &circ,
+                    Stroke {
+                        width: 1.5,
+                        style: stroke::Style::Solid(Color::from_rgb(0.15, 0.15, 0.15)),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+
         thumb(&mut overlay, r_outer, self.r);
         thumb(&mut overlay, r_mid, self.g);
         thumb(&mut overlay, r_inner, self.b);
 
-        // Compute nearest color
-        let combined_hex = format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b);
-        let dist2 = |a: (u8, u8, u8), b: (u8, u8, u8)| -> u32 {
-            let dr = a.0 as i32 - b.0 as i32;
-            let dg = a.1 as i32 - b.1 as i32;
-            let db = a.2 as i32 - b.2 as i32;
-            (dr * dr + dg * dg + db * db) as u32
-        };
-
-        let mut best_name: Option<&'static str> = None;
-        let mut best_hex: Option<&'static str> = None;
-        let mut best_d: Option<u32> = None;
-
-        for (h, name) in COMBINED_COLORS.iter() {
-            if let Some(rgb) = hex_to_rgb(h.as_str()) {
-                let d = dist2((self.r, self.g, self.b), (rgb.r, rgb.g, rgb.b));
-                if best_d.map_or(true, |bd| d < bd) {
-                    best_d = Some(d);
-                    best_hex = Some(h.as_str());
-                    best_name = Some(name.as_str());
-                }
+        // Harmony swatches, spread along an arc in the lower half of the center disc
+        if !self.harmony.is_empty() {
+            let n = self.harmony.len();
+            let swatch_r = (inner_radius * 0.16).clamp(4.0, 14.0);
+            let arc_r = inner_radius - swatch_r * 1.6;
+            for (i, c) in self.harmony.iter().enumerate() {
+                // between 30° and 150° (screen y points down), evenly spaced
+                let t = (i as f32 + 1.0) / (n as f32 + 1.0);
+                let angle = (30.0 + 120.0 * (1.0 - t)).to_radians();
+                let disc = Path::circle(polar(center, arc_r, angle), swatch_r);
+                let shown = seen(*c);
+                overlay.fill(&disc, Color::from_rgb8(shown.r, shown.g, shown.b));
+                overlay.stroke(
+                    &disc,
+                    Stroke {
+                        width: 1.5,
+                        style: stroke::Style::Solid(Color::WHITE),
+                        ..Default::default()
+                    },
+                );
             }
         }
 
+        // Compute nearest color
+        let combined_hex = format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b);
+        let nearest = nearest_colors(
+            Rgb { r: self.r, g: self.g, b: self.b },
+            1,
+            Origin::All,
+            DistanceMetric::default(),
+        );
+        let best = nearest.first().map(|(h, name, _)| (name.as_str(), h.as_str()));
+
         let mut label = combined_hex.clone();
-        if let (Some(nm), Some(hx)) = (best_name, best_hex) {
+        if let Some((nm, hx)) = best {
             let exact = hx.eq_ignore_ascii_case(&combined_hex);
             if exact {
                 label.push_str(&format!("\n{} *", nm));
@@ -852,6 +912,42 @@ where
 
 /* -------- helpers -------- */
 
+/// Fill a disc with a light/dark checkerboard so translucent colors read as such.
+/// Drawn as thin horizontal strips clipped to the circle's chord.
+fn checkerboard(frame: &mut Frame, center: Point, radius: f32) {
+    let cell = (radius / 5.0).max(4.0);
+    let strip = 1.5_f32;
+
+    frame.fill(&Path::circle(center, radius), Color::from_rgb(0.95, 0.95, 0.95));
+    let dark = Color::from_rgb(0.75, 0.75, 0.75);
+
+    let mut y = -radius;
+    while y < radius {
+        let h = strip.min(radius - y);
+        let mid = y + h / 2.0;
+        let half = (radius * radius - mid * mid).max(0.0).sqrt();
+        let row = ((y + radius) / cell).floor() as i32;
+
+        // dark cells in this row, clipped to [-half, half]
+        let mut col = (-radius / cell).floor() as i32;
+        while (col as f32) * cell < half {
+            if (row + col).rem_euclid(2) == 0 {
+                let x0 = (col as f32 * cell).max(-half);
+                let x1 = ((col + 1) as f32 * cell).min(half);
+                if x1 > x0 {
+                    frame.fill_rectangle(
+                        Point::new(center.x + x0, center.y + y),
+                        iced::Size::new(x1 - x0, h),
+                        dark,
+                    );
+                }
+            }
+            col += 1;
+        }
+        y += strip;
+    }
+}
+
 fn polar(center: Point, r: f32, angle: f32) -> Point {
     Point::new(center.x + r * angle.cos(), center.y + r * angle.sin())
 }